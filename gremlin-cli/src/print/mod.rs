@@ -14,11 +14,22 @@ pub fn fmt(value: &GValue) -> Result<String> {
         GValue::Edge(e) => edge::fmt(e),
         GValue::Map(map) => map::fmt(map)?,
         GValue::List(list) => glist::fmt(list)?,
+        GValue::Byte(n) => n.to_string(),
+        GValue::Short(n) => n.to_string(),
         GValue::Int32(n) => n.to_string(),
         GValue::Int64(n) => n.to_string(),
         GValue::String(n) => n.to_string(),
         GValue::Uuid(uuid) => uuid.to_string(),
         GValue::Date(date) => date.to_string(),
+        GValue::Instant(date) => date.to_string(),
+        GValue::Duration(duration) => duration.to_string(),
+        GValue::LocalDate(date) => date.to_string(),
+        GValue::LocalDateTime(date) => date.to_string(),
+        GValue::OffsetDateTime(date) => date.to_string(),
+        GValue::ZonedDateTime(date) => date.to_string(),
+        GValue::Year(year) => year.to_string(),
+        GValue::Char(c) => c.to_string(),
+        GValue::Bytes(bytes) => format!("{:02x?}", bytes),
         GValue::Double(n) => n.to_string(),
         GValue::Path(path) => path::fmt(path)?,
         _ => return Err(anyhow!("Printing no supported for {:?}", value)),
@@ -1,18 +1,14 @@
 use crate::aio::pool::GremlinConnectionManager;
 use crate::aio::GResultSet;
-use crate::io::GraphSON;
-use crate::message::{
-    message_with_args, message_with_args_and_uuid, message_with_args_v2, Message,
-};
+use crate::authenticator::authenticate_async;
 use crate::process::traversal::Bytecode;
 use crate::GValue;
 use crate::ToGValue;
-use crate::{ConnectionOptions, GremlinError, GremlinResult};
-use base64::encode;
+use crate::{ConnectionOptions, GremlinError, GremlinResult, RequestOptions};
 use futures::future::{BoxFuture, FutureExt};
 use mobc::{Connection, Pool};
-use serde::Serialize;
 use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
 
 pub type SessionedClient = GremlinClient;
 
@@ -21,14 +17,11 @@ impl SessionedClient {
         if let Some(session_name) = self.session.take() {
             let mut args = HashMap::new();
             args.insert(String::from("session"), GValue::from(session_name.clone()));
-            let args = self.options.serializer.write(&GValue::from(args))?;
 
-            let processor = "session".to_string();
-
-            let message = match self.options.serializer {
-                GraphSON::V2 => message_with_args_v2(String::from("close"), processor, args),
-                GraphSON::V3 => message_with_args(String::from("close"), processor, args),
-            };
+            let message = self
+                .options
+                .serializer
+                .build_message("close", "session", args, None)?;
 
             let conn = self.pool.get().await?;
 
@@ -97,6 +90,20 @@ impl GremlinClient {
         script: T,
         params: &[(&str, &dyn ToGValue)],
     ) -> GremlinResult<GResultSet>
+    where
+        T: Into<String>,
+    {
+        self.execute_with_options(script, params, &RequestOptions::default())
+            .await
+    }
+
+    /// Execute a script with [RequestOptions](RequestOptions) such as an evaluation timeout.
+    pub async fn execute_with_options<T>(
+        &self,
+        script: T,
+        params: &[(&str, &dyn ToGValue)],
+        options: &RequestOptions,
+    ) -> GremlinResult<GResultSet>
     where
         T: Into<String>,
     {
@@ -131,87 +138,72 @@ impl GremlinClient {
             args.insert(String::from("session"), GValue::from(session_name.clone()));
         }
 
-        let args = self.options.serializer.write(&GValue::from(args))?;
+        options.apply(&mut args);
 
         let processor = if self.session.is_some() {
-            "session".to_string()
+            "session"
         } else {
-            String::default()
+            ""
         };
 
-        let message = match self.options.serializer {
-            GraphSON::V2 => message_with_args_v2(String::from("eval"), processor, args),
-            GraphSON::V3 => message_with_args(String::from("eval"), processor, args),
-        };
+        let message = self
+            .options
+            .serializer
+            .build_message("eval", processor, args, None)?;
 
         let conn = self.pool.get().await?;
 
         self.send_message_new(conn, message).await
     }
 
-    pub(crate) fn send_message_new<'a, T: Serialize>(
-        &'a self,
+    pub(crate) fn send_message_new(
+        &self,
         mut conn: Connection<GremlinConnectionManager>,
-        msg: Message<T>,
-    ) -> BoxFuture<'a, GremlinResult<GResultSet>> {
-        let id = msg.id().clone();
-        let message = self.build_message(msg).unwrap();
+        msg: (Uuid, Vec<u8>),
+    ) -> BoxFuture<'_, GremlinResult<GResultSet>> {
+        let (id, binary) = msg;
 
         async move {
-            let content_type = self.options.serializer.content_type();
-            let payload = String::from("") + content_type + &message;
-            let mut binary = payload.into_bytes();
-            binary.insert(0, content_type.len() as u8);
-
-            let (response, receiver) = conn.send(id, binary).await?;
+            let response = conn.send(id, binary).await?;
+            let (mut response, receiver) =
+                authenticate_async(&self.options, &mut conn, response).await?;
 
             let (response, results) = match response.status.code {
                 200 | 206 => {
-                    let results: VecDeque<GValue> = self
-                        .options
-                        .deserializer
-                        .read(&response.result.data)?
+                    let results: VecDeque<GValue> = response
+                        .result
+                        .data
+                        .take()
                         .map(|v| v.into())
                         .unwrap_or_else(VecDeque::new);
                     Ok((response, results))
                 }
                 204 => Ok((response, VecDeque::new())),
-                407 => match &self.options.credentials {
-                    Some(c) => {
-                        let mut args = HashMap::new();
-
-                        args.insert(
-                            String::from("sasl"),
-                            GValue::String(encode(&format!("\0{}\0{}", c.username, c.password))),
-                        );
-
-                        let args = self.options.serializer.write(&GValue::from(args))?;
-                        let message = message_with_args_and_uuid(
-                            String::from("authentication"),
-                            String::from("traversal"),
-                            response.request_id,
-                            args,
-                        );
-
-                        return self.send_message_new(conn, message).await;
-                    }
-                    None => Err(GremlinError::Request((
-                        response.status.code,
-                        response.status.message,
-                    ))),
-                },
-                _ => Err(GremlinError::Request((
-                    response.status.code,
-                    response.status.message,
-                ))),
+                _ => Err(response.status.into_error()),
             }?;
 
-            Ok(GResultSet::new(self.clone(), results, response, receiver))
+            // The next request on an HTTP connection waits for this response to be read to the end
+            let conn = match response.status.code {
+                206 if self.options.uses_http() => Some(conn),
+                _ => None,
+            };
+
+            Ok(GResultSet::new(
+                self.clone(),
+                results,
+                response,
+                receiver,
+                conn,
+            ))
         }
         .boxed()
     }
 
-    pub(crate) async fn submit_traversal(&self, bytecode: &Bytecode) -> GremlinResult<GResultSet> {
+    pub(crate) async fn submit_traversal(
+        &self,
+        bytecode: &Bytecode,
+        options: &RequestOptions,
+    ) -> GremlinResult<GResultSet> {
         let mut args = HashMap::new();
 
         args.insert(String::from("gremlin"), GValue::Bytecode(bytecode.clone()));
@@ -229,16 +221,122 @@ impl GremlinClient {
 
         args.insert(String::from("aliases"), GValue::from(aliases));
 
-        let args = self.options.serializer.write(&GValue::from(args))?;
+        options.apply(&mut args);
 
-        let message = message_with_args(String::from("bytecode"), String::from("traversal"), args);
+        let message = self
+            .options
+            .serializer
+            .build_message("bytecode", "traversal", args, None)?;
 
         let conn = self.pool.get().await?;
 
         self.send_message_new(conn, message).await
     }
+}
 
-    fn build_message<T: Serialize>(&self, msg: Message<T>) -> GremlinResult<String> {
-        serde_json::to_string(&msg).map_err(GremlinError::from)
+#[cfg(test)]
+mod tests {
+    use super::GremlinClient;
+    use crate::io::graph_binary_v4;
+    use crate::test_support::read_http_request;
+    use crate::{ConnectionOptions, GValue, Protocol};
+    use futures::StreamExt;
+    use std::io::{BufReader, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    const STATUS_OK: [u8; 9] = [0xfd, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc8, 0x01, 0x01];
+
+    fn write_chunk(stream: &mut TcpStream, chunk: &[u8]) {
+        stream
+            .write_all(format!("{:x}\r\n", chunk.len()).as_bytes())
+            .unwrap();
+        stream.write_all(chunk).unwrap();
+        stream.write_all(b"\r\n").unwrap();
+        stream.flush().unwrap();
+    }
+
+    fn values(values: &[&str]) -> Vec<u8> {
+        let mut buf = vec![];
+        for value in values {
+            graph_binary_v4::write(&GValue::from(*value), &mut buf).unwrap();
+        }
+        buf
+    }
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn it_should_keep_an_http_connection_until_the_response_is_read() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (release, released) = mpsc::channel::<()>();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            read_http_request(&mut reader);
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n")
+                .unwrap();
+            write_chunk(
+                &mut stream,
+                &[&[0x81, 0x00][..], &values(&["marko"])].concat(),
+            );
+            released.recv().unwrap();
+            write_chunk(&mut stream, &[&values(&["josh"])[..], &STATUS_OK].concat());
+            write_chunk(&mut stream, &[]);
+
+            read_http_request(&mut reader);
+            let body = [&[0x81, 0x00][..], &values(&["vadas"]), &STATUS_OK].concat();
+            stream
+                .write_all(
+                    format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len()).as_bytes(),
+                )
+                .unwrap();
+            stream.write_all(&body).unwrap();
+        });
+
+        let client = GremlinClient::connect(
+            ConnectionOptions::builder()
+                .host("127.0.0.1")
+                .port(port)
+                .protocol(Protocol::TinkerPop4)
+                .pool_size(1)
+                .pool_connection_timeout(Some(Duration::from_millis(200)))
+                .build(),
+        )
+        .await
+        .unwrap();
+
+        let mut first = client.execute("g.V()", &[]).await.unwrap();
+        assert_eq!(
+            Some(GValue::from("marko")),
+            first.next().await.transpose().unwrap()
+        );
+
+        // The only connection is busy until the streamed response was read
+        assert!(client.execute("g.V()", &[]).await.is_err());
+
+        release.send(()).unwrap();
+        assert_eq!(
+            Some(GValue::from("josh")),
+            first.next().await.transpose().unwrap()
+        );
+        assert!(first.next().await.is_none());
+
+        let second: Vec<GValue> = client
+            .execute("g.V()", &[])
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(vec![GValue::from("vadas")], second);
+
+        drop(first);
+        server.join().unwrap();
     }
 }
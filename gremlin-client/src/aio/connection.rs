@@ -1,7 +1,8 @@
-use crate::{GremlinError, GremlinResult, WebSocketOptions};
+use crate::{http, GremlinError, GremlinResult, WebSocketOptions};
 
 use crate::connection::ConnectionOptions;
 
+use crate::io::IoProtocol;
 use crate::message::Response;
 
 #[cfg(feature = "async-std-runtime")]
@@ -25,16 +26,14 @@ use futures::TryFutureExt;
 #[cfg(feature = "tokio-runtime")]
 use tokio_use::*;
 
-#[cfg(feature = "async-std-runtime")]
-use async_tungstenite::async_std::connect_async_with_tls_connector_and_config;
-
 #[cfg(feature = "tokio-runtime")]
-use async_tungstenite::tokio::{connect_async_with_tls_connector_and_config, TokioAdapter};
+use async_tungstenite::tokio::TokioAdapter;
 
 use async_tungstenite::tungstenite::protocol::{Message, WebSocketConfig};
-use async_tungstenite::WebSocketStream;
 use async_tungstenite::{self, stream};
+use async_tungstenite::{client_async_with_config, WebSocketStream};
 use futures::{
+    io::{AsyncReadExt, AsyncWriteExt},
     lock::Mutex,
     stream::{SplitSink, SplitStream},
     SinkExt, StreamExt,
@@ -42,16 +41,17 @@ use futures::{
 
 use futures::channel::mpsc::{channel, Receiver, Sender};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use url;
 use uuid::Uuid;
 
 #[cfg(feature = "async-std-runtime")]
-type WSStream = WebSocketStream<stream::Stream<TcpStream, TlsStream<TcpStream>>>;
+type MaybeTlsStream = stream::Stream<TcpStream, TlsStream<TcpStream>>;
 
 #[cfg(feature = "tokio-runtime")]
-type WSStream =
-    WebSocketStream<stream::Stream<TokioAdapter<TcpStream>, TokioAdapter<TlsStream<TcpStream>>>>;
+type MaybeTlsStream = stream::Stream<TokioAdapter<TcpStream>, TokioAdapter<TlsStream<TcpStream>>>;
+
+type WSStream = WebSocketStream<MaybeTlsStream>;
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -61,9 +61,12 @@ pub enum Cmd {
     Shutdown,
 }
 
+type Requests = Arc<Mutex<HashMap<Uuid, Sender<GremlinResult<Response>>>>>;
+
 pub(crate) struct Conn {
     sender: Sender<Cmd>,
-    valid: bool,
+    // Shared with the background tasks, which give up on a broken connection
+    valid: Arc<AtomicBool>,
 }
 
 impl std::fmt::Debug for Conn {
@@ -77,7 +80,8 @@ mod tls {
 
     use std::time::SystemTime;
 
-    use crate::connection::ConnectionOptions;
+    use crate::connection::{unbracketed, ConnectionOptions, TlsIdentity, TlsOptions, TlsVersion};
+    use crate::{GremlinError, GremlinResult};
     use rustls::{Certificate, ServerName};
     pub struct NoCertificateVerification {}
 
@@ -95,37 +99,134 @@ mod tls {
         }
     }
 
-    pub fn connector(opts: &ConnectionOptions) -> Option<async_tls::TlsConnector> {
-        use rustls::ClientConfig;
+    fn tls_error<E: ToString>(e: E) -> GremlinError {
+        GremlinError::Generic(e.to_string())
+    }
+
+    // The rustls counterpart of `TlsOptions::tls_connector`
+    pub fn connector(tls: &TlsOptions) -> GremlinResult<async_tls::TlsConnector> {
+        use rustls::{ClientConfig, OwnedTrustAnchor, PrivateKey, RootCertStore};
+        use rustls_pemfile::Item;
         use std::sync::Arc;
-        if opts
-            .tls_options
-            .as_ref()
-            .map(|tls| tls.accept_invalid_certs)
-            .unwrap_or(false)
-        {
-            let config = ClientConfig::builder()
-                .with_safe_defaults()
-                .with_custom_certificate_verifier(Arc::new(NoCertificateVerification {}))
-                .with_no_client_auth();
-
-            Some(async_tls::TlsConnector::from(Arc::new(config)))
+
+        let versions: &[&rustls::SupportedProtocolVersion] = match tls.min_protocol_version {
+            None | Some(TlsVersion::Tls12) => rustls::ALL_VERSIONS,
+            Some(TlsVersion::Tls13) => &[&rustls::version::TLS13],
+            Some(version) => {
+                return Err(GremlinError::Generic(format!(
+                    "{:?} is not supported by rustls",
+                    version
+                )))
+            }
+        };
+        let builder = ClientConfig::builder()
+            .with_safe_default_cipher_suites()
+            .with_safe_default_kx_groups()
+            .with_protocol_versions(versions)
+            .map_err(tls_error)?;
+
+        // The custom roots are loaded either way, a bad certificate fails the connection even
+        // when the server certificate is not verified
+        let mut roots = RootCertStore::empty();
+        roots.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        }));
+        for pem in &tls.root_certificates {
+            for certificate in rustls_pemfile::certs(&mut pem.as_slice())? {
+                roots.add(&Certificate(certificate)).map_err(tls_error)?;
+            }
+        }
+
+        let identity = match &tls.identity {
+            None => None,
+            Some(TlsIdentity::Pem { certificate, key }) => {
+                let chain = rustls_pemfile::certs(&mut certificate.as_slice())?
+                    .into_iter()
+                    .map(Certificate)
+                    .collect();
+                let key = rustls_pemfile::read_all(&mut key.as_slice())?
+                    .into_iter()
+                    .find_map(|item| match item {
+                        Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(key),
+                        _ => None,
+                    })
+                    .ok_or_else(|| GremlinError::Generic(String::from("No private key found")))?;
+                Some((chain, PrivateKey(key)))
+            }
+            Some(TlsIdentity::Pkcs12 { .. }) => {
+                return Err(GremlinError::Generic(String::from(
+                    "PKCS#12 identities are not supported by rustls",
+                )))
+            }
+        };
+
+        // Both steps of the builder have their own type, each one is completed on its own
+        let config = if tls.accept_invalid_certs {
+            let builder =
+                builder.with_custom_certificate_verifier(Arc::new(NoCertificateVerification {}));
+            match identity {
+                Some((chain, key)) => builder.with_single_cert(chain, key).map_err(tls_error)?,
+                None => builder.with_no_client_auth(),
+            }
         } else {
-            Some(async_tls::TlsConnector::new())
+            let builder = builder.with_root_certificates(roots);
+            match identity {
+                Some((chain, key)) => builder.with_single_cert(chain, key).map_err(tls_error)?,
+                None => builder.with_no_client_auth(),
+            }
+        };
+
+        Ok(async_tls::TlsConnector::from(Arc::new(config)))
+    }
+
+    pub async fn open(opts: &ConnectionOptions) -> GremlinResult<super::MaybeTlsStream> {
+        use async_tungstenite::stream::Stream;
+
+        let stream = super::TcpStream::connect((unbracketed(&opts.host), opts.port)).await?;
+        stream.set_nodelay(true)?;
+        if !opts.ssl {
+            return Ok(Stream::Plain(stream));
         }
+        let connector = match opts.tls_options.as_ref() {
+            Some(tls) => connector(tls)?,
+            None => async_tls::TlsConnector::new(),
+        };
+        Ok(Stream::Tls(
+            connector.connect(opts.server_name(), stream).await?,
+        ))
     }
 }
 
 #[cfg(feature = "tokio-runtime")]
 mod tls {
 
-    use crate::connection::ConnectionOptions;
+    use crate::connection::{unbracketed, ConnectionOptions};
+    use crate::{GremlinError, GremlinResult};
     use tokio_native_tls::TlsConnector;
 
-    pub fn connector(opts: &ConnectionOptions) -> Option<TlsConnector> {
-        opts.tls_options
-            .as_ref()
-            .and_then(|tls| tls.tls_connector().map(TlsConnector::from).ok())
+    pub async fn open(opts: &ConnectionOptions) -> GremlinResult<super::MaybeTlsStream> {
+        use async_tungstenite::{stream::Stream, tokio::TokioAdapter};
+
+        let stream = super::TcpStream::connect((unbracketed(&opts.host), opts.port)).await?;
+        stream.set_nodelay(true)?;
+        if !opts.ssl {
+            return Ok(Stream::Plain(TokioAdapter::new(stream)));
+        }
+        let connector = match opts.tls_options.as_ref() {
+            Some(tls) => tls.tls_connector(),
+            None => native_tls::TlsConnector::new(),
+        }
+        .map(TlsConnector::from)
+        .map_err(|e| GremlinError::Generic(e.to_string()))?;
+        let stream = connector
+            .connect(opts.server_name(), stream)
+            .await
+            .map_err(|e| GremlinError::Generic(e.to_string()))?;
+        Ok(Stream::Tls(TokioAdapter::new(stream)))
     }
 }
 impl Conn {
@@ -134,43 +235,46 @@ impl Conn {
         T: Into<ConnectionOptions>,
     {
         let opts = options.into();
-        let url = url::Url::parse(&opts.websocket_url()).expect("failed to parse url");
+
+        opts.check_protocol()?;
+        opts.check_authenticator()?;
+        if opts.uses_http() {
+            let stream = tls::open(&opts).await?;
+            let (sender, receiver) = channel(20);
+
+            http_loop(stream, opts, receiver);
+
+            return Ok(Conn {
+                sender,
+                valid: Arc::new(AtomicBool::new(true)),
+            });
+        }
+
+        let request = opts.websocket_request()?;
 
         let websocket_config = opts.websocket_options.as_ref().map(WebSocketConfig::from);
 
-        #[cfg(feature = "async-std-runtime")]
-        let (client, _) = {
-            connect_async_with_tls_connector_and_config(
-                url,
-                tls::connector(&opts),
-                websocket_config,
-            )
-            .map_err(|e| Arc::new(e))
-            .await?
-        };
-        #[cfg(feature = "tokio-runtime")]
-        let (client, _) = {
-            connect_async_with_tls_connector_and_config(
-                url,
-                tls::connector(&opts),
-                websocket_config,
-            )
-            .map_err(|e| Arc::new(e))
-            .await?
-        };
+        let stream = tls::open(&opts).await?;
+        let (client, _) = client_async_with_config(request, stream, websocket_config)
+            .map_err(Arc::new)
+            .await?;
 
         let (sink, stream) = client.split();
         let (sender, receiver) = channel(20);
         let requests = Arc::new(Mutex::new(HashMap::new()));
+        let valid = Arc::new(AtomicBool::new(true));
 
-        sender_loop(sink, requests.clone(), receiver);
+        sender_loop(sink, requests.clone(), valid.clone(), receiver);
 
-        receiver_loop(stream, requests.clone(), sender.clone());
+        receiver_loop(
+            stream,
+            requests,
+            valid.clone(),
+            sender.clone(),
+            opts.deserializer,
+        );
 
-        Ok(Conn {
-            sender,
-            valid: true,
-        })
+        Ok(Conn { sender, valid })
     }
 
     pub async fn send(
@@ -184,22 +288,24 @@ impl Conn {
             .send(Cmd::Msg((sender, id, payload)))
             .await
             .map_err(|e| {
-                self.valid = false;
+                self.valid.store(false, Ordering::SeqCst);
                 e
             })?;
 
         receiver
             .next()
             .await
-            .expect("It should contain the response")
+            .unwrap_or_else(|| Err(closed()))
             .map(|r| (r, receiver))
             .map_err(|e| {
-                //If there's been an websocket layer error, mark the connection as invalid
+                //If there's been an websocket or I/O layer error, mark the connection as invalid
                 match e {
                     GremlinError::WebSocket(_)
                     | GremlinError::WebSocketAsync(_)
-                    | GremlinError::WebSocketPoolAsync(_) => {
-                        self.valid = false;
+                    | GremlinError::WebSocketPoolAsync(_)
+                    | GremlinError::Io(_)
+                    | GremlinError::Closed(_) => {
+                        self.valid.store(false, Ordering::SeqCst);
                     }
                     _ => {}
                 }
@@ -208,7 +314,7 @@ impl Conn {
     }
 
     pub fn is_valid(&self) -> bool {
-        self.valid
+        self.valid.load(Ordering::SeqCst)
     }
 }
 
@@ -222,9 +328,28 @@ fn send_shutdown(conn: &mut Conn) {
     conn.sender.close_channel();
 }
 
+fn closed() -> GremlinError {
+    GremlinError::Closed(String::from("connection lost"))
+}
+
+// Mark the connection as broken and fail all of its pending requests
+async fn fail_requests<F>(requests: &Requests, valid: &AtomicBool, error: F)
+where
+    F: Fn() -> GremlinError,
+{
+    let mut guard = requests.lock().await;
+    valid.store(false, Ordering::SeqCst);
+    let senders: Vec<_> = guard.drain().map(|(_, s)| s).collect();
+    drop(guard);
+    for mut s in senders {
+        let _ = s.send(Err(error())).await;
+    }
+}
+
 fn sender_loop(
     mut sink: SplitSink<WSStream, Message>,
-    requests: Arc<Mutex<HashMap<Uuid, Sender<GremlinResult<Response>>>>>,
+    requests: Requests,
+    valid: Arc<AtomicBool>,
     mut receiver: Receiver<Cmd>,
 ) {
     task::spawn(async move {
@@ -233,20 +358,27 @@ fn sender_loop(
                 Some(item) => match item {
                     Cmd::Msg(msg) => {
                         let mut guard = requests.lock().await;
+                        // Nobody would read the response of a broken connection
+                        if !valid.load(Ordering::SeqCst) {
+                            drop(guard);
+                            let mut sender = msg.0;
+                            let _ = sender.send(Err(closed())).await;
+                            continue;
+                        }
                         guard.insert(msg.1, msg.0);
+                        drop(guard);
                         if let Err(e) = sink.send(Message::Binary(msg.2)).await {
-                            let mut sender = guard.remove(&msg.1).unwrap();
-                            sender
-                                .send(Err(GremlinError::from(Arc::new(e))))
-                                .await
-                                .expect("Failed to send error");
+                            let e = Arc::new(e);
+                            fail_requests(&requests, &valid, || e.clone().into()).await;
+                            break;
                         }
-                        drop(guard);
                     }
                     Cmd::Pong(data) => {
-                        sink.send(Message::Pong(data))
-                            .await
-                            .expect("Failed to send pong message.");
+                        if let Err(e) = sink.send(Message::Pong(data)).await {
+                            let e = Arc::new(e);
+                            fail_requests(&requests, &valid, || e.clone().into()).await;
+                            break;
+                        }
                     }
                     Cmd::Shutdown => {
                         let mut guard = requests.lock().await;
@@ -264,67 +396,266 @@ fn sender_loop(
 
 fn receiver_loop(
     mut stream: SplitStream<WSStream>,
-    requests: Arc<Mutex<HashMap<Uuid, Sender<GremlinResult<Response>>>>>,
+    requests: Requests,
+    valid: Arc<AtomicBool>,
     mut sender: Sender<Cmd>,
+    deserializer: IoProtocol,
 ) {
     task::spawn(async move {
         loop {
-            match stream.next().await {
+            let data = match stream.next().await {
+                Some(Ok(Message::Binary(data))) => data,
+                Some(Ok(Message::Text(text))) => text.into_bytes(),
+                Some(Ok(Message::Ping(data))) => {
+                    let _ = sender.send(Cmd::Pong(data)).await;
+                    continue;
+                }
+                Some(Ok(Message::Close(frame))) => {
+                    let reason = match frame {
+                        Some(frame) => format!("{} {}", frame.code, frame.reason),
+                        None => String::from("no close frame"),
+                    };
+                    fail_requests(&requests, &valid, || GremlinError::Closed(reason.clone())).await;
+                    break;
+                }
+                Some(Ok(_)) => continue,
                 Some(Err(error)) => {
-                    let mut guard = requests.lock().await;
                     let error = Arc::new(error);
-                    for s in guard.values_mut() {
-                        match s.send(Err(error.clone().into())).await {
-                            Ok(_r) => {}
-                            Err(_e) => {}
-                        }
-                    }
-                    guard.clear();
+                    fail_requests(&requests, &valid, || error.clone().into()).await;
+                    break;
                 }
-                Some(Ok(item)) => match item {
-                    Message::Binary(data) => {
-                        let response: Response = serde_json::from_slice(&data).unwrap();
-                        let mut guard = requests.lock().await;
-                        if response.status.code != 206 {
-                            let item = guard.remove(&response.request_id);
-                            drop(guard);
-                            if let Some(mut s) = item {
-                                match s.send(Ok(response)).await {
-                                    Ok(_r) => {}
-                                    Err(_e) => {}
-                                };
-                            }
-                        } else {
-                            let item = guard.get_mut(&response.request_id);
-                            if let Some(s) = item {
-                                match s.send(Ok(response)).await {
-                                    Ok(_r) => {}
-                                    Err(_e) => {}
-                                };
-                            }
-                            drop(guard);
-                        }
-                    }
-                    Message::Ping(data) => {
-                        let _ = sender.send(Cmd::Pong(data)).await;
-                    }
-                    _ => {}
-                },
                 None => {
+                    fail_requests(&requests, &valid, closed).await;
+                    break;
+                }
+            };
+
+            // The request of a frame that cannot be decoded is unknown, and the frames that
+            // follow may not be decoded either
+            let response = match deserializer.read_response(&data) {
+                Ok(response) => response,
+                Err(error) => {
+                    let message = format!("Invalid response frame: {}", error);
+                    fail_requests(&requests, &valid, || GremlinError::Generic(message.clone()))
+                        .await;
                     break;
                 }
+            };
+
+            let mut guard = requests.lock().await;
+            let item = if response.status.code != 206 {
+                guard.remove(&response.request_id)
+            } else {
+                guard.get(&response.request_id).cloned()
+            };
+            drop(guard);
+            if let Some(mut s) = item {
+                let _ = s.send(Ok(response)).await;
             }
         }
     });
 }
 
+fn http_loop(stream: MaybeTlsStream, opts: ConnectionOptions, mut receiver: Receiver<Cmd>) {
+    task::spawn(async move {
+        let mut stream = Some(stream);
+        while let Some(item) = receiver.next().await {
+            if let Cmd::Msg((mut sender, _, payload)) = item {
+                if let Err(e) = http_request(&mut stream, &opts, &payload, &mut sender).await {
+                    let _ = sender.send(Err(e)).await;
+                }
+            }
+        }
+    });
+}
+
+// Forward the response messages of a request as they are read, the stream is only kept for the
+// next request once its response has been read to the end
+async fn http_request(
+    stream: &mut Option<MaybeTlsStream>,
+    opts: &ConnectionOptions,
+    payload: &[u8],
+    sender: &mut Sender<GremlinResult<Response>>,
+) -> GremlinResult<()> {
+    let request = http::write_request(opts, payload)?;
+    let mut current = match stream.take() {
+        Some(current) => current,
+        None => tls::open(opts).await?,
+    };
+    current.write_all(&request).await?;
+    current.flush().await?;
+
+    let mut parser = http::ResponseParser::new(opts);
+    let mut chunk = [0; 8192];
+    while !parser.is_done() {
+        match parser.next(&opts.deserializer)? {
+            Some(response) => {
+                if sender.send(Ok(response)).await.is_err() {
+                    // The result set was dropped before reading all of its results
+                    return Ok(());
+                }
+            }
+            None => {
+                let read = current.read(&mut chunk).await?;
+                parser.feed(&chunk[..read], read == 0)?;
+            }
+        }
+    }
+    if parser.is_reusable() {
+        *stream = Some(current);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{read_http_request, serve_once};
+    use crate::Transport;
+    use std::io::{BufReader, Write};
+    use std::net::TcpListener;
+    use tungstenite::protocol::{frame::coding::CloseCode, CloseFrame};
+
+    const ID: &str = "41d2e28a-20a4-4ab0-b379-d810dede3786";
+
+    fn response() -> Message {
+        Message::Text(format!(
+            r#"{{"requestId": "{}", "status": {{"message": "", "code": 200}},
+            "result": {{"data": {{"@type": "g:List", "@value": ["marko"]}}}}}}"#,
+            ID
+        ))
+    }
+
+    async fn send(conn: &mut Conn) -> GremlinResult<Response> {
+        let id = Uuid::parse_str(ID).unwrap();
+        conn.send(id, b"request".to_vec()).await.map(|(r, _)| r)
+    }
 
     #[cfg_attr(feature = "async-std-runtime", async_std::test)]
     #[cfg_attr(feature = "tokio-runtime", tokio::test)]
     async fn it_should_connect() {
         Conn::connect(("localhost", 8182)).await.unwrap();
     }
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn it_should_answer_pings_and_read_text_frames() {
+        let (port, _) = serve_once(|websocket| {
+            websocket.read().unwrap();
+            websocket.send(Message::Ping(b"ping".to_vec())).unwrap();
+            websocket.send(response()).unwrap();
+            let mut ponged = false;
+            while let Ok(message) = websocket.read() {
+                match message {
+                    Message::Pong(data) => ponged = data == b"ping",
+                    Message::Binary(_) if ponged => websocket.send(response()).unwrap(),
+                    _ => {}
+                }
+            }
+        });
+        let mut conn = Conn::connect(("127.0.0.1", port)).await.unwrap();
+
+        assert_eq!(200, send(&mut conn).await.unwrap().status.code);
+        // Only answered once the peer got its pong
+        assert_eq!(200, send(&mut conn).await.unwrap().status.code);
+        assert!(conn.is_valid());
+    }
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn it_should_fail_requests_on_invalid_frames() {
+        let mut seed = 7_u32;
+        let mut garbage = || {
+            (0..256)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    (seed >> 16) as u8
+                })
+                .collect::<Vec<u8>>()
+        };
+        let frames = vec![
+            Message::Binary(vec![]),
+            Message::Binary(vec![0x81, 0x00]),
+            Message::Binary(garbage()),
+            Message::Binary(garbage()),
+            Message::Text(String::from("{")),
+            Message::Text(String::from(r#"{"requestId": 1}"#)),
+            Message::Text(String::from_utf8_lossy(&garbage()).into_owned()),
+        ];
+
+        for frame in frames {
+            let (port, _) = serve_once(move |websocket| {
+                websocket.read().unwrap();
+                websocket.send(frame).unwrap();
+                while websocket.read().is_ok() {}
+            });
+            let mut conn = Conn::connect(("127.0.0.1", port)).await.unwrap();
+
+            assert!(send(&mut conn).await.is_err());
+            assert!(!conn.is_valid());
+            // Later requests fail instead of waiting for a response forever
+            assert!(send(&mut conn).await.is_err());
+        }
+    }
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn it_should_fail_requests_on_close() {
+        let (port, _) = serve_once(|websocket| {
+            websocket.read().unwrap();
+            websocket
+                .close(Some(CloseFrame {
+                    code: CloseCode::Away,
+                    reason: "shutting down".into(),
+                }))
+                .unwrap();
+            while websocket.read().is_ok() {}
+        });
+        let mut conn = Conn::connect(("127.0.0.1", port)).await.unwrap();
+
+        match send(&mut conn).await {
+            Err(GremlinError::Closed(reason)) => assert_eq!("1001 shutting down", reason),
+            other => panic!("Unexpected {:?}", other),
+        }
+        assert!(!conn.is_valid());
+
+        // Dropped without a close frame
+        let (port, _) = serve_once(|websocket| {
+            websocket.read().unwrap();
+        });
+        let mut conn = Conn::connect(("127.0.0.1", port)).await.unwrap();
+
+        assert!(send(&mut conn).await.is_err());
+        assert!(!conn.is_valid());
+    }
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn it_should_connect_to_ipv6_literals() {
+        let listener = TcpListener::bind("[::1]:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut stream = BufReader::new(stream);
+            read_http_request(&mut stream);
+            let body = response().into_data();
+            let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+            let stream = stream.get_mut();
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(&body).unwrap();
+        });
+        let options = ConnectionOptions::builder()
+            .host("[::1]")
+            .port(port)
+            .transport(Transport::Http)
+            .build();
+        let (id, message) = options
+            .serializer
+            .build_message("eval", "", HashMap::new(), None)
+            .unwrap();
+        let mut conn = Conn::connect(options).await.unwrap();
+
+        let (response, _) = conn.send(id, message).await.unwrap();
+        assert_eq!(200, response.status.code);
+    }
 }
@@ -1,12 +1,11 @@
 use mobc::Manager;
 
 use crate::aio::connection::Conn;
+use crate::authenticator::authenticate_async;
 use crate::connection::ConnectionOptions;
 use crate::error::GremlinError;
-use crate::message::{message_with_args, message_with_args_and_uuid, message_with_args_v2};
-use crate::{GValue, GraphSON};
+use crate::GValue;
 use async_trait::async_trait;
-use base64::encode;
 use std::collections::HashMap;
 
 #[derive(Debug)]
@@ -40,77 +39,23 @@ impl Manager for GremlinConnectionManager {
             String::from("language"),
             GValue::String(String::from("gremlin-groovy")),
         );
-        let args = self.options.serializer.write(&GValue::from(args))?;
 
-        let message = match self.options.serializer {
-            GraphSON::V2 => message_with_args_v2(String::from("eval"), String::default(), args),
-            GraphSON::V3 => message_with_args(String::from("eval"), String::default(), args),
-        };
-
-        let id = message.id().clone();
-        let msg = serde_json::to_string(&message).map_err(GremlinError::from)?;
-
-        let content_type = self.options.serializer.content_type();
-
-        let payload = String::from("") + content_type + &msg;
-        let mut binary = payload.into_bytes();
-        binary.insert(0, content_type.len() as u8);
-
-        let (response, _receiver) = conn.send(id, binary).await?;
+        let (id, binary) = self
+            .options
+            .serializer
+            .build_message("eval", "", args, None)?;
 
+        let response = conn.send(id, binary).await?;
+        let challenged = response.0.status.code == 407;
+        let (response, _receiver) = authenticate_async(&self.options, &mut conn, response).await?;
         match response.status.code {
             200 | 206 => Ok(conn),
             204 => Ok(conn),
-            407 => match &self.options.credentials {
-                Some(c) => {
-                    let mut args = HashMap::new();
-
-                    args.insert(
-                        String::from("sasl"),
-                        GValue::String(encode(&format!("\0{}\0{}", c.username, c.password))),
-                    );
-
-                    let args = self.options.serializer.write(&GValue::from(args))?;
-                    let message = message_with_args_and_uuid(
-                        String::from("authentication"),
-                        String::from("traversal"),
-                        response.request_id,
-                        args,
-                    );
-
-                    let id = message.id().clone();
-                    let msg = serde_json::to_string(&message).map_err(GremlinError::from)?;
-
-                    let content_type = self.options.serializer.content_type();
-                    let payload = String::from("") + content_type + &msg;
-
-                    let mut binary = payload.into_bytes();
-                    binary.insert(0, content_type.len() as u8);
-
-                    let (response, _receiver) = conn.send(id, binary).await?;
-
-                    match response.status.code {
-                        200 | 206 => Ok(conn),
-                        204 => Ok(conn),
-                        401 => Ok(conn),
-                        // 401 is actually a username/password incorrect error, but if not
-                        // not returned as okay, the pool loops infinitely trying
-                        // to authenticate.
-                        _ => Err(GremlinError::Request((
-                            response.status.code,
-                            response.status.message,
-                        ))),
-                    }
-                }
-                None => Err(GremlinError::Request((
-                    response.status.code,
-                    response.status.message,
-                ))),
-            },
-            _ => Err(GremlinError::Request((
-                response.status.code,
-                response.status.message,
-            ))),
+            // 401 is actually a username/password incorrect error, but if not
+            // not returned as okay, the pool loops infinitely trying
+            // to authenticate.
+            401 if challenged => Ok(conn),
+            _ => Err(response.status.into_error()),
         }
     }
 
@@ -3,18 +3,30 @@ use crate::aio::GremlinClient;
 use crate::conversion::FromGValue;
 use crate::process::traversal::remote::Terminator;
 use crate::process::traversal::GraphTraversal;
-use crate::GremlinResult;
+use crate::{GremlinResult, RequestOptions};
 use futures::future::{BoxFuture, FutureExt};
 use futures::StreamExt;
 
 #[derive(Clone)]
 pub struct AsyncTerminator {
     client: GremlinClient,
+    options: RequestOptions,
 }
 
 impl AsyncTerminator {
     pub fn new(client: GremlinClient) -> AsyncTerminator {
-        AsyncTerminator { client }
+        AsyncTerminator {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Submit traversals with the given [RequestOptions](RequestOptions)
+    pub fn with_options(&self, options: RequestOptions) -> AsyncTerminator {
+        AsyncTerminator {
+            client: self.client.clone(),
+            options,
+        }
     }
 }
 
@@ -83,10 +95,11 @@ impl<T: FromGValue + std::marker::Send + 'static> Terminator<T> for AsyncTermina
         E: Terminator<T>,
     {
         let client = self.client.clone();
+        let options = self.options.clone();
         let bytecode = traversal.bytecode().clone();
 
         async move {
-            let stream = client.submit_traversal(&bytecode).await?;
+            let stream = client.submit_traversal(&bytecode, &options).await?;
 
             Ok(RemoteTraversalStream::new(stream))
         }
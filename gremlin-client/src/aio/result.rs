@@ -1,12 +1,14 @@
+use crate::aio::pool::GremlinConnectionManager;
 use crate::aio::GremlinClient;
 use crate::message::Response;
-use crate::structure::GValue;
+use crate::structure::{GValue, Map, ResponseFrame};
 use crate::GremlinResult;
 use futures::Stream;
 
 use core::task::Context;
 use core::task::Poll;
 use futures::channel::mpsc::Receiver;
+use mobc::Connection;
 use pin_project_lite::pin_project;
 use std::collections::VecDeque;
 use std::pin::Pin;
@@ -16,8 +18,12 @@ pin_project! {
         client: GremlinClient,
         results: VecDeque<GValue>,
         response: Response,
+        frames: Vec<ResponseFrame>,
         #[pin]
         receiver: Receiver<GremlinResult<Response>>,
+        // An HTTP connection carries one response at a time, it goes back to the pool once the
+        // last message of the response was read
+        conn: Option<Connection<GremlinConnectionManager>>,
     }
 }
 
@@ -37,14 +43,37 @@ impl GResultSet {
         results: VecDeque<GValue>,
         response: Response,
         receiver: Receiver<GremlinResult<Response>>,
+        conn: Option<Connection<GremlinConnectionManager>>,
     ) -> GResultSet {
         GResultSet {
             client,
             results,
+            frames: vec![ResponseFrame::new(&response)],
             response,
             receiver,
+            conn,
         }
     }
+
+    /// Status attributes of the last response frame read, see
+    /// [ResponseFrame::status_attributes](ResponseFrame::status_attributes).
+    pub fn status_attributes(&self) -> &Map {
+        &self.response.status.attributes
+    }
+
+    /// Result meta of the last response frame read, see
+    /// [ResponseFrame::result_meta](ResponseFrame::result_meta).
+    pub fn result_meta(&self) -> &Map {
+        &self.response.result.meta
+    }
+
+    /// Every response frame read so far, in order.
+    ///
+    /// The next partial (206) frame is only read once the results of the current one are
+    /// consumed.
+    pub fn frames(&self) -> &[ResponseFrame] {
+        &self.frames
+    }
 }
 
 impl Stream for GResultSet {
@@ -58,26 +87,45 @@ impl Stream for GResultSet {
                 None => {
                     if this.response.status.code == 206 {
                         match futures::ready!(this.receiver.as_mut().poll_next(cx)) {
-                            Some(Ok(response)) => {
-                                let results: VecDeque<GValue> = this
-                                    .client
-                                    .options
-                                    .serializer
-                                    .read(&response.result.data)?
+                            Some(Ok(response))
+                                if !matches!(response.status.code, 200 | 204 | 206) =>
+                            {
+                                // A streamed response may fail after its first results
+                                this.frames.push(ResponseFrame::new(&response));
+                                *this.response = response;
+                                *this.conn = None;
+                                return Poll::Ready(Some(Err(this
+                                    .response
+                                    .status
+                                    .clone()
+                                    .into_error())));
+                            }
+                            Some(Ok(mut response)) => {
+                                let results: VecDeque<GValue> = response
+                                    .result
+                                    .data
+                                    .take()
                                     .map(|v| v.into())
                                     .unwrap_or_else(VecDeque::new);
 
+                                if response.status.code != 206 {
+                                    *this.conn = None;
+                                }
                                 *this.results = results;
+                                this.frames.push(ResponseFrame::new(&response));
                                 *this.response = response;
                             }
                             Some(Err(e)) => {
+                                *this.conn = None;
                                 return Poll::Ready(Some(Err(e)));
                             }
                             None => {
+                                *this.conn = None;
                                 return Poll::Ready(None);
                             }
                         }
                     } else {
+                        *this.conn = None;
                         return Poll::Ready(None);
                     }
                 }
@@ -85,3 +133,55 @@ impl Stream for GResultSet {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::aio::GremlinClient;
+    use crate::test_support::{response, serve};
+    use crate::{ConnectionOptions, GValue};
+    use futures::StreamExt;
+    use serde_json::json;
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn it_should_keep_the_metadata_of_every_frame() {
+        let port = serve(|request| {
+            let id = request["requestId"].as_str().unwrap();
+            [206, 206, 200]
+                .iter()
+                .enumerate()
+                .map(|(frame, code)| {
+                    let attributes = json!({ "frame": frame.to_string() });
+                    response(id, *code, json!([frame.to_string()]), attributes, json!({}))
+                })
+                .collect()
+        });
+        let options = ConnectionOptions::builder()
+            .host("127.0.0.1")
+            .port(port)
+            .pool_size(1)
+            .build();
+        let client = GremlinClient::connect(options).await.unwrap();
+
+        let mut results = client.execute("g.inject(1)", &[]).await.unwrap();
+        let mut values = vec![];
+        while let Some(value) = results.next().await {
+            values.push(value.unwrap());
+        }
+        assert_eq!(3, values.len());
+
+        let frames: Vec<_> = results
+            .frames()
+            .iter()
+            .map(|frame| frame.status_attributes().get("frame").cloned())
+            .collect();
+        assert_eq!(
+            vec![
+                Some(GValue::from("0")),
+                Some(GValue::from("1")),
+                Some(GValue::from("2"))
+            ],
+            frames
+        );
+    }
+}
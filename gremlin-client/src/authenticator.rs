@@ -0,0 +1,319 @@
+#[cfg(feature = "async_gremlin")]
+use crate::aio::connection::Conn;
+use crate::connection::{Connection, ConnectionOptions};
+use crate::message::Response;
+use crate::structure::Map;
+use crate::{GValue, GremlinError, GremlinResult};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+#[cfg(feature = "async_gremlin")]
+use futures::channel::mpsc::Receiver;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+/// Answers the authentication challenges (status 407) of the server.
+///
+/// The arguments returned are sent in an `authentication` request, a mechanism needing more
+/// than one round trip receives the following challenges the same way. Challenges are only
+/// exchanged over the WebSocket transport, HTTP requests carry
+/// [credentials](crate::ConnectionOptionsBuilder::credentials) as basic authentication.
+///
+/// ```
+/// use gremlin_client::{Authenticator, Challenge, ConnectionOptions, GValue, GremlinResult};
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug)]
+/// struct Token(String);
+///
+/// impl Authenticator for Token {
+///     fn authenticate(&self, _challenge: &Challenge) -> GremlinResult<HashMap<String, GValue>> {
+///         let mut args = HashMap::new();
+///         args.insert(String::from("saslMechanism"), GValue::from("TOKEN"));
+///         args.insert(String::from("sasl"), GValue::from(self.0.clone()));
+///         Ok(args)
+///     }
+/// }
+///
+/// let options = ConnectionOptions::builder()
+///     .authenticator(Token(String::from("secret")))
+///     .build();
+/// ```
+pub trait Authenticator: Debug + Send + Sync {
+    fn authenticate(&self, challenge: &Challenge) -> GremlinResult<HashMap<String, GValue>>;
+}
+
+/// An authentication challenge sent by the server.
+#[derive(Debug)]
+pub struct Challenge<'a> {
+    response: &'a Response,
+}
+
+impl Challenge<'_> {
+    pub fn attributes(&self) -> &Map {
+        &self.response.status.attributes
+    }
+
+    /// The data of the challenge, for SASL mechanisms exchanging several messages
+    pub fn data(&self) -> Option<&GValue> {
+        self.response.result.data.as_ref()
+    }
+}
+
+/// The SASL `PLAIN` mechanism, used by [credentials](crate::ConnectionOptionsBuilder::credentials).
+#[derive(Clone, Debug)]
+pub struct PlainAuthenticator {
+    username: String,
+    password: String,
+}
+
+impl PlainAuthenticator {
+    pub fn new(username: &str, password: &str) -> PlainAuthenticator {
+        PlainAuthenticator {
+            username: String::from(username),
+            password: String::from(password),
+        }
+    }
+}
+
+impl Authenticator for PlainAuthenticator {
+    fn authenticate(&self, _challenge: &Challenge) -> GremlinResult<HashMap<String, GValue>> {
+        let mut args = HashMap::new();
+        args.insert(
+            String::from("sasl"),
+            GValue::String(STANDARD.encode(format!("\0{}\0{}", self.username, self.password))),
+        );
+        Ok(args)
+    }
+}
+
+// Challenges answered for a single request, past them a server that keeps challenging the client
+// fails the request rather than keeping its connection busy.
+const MAX_ROUNDS: usize = 8;
+
+/// Build the `authentication` request answering a 407 response, the response status is
+/// returned as an error when no authenticator is configured.
+fn authentication_message(
+    options: &ConnectionOptions,
+    response: &Response,
+) -> GremlinResult<(Uuid, Vec<u8>)> {
+    let authenticator = match &options.authenticator {
+        Some(authenticator) => authenticator,
+        None => return Err(response.status.clone().into_error()),
+    };
+    let args = authenticator.authenticate(&Challenge { response })?;
+
+    options.serializer.build_message(
+        "authentication",
+        "traversal",
+        args,
+        Some(response.request_id),
+    )
+}
+
+// The authentication rounds of a request
+struct Rounds<'a> {
+    options: &'a ConnectionOptions,
+    count: usize,
+}
+
+impl Rounds<'_> {
+    fn new(options: &ConnectionOptions) -> Rounds<'_> {
+        Rounds { options, count: 0 }
+    }
+
+    // The request answering `response`, `None` once the server sent anything but a challenge
+    fn answer(&mut self, response: &Response) -> GremlinResult<Option<(Uuid, Vec<u8>)>> {
+        if response.status.code != 407 {
+            return Ok(None);
+        }
+        if self.count == MAX_ROUNDS {
+            return Err(GremlinError::Authentication(format!(
+                "still challenged after {} rounds",
+                MAX_ROUNDS
+            )));
+        }
+        self.count += 1;
+        authentication_message(self.options, response).map(Some)
+    }
+}
+
+/// Answer the authentication challenges of the server until it sends another response, which
+/// is returned.
+pub(crate) fn authenticate(
+    options: &ConnectionOptions,
+    conn: &mut Connection,
+    mut response: Response,
+) -> GremlinResult<Response> {
+    let mut rounds = Rounds::new(options);
+    while let Some((_, message)) = rounds.answer(&response)? {
+        conn.send(message)?;
+        response = conn.recv()?;
+    }
+    Ok(response)
+}
+
+/// [authenticate](authenticate) for the async connections, the response is returned with the
+/// receiver of its following frames.
+#[cfg(feature = "async_gremlin")]
+pub(crate) async fn authenticate_async(
+    options: &ConnectionOptions,
+    conn: &mut Conn,
+    response: (Response, Receiver<GremlinResult<Response>>),
+) -> GremlinResult<(Response, Receiver<GremlinResult<Response>>)> {
+    let (mut response, mut receiver) = response;
+    let mut rounds = Rounds::new(options);
+    while let Some((id, message)) = rounds.answer(&response)? {
+        let (next, next_receiver) = conn.send(id, message).await?;
+        response = next;
+        receiver = next_receiver;
+    }
+    Ok((response, receiver))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{authenticate, authentication_message, Authenticator, Challenge, MAX_ROUNDS};
+    use crate::connection::{Connection, Transport};
+    use crate::message::{ReponseStatus, Response, ResponseResult};
+    use crate::structure::Map;
+    use crate::test_support::{response, serve};
+    use crate::{ConnectionOptions, GValue, GremlinClient, GremlinError, GremlinResult};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn challenge() -> Response {
+        Response {
+            request_id: Uuid::new_v4(),
+            result: ResponseResult {
+                data: None,
+                meta: Map::default(),
+            },
+            status: ReponseStatus {
+                code: 407,
+                message: String::new(),
+                attributes: Map::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn it_should_answer_with_sasl_plain() {
+        let options = ConnectionOptions::builder()
+            .credentials("stephen", "password")
+            .build();
+        let response = challenge();
+
+        let (id, message) = authentication_message(&options, &response).unwrap();
+        let message = String::from_utf8(message).unwrap();
+
+        assert_eq!(response.request_id, id);
+        assert!(message.contains(r#""op":"authentication""#), "{}", message);
+        // base64 of "\0stephen\0password"
+        assert!(message.contains("AHN0ZXBoZW4AcGFzc3dvcmQ="), "{}", message);
+    }
+
+    #[test]
+    fn it_should_fail_without_authenticator() {
+        match authentication_message(&ConnectionOptions::default(), &challenge()) {
+            Err(GremlinError::Server { code, .. }) => assert_eq!(407, code.code()),
+            other => panic!("Unexpected {:?}", other),
+        }
+    }
+
+    // Answers the challenge of each round with its number
+    #[derive(Debug)]
+    struct Rounds;
+
+    impl Authenticator for Rounds {
+        fn authenticate(&self, challenge: &Challenge) -> GremlinResult<HashMap<String, GValue>> {
+            let round = challenge.attributes().get("round").cloned();
+            let round = round.unwrap_or(GValue::Null).take::<String>()?;
+            let mut args = HashMap::new();
+            args.insert(
+                String::from("sasl"),
+                GValue::String(format!("answer-{}", round)),
+            );
+            Ok(args)
+        }
+    }
+
+    // A server challenging every request `rounds` times before answering it
+    fn serve_rounds(rounds: usize) -> u16 {
+        serve(move |request| {
+            let id = request["requestId"].as_str().unwrap();
+            let args = request["args"].to_string();
+            let answered = args.find("answer-").map(|at| {
+                let round: String = args[at + 7..]
+                    .chars()
+                    .take_while(char::is_ascii_digit)
+                    .collect();
+                round.parse::<usize>().unwrap()
+            });
+
+            let (code, round) = match (request["op"].as_str(), answered) {
+                (Some("authentication"), Some(round)) if round == rounds => (200, 0),
+                (Some("authentication"), Some(round)) => (407, round + 1),
+                (Some("authentication"), None) => (401, 0),
+                _ => (407, 1),
+            };
+            let attributes = json!({ "round": round.to_string() });
+            vec![response(id, code, json!(["ok"]), attributes, json!({}))]
+        })
+    }
+
+    fn options(port: u16) -> ConnectionOptions {
+        ConnectionOptions::builder()
+            .host("127.0.0.1")
+            .port(port)
+            .pool_size(1)
+            .authenticator(Rounds)
+            .build()
+    }
+
+    #[test]
+    fn it_should_answer_every_challenge_round() {
+        let client = GremlinClient::connect(options(serve_rounds(2))).unwrap();
+
+        let results: Vec<GValue> = client
+            .execute("g.inject('ok')", &[])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(vec![GValue::from("ok")], results);
+    }
+
+    #[test]
+    fn it_should_give_up_on_endless_challenges() {
+        let options = options(serve_rounds(usize::MAX));
+        let mut conn = Connection::connect(options.clone()).unwrap();
+        let (_, message) = options
+            .serializer
+            .build_message("eval", "", HashMap::new(), None)
+            .unwrap();
+        conn.send(message).unwrap();
+        let response = conn.recv().unwrap();
+
+        match authenticate(&options, &mut conn, response) {
+            Err(GremlinError::Authentication(message)) => {
+                assert!(message.contains(&MAX_ROUNDS.to_string()), "{}", message)
+            }
+            other => panic!("Unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_should_reject_custom_authenticators_over_http() {
+        let options = ConnectionOptions::builder()
+            .transport(Transport::Http)
+            .authenticator(Rounds)
+            .build();
+
+        match Connection::connect(options) {
+            Err(GremlinError::Generic(message)) => assert!(message.contains("WebSocket")),
+            other => panic!("Unexpected {:?}", other.map(|_| ())),
+        }
+    }
+}
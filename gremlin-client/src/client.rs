@@ -1,16 +1,14 @@
-use crate::io::GraphSON;
-use crate::message::{
-    message_with_args, message_with_args_and_uuid, message_with_args_v2, Message, Response,
-};
+use crate::authenticator::authenticate;
+use crate::io::{GraphSON, IoProtocol};
+use crate::message::{message_with_args, Message, Response};
 use crate::pool::GremlinConnectionManager;
 use crate::process::traversal::Bytecode;
 use crate::ToGValue;
-use crate::{ConnectionOptions, GremlinError, GremlinResult};
+use crate::{ConnectionOptions, GremlinError, GremlinResult, RequestOptions};
 use crate::{GResultSet, GValue};
-use base64::encode;
 use r2d2::Pool;
-use serde::Serialize;
 use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
 
 type SessionedClient = GremlinClient;
 
@@ -19,14 +17,11 @@ impl SessionedClient {
         if let Some(session_name) = self.session.take() {
             let mut args = HashMap::new();
             args.insert(String::from("session"), GValue::from(session_name.clone()));
-            let args = self.options.serializer.write(&GValue::from(args))?;
 
-            let processor = "session".to_string();
-
-            let message = match self.options.serializer {
-                GraphSON::V2 => message_with_args_v2(String::from("close"), processor, args),
-                GraphSON::V3 => message_with_args(String::from("close"), processor, args),
-            };
+            let message = self
+                .options
+                .serializer
+                .build_message("close", "session", args, None)?;
 
             let conn = self.pool.get()?;
 
@@ -93,6 +88,19 @@ impl GremlinClient {
         script: T,
         params: &[(&str, &dyn ToGValue)],
     ) -> GremlinResult<GResultSet>
+    where
+        T: Into<String>,
+    {
+        self.execute_with_options(script, params, &RequestOptions::default())
+    }
+
+    /// Execute a script with [RequestOptions](RequestOptions) such as an evaluation timeout.
+    pub fn execute_with_options<T>(
+        &self,
+        script: T,
+        params: &[(&str, &dyn ToGValue)],
+        options: &RequestOptions,
+    ) -> GremlinResult<GResultSet>
     where
         T: Into<String>,
     {
@@ -127,46 +135,40 @@ impl GremlinClient {
             args.insert(String::from("session"), GValue::from(session_name.clone()));
         }
 
-        let args = self.options.serializer.write(&GValue::from(args))?;
+        options.apply(&mut args);
 
         let processor = if self.session.is_some() {
-            "session".to_string()
+            "session"
         } else {
-            String::default()
+            ""
         };
 
-        let message = match self.options.serializer {
-            GraphSON::V2 => message_with_args_v2(String::from("eval"), processor, args),
-            GraphSON::V3 => message_with_args(String::from("eval"), processor, args),
-        };
+        let message = self
+            .options
+            .serializer
+            .build_message("eval", processor, args, None)?;
 
         let conn = self.pool.get()?;
 
         self.send_message(conn, message)
     }
 
-    pub(crate) fn write_message<T: Serialize>(
+    pub(crate) fn write_message(
         &self,
         conn: &mut r2d2::PooledConnection<GremlinConnectionManager>,
-        msg: Message<T>,
+        msg: (Uuid, Vec<u8>),
     ) -> GremlinResult<()> {
-        let message = self.build_message(msg)?;
-
-        let content_type = self.options.serializer.content_type();
-        let payload = String::from("") + content_type + &message;
-
-        let mut binary = payload.into_bytes();
-        binary.insert(0, content_type.len() as u8);
+        let (_, payload) = msg;
 
-        conn.send(binary)?;
+        conn.send(payload)?;
 
         Ok(())
     }
 
-    pub(crate) fn send_message<T: Serialize>(
+    pub(crate) fn send_message(
         &self,
         mut conn: r2d2::PooledConnection<GremlinConnectionManager>,
-        msg: Message<T>,
+        msg: (Uuid, Vec<u8>),
     ) -> GremlinResult<GResultSet> {
         self.write_message(&mut conn, msg)?;
 
@@ -175,10 +177,30 @@ impl GremlinClient {
         Ok(GResultSet::new(self.clone(), results, response, conn))
     }
 
+    /// Generate the GraphSON request message for the given bytecode.
+    ///
+    /// The configured GraphSON version is used, falling back to [GraphSON::V3] when a
+    /// non GraphSON serializer has been configured.
     pub fn generate_message(
         &self,
         bytecode: &Bytecode,
     ) -> GremlinResult<Message<serde_json::Value>> {
+        let args = self.traversal_args(bytecode);
+
+        let graphson = match &self.options.serializer {
+            IoProtocol::GraphSON(graphson) => graphson.clone(),
+            _ => GraphSON::V3,
+        };
+        let args = graphson.write(&GValue::from(args))?;
+
+        Ok(message_with_args(
+            String::from("bytecode"),
+            String::from("traversal"),
+            args,
+        ))
+    }
+
+    fn traversal_args(&self, bytecode: &Bytecode) -> HashMap<String, GValue> {
         let mut args = HashMap::new();
 
         args.insert(String::from("gremlin"), GValue::Bytecode(bytecode.clone()));
@@ -196,17 +218,21 @@ impl GremlinClient {
 
         args.insert(String::from("aliases"), GValue::from(aliases));
 
-        let args = self.options.serializer.write(&GValue::from(args))?;
-
-        Ok(message_with_args(
-            String::from("bytecode"),
-            String::from("traversal"),
-            args,
-        ))
+        args
     }
 
-    pub(crate) fn submit_traversal(&self, bytecode: &Bytecode) -> GremlinResult<GResultSet> {
-        let message = self.generate_message(bytecode)?;
+    pub(crate) fn submit_traversal(
+        &self,
+        bytecode: &Bytecode,
+        options: &RequestOptions,
+    ) -> GremlinResult<GResultSet> {
+        let mut args = self.traversal_args(bytecode);
+        options.apply(&mut args);
+
+        let message = self
+            .options
+            .serializer
+            .build_message("bytecode", "traversal", args, None)?;
 
         let conn = self.pool.get()?;
 
@@ -217,54 +243,22 @@ impl GremlinClient {
         &self,
         conn: &mut r2d2::PooledConnection<GremlinConnectionManager>,
     ) -> GremlinResult<(Response, VecDeque<GValue>)> {
-        let result = conn.recv()?;
-        let response: Response = serde_json::from_slice(&result)?;
+        let response = conn.recv()?;
+        let mut response = authenticate(&self.options, conn, response)?;
 
         match response.status.code {
             200 | 206 => {
-                let results: VecDeque<GValue> = self
-                    .options
-                    .deserializer
-                    .read(&response.result.data)?
+                let results: VecDeque<GValue> = response
+                    .result
+                    .data
+                    .take()
                     .map(|v| v.into())
                     .unwrap_or_else(VecDeque::new);
 
                 Ok((response, results))
             }
             204 => Ok((response, VecDeque::new())),
-            407 => match &self.options.credentials {
-                Some(c) => {
-                    let mut args = HashMap::new();
-
-                    args.insert(
-                        String::from("sasl"),
-                        GValue::String(encode(&format!("\0{}\0{}", c.username, c.password))),
-                    );
-
-                    let args = self.options.serializer.write(&GValue::from(args))?;
-                    let message = message_with_args_and_uuid(
-                        String::from("authentication"),
-                        String::from("traversal"),
-                        response.request_id,
-                        args,
-                    );
-
-                    self.write_message(conn, message)?;
-
-                    self.read_response(conn)
-                }
-                None => Err(GremlinError::Request((
-                    response.status.code,
-                    response.status.message,
-                ))),
-            },
-            _ => Err(GremlinError::Request((
-                response.status.code,
-                response.status.message,
-            ))),
+            _ => Err(response.status.into_error()),
         }
     }
-    fn build_message<T: Serialize>(&self, msg: Message<T>) -> GremlinResult<String> {
-        serde_json::to_string(&msg).map_err(GremlinError::from)
-    }
 }
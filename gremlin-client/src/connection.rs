@@ -1,16 +1,29 @@
 use std::{net::TcpStream, sync::Arc, time::Duration};
 
-use crate::{GraphSON, GremlinError, GremlinResult};
-use native_tls::TlsConnector;
+use crate::authenticator::{Authenticator, PlainAuthenticator};
+use crate::message::Response;
+#[cfg(feature = "aws-sigv4")]
+use crate::sigv4::SigV4Signer;
+use crate::{http, GraphSON, GremlinError, GremlinResult, IoProtocol};
+use native_tls::{Certificate, Identity, Protocol as TlsProtocol, TlsConnector};
+use std::io::{Read, Write};
 use tungstenite::{
-    client::{uri_mode, IntoClientRequest},
-    client_tls_with_config,
+    client::{client_with_config, IntoClientRequest},
+    handshake::client::Request,
+    http::{
+        uri::{InvalidUri, Uri},
+        HeaderName, HeaderValue,
+    },
     protocol::WebSocketConfig,
-    stream::{MaybeTlsStream, Mode, NoDelay},
-    Connector, Message, WebSocket,
+    stream::{MaybeTlsStream, NoDelay},
+    Message, WebSocket,
 };
 
-struct ConnectionStream(WebSocket<MaybeTlsStream<TcpStream>>);
+#[allow(clippy::large_enum_variant)]
+enum ConnectionStream {
+    WebSocket(WebSocket<MaybeTlsStream<TcpStream>>),
+    Http(HttpStream),
+}
 
 impl std::fmt::Debug for ConnectionStream {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -20,56 +33,160 @@ impl std::fmt::Debug for ConnectionStream {
 
 impl ConnectionStream {
     fn connect(options: ConnectionOptions) -> GremlinResult<Self> {
-        let connector = match options.tls_options.as_ref() {
-            Some(option) => Some(Connector::NativeTls(
-                option
-                    .tls_connector()
-                    .map_err(|e| GremlinError::Generic(e.to_string()))?,
-            )),
-            _ => None,
-        };
+        options.check_protocol()?;
+        options.check_authenticator()?;
+        if options.uses_http() {
+            return Ok(ConnectionStream::Http(HttpStream::connect(options)?));
+        }
 
-        let request = options
-            .websocket_url()
-            .into_client_request()
-            .map_err(|e| GremlinError::Generic(e.to_string()))?;
-        let uri = request.uri();
-        let mode = uri_mode(uri).map_err(|e| GremlinError::Generic(e.to_string()))?;
-        let host = request
-            .uri()
-            .host()
-            .ok_or_else(|| GremlinError::Generic("No Hostname".into()))?;
-        let port = uri.port_u16().unwrap_or(match mode {
-            Mode::Plain => 80,
-            Mode::Tls => 443,
-        });
-        let mut stream = TcpStream::connect((host, port))
-            .map_err(|e| GremlinError::Generic(format!("Unable to connect {e:?}")))?;
-        NoDelay::set_nodelay(&mut stream, true)
-            .map_err(|e| GremlinError::Generic(e.to_string()))?;
+        let request = options.websocket_request()?;
+        let stream = open_stream(&options)?;
 
         let websocket_config = options
             .websocket_options
             .as_ref()
             .map(WebSocketConfig::from);
 
-        let (client, _response) =
-            client_tls_with_config(options.websocket_url(), stream, websocket_config, connector)
-                .map_err(|e| GremlinError::Generic(e.to_string()))?;
+        let (client, _response) = client_with_config(request, stream, websocket_config)
+            .map_err(|e| GremlinError::Generic(e.to_string()))?;
 
-        Ok(ConnectionStream(client))
+        Ok(ConnectionStream::WebSocket(client))
     }
 
     fn send(&mut self, payload: Vec<u8>) -> GremlinResult<()> {
-        self.0
-            .write_message(Message::Binary(payload))
-            .map_err(GremlinError::from)
+        match self {
+            ConnectionStream::WebSocket(client) => client
+                .write_message(Message::Binary(payload))
+                .map_err(GremlinError::from),
+            ConnectionStream::Http(client) => client.send(payload),
+        }
+    }
+
+    fn recv(&mut self, deserializer: &IoProtocol) -> GremlinResult<Response> {
+        match self {
+            ConnectionStream::WebSocket(client) => loop {
+                // Pings are answered by tungstenite on the next read or write
+                match client.read_message()? {
+                    Message::Binary(binary) => return deserializer.read_response(&binary),
+                    Message::Text(text) => return deserializer.read_response(text.as_bytes()),
+                    Message::Close(frame) => {
+                        // Best effort to complete the close handshake
+                        let _ = client.flush();
+                        return Err(GremlinError::Closed(match frame {
+                            Some(frame) => format!("{} {}", frame.code, frame.reason),
+                            None => String::from("no close frame"),
+                        }));
+                    }
+                    Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+                }
+            },
+            ConnectionStream::Http(client) => client.recv(),
+        }
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> GremlinResult<()> {
+        let stream = match self {
+            ConnectionStream::WebSocket(client) => Some(client.get_ref()),
+            ConnectionStream::Http(client) => client
+                .response
+                .as_ref()
+                .map(|(stream, _)| stream)
+                .or(client.stream.as_ref()),
+        };
+        match stream {
+            Some(MaybeTlsStream::Plain(stream)) => stream.set_read_timeout(timeout)?,
+            Some(MaybeTlsStream::NativeTls(stream)) => {
+                stream.get_ref().set_read_timeout(timeout)?
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// `host` without the brackets of an IPv6 literal, which the socket and TLS APIs do not take.
+pub(crate) fn unbracketed(host: &str) -> &str {
+    host.strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host)
+}
+
+fn tcp_connect(host: &str, port: u16) -> GremlinResult<TcpStream> {
+    let mut stream = TcpStream::connect((unbracketed(host), port))
+        .map_err(|e| GremlinError::Generic(format!("Unable to connect {e:?}")))?;
+    NoDelay::set_nodelay(&mut stream, true).map_err(|e| GremlinError::Generic(e.to_string()))?;
+    Ok(stream)
+}
+
+fn open_stream(options: &ConnectionOptions) -> GremlinResult<MaybeTlsStream<TcpStream>> {
+    let stream = tcp_connect(&options.host, options.port)?;
+    if !options.ssl {
+        return Ok(MaybeTlsStream::Plain(stream));
+    }
+    let connector = match options.tls_options.as_ref() {
+        Some(option) => option.tls_connector(),
+        None => TlsConnector::new(),
+    }
+    .map_err(|e| GremlinError::Generic(e.to_string()))?;
+
+    connector
+        .connect(options.server_name(), stream)
+        .map(MaybeTlsStream::NativeTls)
+        .map_err(|e| GremlinError::Generic(e.to_string()))
+}
+
+/// Each request is a `POST /gremlin`, its response is read as the result set is consumed.
+///
+/// The underlying stream is kept alive between requests unless the server closes it.
+struct HttpStream {
+    options: ConnectionOptions,
+    stream: Option<MaybeTlsStream<TcpStream>>,
+    response: Option<(MaybeTlsStream<TcpStream>, http::ResponseParser)>,
+}
+
+impl HttpStream {
+    fn connect(options: ConnectionOptions) -> GremlinResult<Self> {
+        let stream = open_stream(&options)?;
+        Ok(HttpStream {
+            options,
+            stream: Some(stream),
+            response: None,
+        })
+    }
+
+    fn send(&mut self, payload: Vec<u8>) -> GremlinResult<()> {
+        let request = http::write_request(&self.options, &payload)?;
+        // The stream of a response that was not read to its end cannot be reused
+        self.response = None;
+        let mut stream = match self.stream.take() {
+            Some(stream) => stream,
+            None => open_stream(&self.options)?,
+        };
+
+        stream.write_all(&request)?;
+        stream.flush()?;
+        self.response = Some((stream, http::ResponseParser::new(&self.options)));
+        Ok(())
     }
 
-    fn recv(&mut self) -> GremlinResult<Vec<u8>> {
-        match self.0.read_message()? {
-            Message::Binary(binary) => Ok(binary),
-            _ => unimplemented!(),
+    fn recv(&mut self) -> GremlinResult<Response> {
+        let (mut stream, mut parser) = self
+            .response
+            .take()
+            .ok_or_else(|| GremlinError::Generic(String::from("No pending HTTP response")))?;
+
+        let mut chunk = [0; 8192];
+        loop {
+            if let Some(response) = parser.next(&self.options.deserializer)? {
+                if !parser.is_done() {
+                    self.response = Some((stream, parser));
+                } else if parser.is_reusable() {
+                    self.stream = Some(stream);
+                }
+                return Ok(response);
+            }
+            let read = stream.read(&mut chunk)?;
+            parser.feed(&chunk[..read], read == 0)?;
         }
     }
 }
@@ -77,6 +194,7 @@ impl ConnectionStream {
 #[derive(Debug)]
 pub(crate) struct Connection {
     stream: ConnectionStream,
+    deserializer: IoProtocol,
     broken: bool,
 }
 
@@ -115,6 +233,62 @@ impl ConnectionOptionsBuilder {
         self
     }
 
+    /// Path of the Gremlin endpoint, `/gremlin` by default. It may end with a query string,
+    /// e.g. for servers behind a reverse proxy expecting an auth token.
+    pub fn path<T>(mut self, path: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let path = path.into();
+        self.0.path = if path.starts_with('/') {
+            path
+        } else {
+            format!("/{}", path)
+        };
+        self
+    }
+
+    /// Take the host, port, path, TLS, transport and credentials from a URL such as
+    /// `wss://user:password@[::1]:8182/graphs/g?token=abc`.
+    ///
+    /// `ws` and `wss` URLs use the WebSocket transport, `http` and `https` ones the
+    /// [HTTP transport](Transport::Http). The port defaults to the one of the scheme and the
+    /// path to `/gremlin`.
+    pub fn url(mut self, url: &str) -> GremlinResult<Self> {
+        let invalid =
+            |reason: &str| GremlinError::Generic(format!("Invalid URL {}: {}", url, reason));
+        let uri: Uri = url
+            .parse()
+            .map_err(|e: InvalidUri| invalid(&e.to_string()))?;
+
+        let (ssl, transport) = match uri.scheme_str() {
+            Some("ws") => (false, Transport::WebSocket),
+            Some("wss") => (true, Transport::WebSocket),
+            Some("http") => (false, Transport::Http),
+            Some("https") => (true, Transport::Http),
+            _ => return Err(invalid("the scheme must be ws, wss, http or https")),
+        };
+        let authority = uri.authority().ok_or_else(|| invalid("no host"))?;
+
+        self.0.host = unbracketed(authority.host()).to_string();
+        self.0.port = authority.port_u16().unwrap_or(if ssl { 443 } else { 80 });
+        self.0.ssl = ssl;
+        self.0.transport = transport;
+
+        if let Some((user_info, _)) = authority.as_str().rsplit_once('@') {
+            let (username, password) = user_info.split_once(':').unwrap_or((user_info, ""));
+            self = self.credentials(&percent_decode(username)?, &percent_decode(password)?);
+        }
+
+        if !matches!(uri.path(), "" | "/") {
+            self.0.path = uri.path().to_string();
+        }
+        if let Some(query) = uri.query() {
+            self.0.path = format!("{}?{}", self.0.path, query);
+        }
+        Ok(self)
+    }
+
     pub fn pool_size(mut self, pool_size: u32) -> Self {
         self.0.pool_size = pool_size;
         self
@@ -141,11 +315,56 @@ impl ConnectionOptionsBuilder {
         self.0
     }
 
+    /// Authenticate with the SASL `PLAIN` mechanism, or basic authentication over HTTP.
     pub fn credentials(mut self, username: &str, password: &str) -> Self {
         self.0.credentials = Some(Credentials {
             username: String::from(username),
             password: String::from(password),
         });
+        self.0.authenticator = Some(Arc::new(PlainAuthenticator::new(username, password)));
+        self
+    }
+
+    /// Answer the authentication challenges of the server with a custom mechanism, replacing
+    /// the [credentials](ConnectionOptionsBuilder::credentials).
+    ///
+    /// Only the WebSocket transport exchanges challenges, connecting over HTTP fails.
+    pub fn authenticator<A>(mut self, authenticator: A) -> Self
+    where
+        A: Authenticator + 'static,
+    {
+        self.0.credentials = None;
+        self.0.authenticator = Some(Arc::new(authenticator));
+        self
+    }
+
+    /// Add a header to the WebSocket upgrade request, or to each request over HTTP, such as
+    /// `User-Agent` or a tenant id.
+    pub fn header<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.0.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Headers computed for the WebSocket upgrade request of each new connection, or for each
+    /// request over HTTP, e.g. short lived tokens. They replace the
+    /// [headers](ConnectionOptionsBuilder::header) with the same name.
+    pub fn header_provider<F>(mut self, provider: F) -> Self
+    where
+        F: Fn() -> Vec<(String, String)> + Send + Sync + 'static,
+    {
+        self.0.header_provider = Some(HeaderProvider(Arc::new(provider)));
+        self
+    }
+
+    /// Sign the WebSocket handshake of each new connection, or each request over HTTP, for
+    /// Amazon Neptune IAM authentication.
+    #[cfg(feature = "aws-sigv4")]
+    pub fn sigv4(mut self, signer: SigV4Signer) -> Self {
+        self.0.signer = Some(signer);
         self
     }
 
@@ -164,13 +383,33 @@ impl ConnectionOptionsBuilder {
         self
     }
 
-    pub fn serializer(mut self, serializer: GraphSON) -> Self {
-        self.0.serializer = serializer;
+    pub fn serializer<T: Into<IoProtocol>>(mut self, serializer: T) -> Self {
+        self.0.serializer = serializer.into();
+        self
+    }
+
+    pub fn deserializer<T: Into<IoProtocol>>(mut self, deserializer: T) -> Self {
+        self.0.deserializer = deserializer.into();
+        self
+    }
+
+    /// Submit requests over HTTP instead of a WebSocket, see [Transport](Transport).
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.0.transport = transport;
         self
     }
 
-    pub fn deserializer(mut self, deserializer: GraphSON) -> Self {
-        self.0.deserializer = deserializer;
+    /// Speak the wire protocol of the given TinkerPop version, see [Protocol](Protocol).
+    ///
+    /// [Protocol::TinkerPop4](Protocol::TinkerPop4) also switches the transport to HTTP and both
+    /// the serializer and the deserializer to [IoProtocol::GraphBinaryV4].
+    pub fn protocol(mut self, protocol: Protocol) -> Self {
+        self.0.protocol = protocol;
+        if let Protocol::TinkerPop4 = protocol {
+            self.0.transport = Transport::Http;
+            self.0.serializer = IoProtocol::GraphBinaryV4;
+            self.0.deserializer = IoProtocol::GraphBinaryV4;
+        }
         self
     }
 }
@@ -179,15 +418,77 @@ impl ConnectionOptionsBuilder {
 pub struct ConnectionOptions {
     pub(crate) host: String,
     pub(crate) port: u16,
+    pub(crate) path: String,
     pub(crate) pool_size: u32,
     pub(crate) pool_healthcheck_interval: Option<Duration>,
     pub(crate) pool_get_connection_timeout: Option<Duration>,
     pub(crate) credentials: Option<Credentials>,
+    pub(crate) authenticator: Option<Arc<dyn Authenticator>>,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) header_provider: Option<HeaderProvider>,
+    #[cfg(feature = "aws-sigv4")]
+    pub(crate) signer: Option<SigV4Signer>,
     pub(crate) ssl: bool,
     pub(crate) tls_options: Option<TlsOptions>,
-    pub(crate) serializer: GraphSON,
-    pub(crate) deserializer: GraphSON,
+    pub(crate) serializer: IoProtocol,
+    pub(crate) deserializer: IoProtocol,
     pub(crate) websocket_options: Option<WebSocketOptions>,
+    pub(crate) transport: Transport,
+    pub(crate) protocol: Protocol,
+}
+
+/// How requests reach the Gremlin Server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transport {
+    /// The `/gremlin` WebSocket channel, results are streamed back in batches.
+    WebSocket,
+    /// `POST /gremlin` requests, each answered with all of its results at once unless the
+    /// [TinkerPop 4 protocol](Protocol::TinkerPop4) streams them.
+    ///
+    /// Useful behind load balancers and gateways that drop WebSocket upgrades. Credentials are
+    /// sent with basic authentication and sessions are not supported. Submitting traversals
+    /// requires a server that accepts serialized request messages over HTTP (TinkerPop 3.5+).
+    Http,
+}
+
+/// The request and response protocol of the Gremlin Server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Protocol {
+    /// Request messages carrying bytecode or scripts, for TinkerPop 3.x servers.
+    TinkerPop3,
+    /// The HTTP protocol of TinkerPop 4.x servers.
+    ///
+    /// Requests carry `gremlin-lang` scripts encoded with GraphBinary 4, traversals are translated
+    /// from their bytecode. Results are streamed back in chunks, which the result set reads as it
+    /// is consumed. Sessions and lambdas are not supported.
+    TinkerPop4,
+}
+
+fn percent_decode(s: &str) -> GremlinResult<String> {
+    let invalid = || GremlinError::Generic(format!("Invalid percent encoding in {}", s));
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        if b == b'%' {
+            let hex = tail.get(..2).ok_or_else(invalid)?;
+            let hex = std::str::from_utf8(hex).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(b);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[derive(Clone)]
+pub(crate) struct HeaderProvider(Arc<dyn Fn() -> Vec<(String, String)> + Send + Sync>);
+
+impl std::fmt::Debug for HeaderProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "HeaderProvider")
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -196,9 +497,91 @@ pub(crate) struct Credentials {
     pub(crate) password: String,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct TlsOptions {
-    pub accept_invalid_certs: bool,
+    pub(crate) accept_invalid_certs: bool,
+    pub(crate) identity: Option<TlsIdentity>,
+    pub(crate) root_certificates: Vec<Vec<u8>>,
+    pub(crate) server_name: Option<String>,
+    pub(crate) min_protocol_version: Option<TlsVersion>,
+}
+
+impl TlsOptions {
+    pub fn builder() -> TlsOptionsBuilder {
+        TlsOptionsBuilder(Self::default())
+    }
+}
+
+pub struct TlsOptionsBuilder(TlsOptions);
+
+impl TlsOptionsBuilder {
+    pub fn build(self) -> TlsOptions {
+        self.0
+    }
+
+    /// Skip the verification of the server certificate, for testing only.
+    pub fn accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.0.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Client certificate presented to servers requiring mutual TLS.
+    pub fn identity(mut self, identity: TlsIdentity) -> Self {
+        self.0.identity = Some(identity);
+        self
+    }
+
+    /// PEM encoded certificates trusted along with the default roots, e.g. of a private CA.
+    pub fn root_certificate<T>(mut self, certificate: T) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        self.0.root_certificates.push(certificate.into());
+        self
+    }
+
+    /// Name sent with SNI and checked against the server certificate, the host by default.
+    pub fn server_name<T>(mut self, server_name: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.0.server_name = Some(server_name.into());
+        self
+    }
+
+    /// Oldest TLS version negotiated with the server.
+    pub fn min_protocol_version(mut self, version: TlsVersion) -> Self {
+        self.0.min_protocol_version = Some(version);
+        self
+    }
+}
+
+/// A client certificate with its private key.
+#[derive(Clone)]
+pub enum TlsIdentity {
+    /// PEM encoded certificate chain and PKCS#8 private key
+    Pem { certificate: Vec<u8>, key: Vec<u8> },
+    /// DER encoded PKCS#12 archive. Not supported by the async-std runtime.
+    Pkcs12 { archive: Vec<u8>, password: String },
+}
+
+impl std::fmt::Debug for TlsIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TlsIdentity::Pem { .. } => write!(f, "TlsIdentity::Pem"),
+            TlsIdentity::Pkcs12 { .. } => write!(f, "TlsIdentity::Pkcs12"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TlsVersion {
+    /// Not supported by the async-std runtime
+    Tls10,
+    /// Not supported by the async-std runtime
+    Tls11,
+    Tls12,
+    Tls13,
 }
 
 #[derive(Clone, Debug)]
@@ -263,15 +646,23 @@ impl Default for ConnectionOptions {
         ConnectionOptions {
             host: String::from("localhost"),
             port: 8182,
+            path: String::from("/gremlin"),
             pool_size: 10,
             pool_get_connection_timeout: Some(Duration::from_secs(30)),
             pool_healthcheck_interval: None,
             credentials: None,
+            authenticator: None,
+            headers: vec![],
+            header_provider: None,
+            #[cfg(feature = "aws-sigv4")]
+            signer: None,
             ssl: false,
             tls_options: None,
-            serializer: GraphSON::V3,
-            deserializer: GraphSON::V3,
+            serializer: IoProtocol::GraphSON(GraphSON::V3),
+            deserializer: IoProtocol::GraphSON(GraphSON::V3),
             websocket_options: None,
+            transport: Transport::WebSocket,
+            protocol: Protocol::TinkerPop3,
         }
     }
 }
@@ -281,9 +672,107 @@ impl ConnectionOptions {
         ConnectionOptionsBuilder(ConnectionOptions::default())
     }
 
+    /// The options of a [URL](ConnectionOptionsBuilder::url).
+    pub fn from_url(url: &str) -> GremlinResult<ConnectionOptions> {
+        ConnectionOptions::builder()
+            .url(url)
+            .map(ConnectionOptionsBuilder::build)
+    }
+
     pub fn websocket_url(&self) -> String {
         let protocol = if self.ssl { "wss" } else { "ws" };
-        format!("{}://{}:{}/gremlin", protocol, self.host, self.port)
+        format!("{}://{}{}", protocol, self.authority(), self.path)
+    }
+
+    /// The custom headers of a new connection, or of a request over HTTP. The provided headers
+    /// replace the [headers](ConnectionOptionsBuilder::header) with the same name.
+    pub(crate) fn custom_headers(&self) -> GremlinResult<Vec<(HeaderName, HeaderValue)>> {
+        let provided = self.header_provider.as_ref().map(|provider| (provider.0)());
+        let mut headers: Vec<(HeaderName, HeaderValue)> = vec![];
+        for (name, value) in self.headers.iter().chain(provided.iter().flatten()) {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| GremlinError::Generic(e.to_string()))?;
+            let value =
+                HeaderValue::from_str(value).map_err(|e| GremlinError::Generic(e.to_string()))?;
+            headers.retain(|(other, _)| *other != name);
+            headers.push((name, value));
+        }
+        Ok(headers)
+    }
+
+    /// The WebSocket upgrade request, built again for each connection with the provided
+    /// headers and signed when a signer is set.
+    pub(crate) fn websocket_request(&self) -> GremlinResult<Request> {
+        let mut request = self
+            .websocket_url()
+            .into_client_request()
+            .map_err(|e| GremlinError::Generic(e.to_string()))?;
+
+        for (name, value) in self.custom_headers()? {
+            request.headers_mut().insert(name, value);
+        }
+
+        #[cfg(feature = "aws-sigv4")]
+        let request = match &self.signer {
+            Some(signer) => signer.sign_request(request)?,
+            None => request,
+        };
+
+        Ok(request)
+    }
+
+    pub fn http_url(&self) -> String {
+        let protocol = if self.ssl { "https" } else { "http" };
+        format!("{}://{}{}", protocol, self.authority(), self.path)
+    }
+
+    // IPv6 addresses are bracketed in URLs and `Host` headers
+    pub(crate) fn authority(&self) -> String {
+        if self.host.contains(':') && !self.host.starts_with('[') {
+            format!("[{}]:{}", self.host, self.port)
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+
+    // The name of the server for TLS, which may differ from the host it is reached at
+    pub(crate) fn server_name(&self) -> &str {
+        self.tls_options
+            .as_ref()
+            .and_then(|tls| tls.server_name.as_deref())
+            .unwrap_or_else(|| unbracketed(&self.host))
+    }
+
+    pub(crate) fn uses_http(&self) -> bool {
+        self.transport == Transport::Http || self.protocol == Protocol::TinkerPop4
+    }
+
+    // TinkerPop 4 responses are only read as GraphBinary 4
+    pub(crate) fn check_protocol(&self) -> GremlinResult<()> {
+        let graph_binary_v4 = |io: &IoProtocol| matches!(io, IoProtocol::GraphBinaryV4);
+        match self.protocol {
+            Protocol::TinkerPop4 if !graph_binary_v4(&self.deserializer) => Err(
+                GremlinError::Generic(String::from("TinkerPop 4 requires GraphBinary 4")),
+            ),
+            Protocol::TinkerPop3
+                if graph_binary_v4(&self.serializer) || graph_binary_v4(&self.deserializer) =>
+            {
+                Err(GremlinError::Generic(String::from(
+                    "GraphBinary 4 requires the TinkerPop 4 protocol",
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // HTTP requests only carry credentials, as basic authentication
+    pub(crate) fn check_authenticator(&self) -> GremlinResult<()> {
+        if self.uses_http() && self.authenticator.is_some() && self.credentials.is_none() {
+            return Err(GremlinError::Generic(String::from(
+                "Custom authenticators require the WebSocket transport",
+            )));
+        }
+        Ok(())
     }
 }
 
@@ -292,24 +781,27 @@ impl Connection {
     where
         T: Into<ConnectionOptions>,
     {
+        let options = options.into();
         Ok(Connection {
-            stream: ConnectionStream::connect(options.into())?,
+            deserializer: options.deserializer.clone(),
+            stream: ConnectionStream::connect(options)?,
             broken: false,
         })
     }
 
     pub fn send(&mut self, payload: Vec<u8>) -> GremlinResult<()> {
         self.stream.send(payload).map_err(|e| {
-            if let GremlinError::WebSocket(_) = e {
+            if let GremlinError::WebSocket(_) | GremlinError::Io(_) | GremlinError::Closed(_) = e {
                 self.broken = true;
             }
             e
         })
     }
 
-    pub fn recv(&mut self) -> GremlinResult<Vec<u8>> {
-        self.stream.recv().map_err(|e| {
-            if let GremlinError::WebSocket(_) = e {
+    /// Read the next response frame, decoded by the configured deserializer.
+    pub fn recv(&mut self) -> GremlinResult<Response> {
+        self.stream.recv(&self.deserializer).map_err(|e| {
+            if let GremlinError::WebSocket(_) | GremlinError::Io(_) | GremlinError::Closed(_) = e {
                 self.broken = true
             }
             e
@@ -319,25 +811,311 @@ impl Connection {
     pub fn is_broken(&self) -> bool {
         self.broken
     }
+
+    /// Keep the pool from handing out this connection again.
+    pub(crate) fn mark_broken(&mut self) {
+        self.broken = true;
+    }
+
+    /// Bound the time a `recv` waits for the server, `None` waits indefinitely.
+    pub(crate) fn set_read_timeout(&mut self, timeout: Option<Duration>) -> GremlinResult<()> {
+        self.stream.set_read_timeout(timeout)
+    }
 }
 
 impl TlsOptions {
     pub(crate) fn tls_connector(&self) -> native_tls::Result<TlsConnector> {
-        TlsConnector::builder()
-            .danger_accept_invalid_certs(self.accept_invalid_certs)
-            .build()
+        let mut builder = TlsConnector::builder();
+        builder.danger_accept_invalid_certs(self.accept_invalid_certs);
+
+        for certificate in &self.root_certificates {
+            builder.add_root_certificate(Certificate::from_pem(certificate)?);
+        }
+        match &self.identity {
+            Some(TlsIdentity::Pem { certificate, key }) => {
+                builder.identity(Identity::from_pkcs8(certificate, key)?);
+            }
+            Some(TlsIdentity::Pkcs12 { archive, password }) => {
+                builder.identity(Identity::from_pkcs12(archive, password)?);
+            }
+            None => {}
+        }
+        builder.min_protocol_version(self.min_protocol_version.map(|version| match version {
+            TlsVersion::Tls10 => TlsProtocol::Tlsv10,
+            TlsVersion::Tls11 => TlsProtocol::Tlsv11,
+            TlsVersion::Tls12 => TlsProtocol::Tlsv12,
+            TlsVersion::Tls13 => TlsProtocol::Tlsv13,
+        }));
+
+        builder.build()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{read_http_request, serve_once};
+    use crate::GValue;
+    use std::collections::HashMap;
+    use std::io::BufReader;
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread::{self, JoinHandle};
+    use tungstenite::protocol::{frame::coding::CloseCode, CloseFrame};
+
+    const RESPONSE: &str = r#"{"requestId": "41d2e28a-20a4-4ab0-b379-d810dede3786",
+        "status": {"message": "", "code": 200, "attributes": {}},
+        "result": {"data": {"@type": "g:List", "@value": ["marko"]}, "meta": {}}}"#;
+
+    // A WebSocket peer running the given exchange on its side of the connection
+    fn serve<F>(exchange: F) -> (Connection, JoinHandle<()>)
+    where
+        F: FnOnce(&mut WebSocket<TcpStream>) + Send + 'static,
+    {
+        let (port, server) = serve_once(exchange);
+        (Connection::connect(("127.0.0.1", port)).unwrap(), server)
+    }
 
     #[test]
     fn it_should_connect() {
         Connection::connect(("localhost", 8182)).unwrap();
     }
 
+    #[test]
+    fn it_should_answer_pings_and_read_text_frames() {
+        let (mut connection, server) = serve(|websocket| {
+            websocket.send(Message::Ping(b"ping".to_vec())).unwrap();
+            websocket
+                .send(Message::Text(String::from(RESPONSE)))
+                .unwrap();
+            assert_eq!(Message::Pong(b"ping".to_vec()), websocket.read().unwrap());
+        });
+
+        let response = connection.recv().unwrap();
+        assert_eq!(200, response.status.code);
+        assert_eq!(
+            Some(GValue::List(vec![GValue::from("marko")].into())),
+            response.result.data
+        );
+        server.join().unwrap();
+        assert!(!connection.is_broken());
+    }
+
+    #[test]
+    fn it_should_report_close_frames() {
+        let (mut connection, server) = serve(|websocket| {
+            websocket
+                .close(Some(CloseFrame {
+                    code: CloseCode::Away,
+                    reason: "shutting down".into(),
+                }))
+                .unwrap();
+            while websocket.read().is_ok() {}
+        });
+
+        match connection.recv() {
+            Err(GremlinError::Closed(reason)) => assert_eq!("1001 shutting down", reason),
+            other => panic!("Unexpected {:?}", other),
+        }
+        assert!(connection.is_broken());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn it_should_send_handshake_headers() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let mut handshakes = vec![];
+            for _ in 0..2 {
+                let (stream, _) = listener.accept().unwrap();
+                let mut headers = None;
+                let _websocket = tungstenite::accept_hdr(
+                    stream,
+                    |request: &tungstenite::handshake::server::Request, response| {
+                        headers = Some(request.headers().clone());
+                        Ok(response)
+                    },
+                )
+                .unwrap();
+                handshakes.push(headers.unwrap());
+            }
+            handshakes
+        });
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let options = ConnectionOptions::builder()
+            .host("127.0.0.1")
+            .port(port)
+            .header("User-Agent", "analytics/1.0")
+            .header("X-Tenant", "default")
+            .header_provider(move || {
+                let call = counter.fetch_add(1, Ordering::SeqCst);
+                vec![
+                    (String::from("X-Tenant"), String::from("acme")),
+                    (String::from("Authorization"), format!("Bearer {}", call)),
+                ]
+            })
+            .build();
+
+        Connection::connect(options.clone()).unwrap();
+        Connection::connect(options).unwrap();
+        let handshakes = server.join().unwrap();
+
+        for (i, headers) in handshakes.iter().enumerate() {
+            assert_eq!("analytics/1.0", headers["User-Agent"]);
+            assert_eq!("acme", headers["X-Tenant"]);
+            assert_eq!(format!("Bearer {}", i), headers["Authorization"]);
+        }
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn it_should_send_headers_over_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut stream = BufReader::new(stream);
+            let head = read_http_request(&mut stream);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                RESPONSE.len(),
+                RESPONSE
+            );
+            stream.get_mut().write_all(response.as_bytes()).unwrap();
+            head
+        });
+
+        let options = ConnectionOptions::builder()
+            .host("127.0.0.1")
+            .port(port)
+            .transport(Transport::Http)
+            .header("User-Agent", "analytics/1.0")
+            .header_provider(|| vec![(String::from("X-Tenant"), String::from("acme"))])
+            .build();
+        let (_, message) = options
+            .serializer
+            .build_message("eval", "", HashMap::new(), None)
+            .unwrap();
+        let mut connection = Connection::connect(options).unwrap();
+        connection.send(message).unwrap();
+
+        assert_eq!(200, connection.recv().unwrap().status.code);
+        let head = server.join().unwrap();
+        assert!(
+            head.contains("\r\nuser-agent: analytics/1.0\r\n"),
+            "{}",
+            head
+        );
+        assert!(head.contains("\r\nx-tenant: acme\r\n"), "{}", head);
+    }
+
+    // Trusts the test CA, which signed certificates for gremlin.test
+    fn trust_test_ca() -> TlsOptionsBuilder {
+        TlsOptions::builder()
+            .root_certificate(&include_bytes!("../tests/certs/ca.pem")[..])
+            .min_protocol_version(TlsVersion::Tls12)
+    }
+
+    fn client_identity() -> TlsIdentity {
+        TlsIdentity::Pem {
+            certificate: include_bytes!("../tests/certs/client.pem").to_vec(),
+            key: include_bytes!("../tests/certs/client.key").to_vec(),
+        }
+    }
+
+    // A WebSocket server behind TLS answering the first two connections which complete the
+    // handshake
+    fn serve_tls<S, F>(accept: F) -> (u16, JoinHandle<()>)
+    where
+        S: Read + Write,
+        F: Fn(TcpStream) -> Option<S> + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                // A failed handshake is reported on the client side
+                if let Some(stream) = accept(stream.unwrap()) {
+                    if let Ok(mut websocket) = tungstenite::accept(stream) {
+                        let _ = websocket.send(Message::Text(String::from(RESPONSE)));
+                    }
+                }
+            }
+        });
+        (port, server)
+    }
+
+    fn tls_options(port: u16, tls_options: TlsOptions) -> ConnectionOptions {
+        ConnectionOptions::builder()
+            .host("127.0.0.1")
+            .port(port)
+            .ssl(true)
+            .tls_options(tls_options)
+            .build()
+    }
+
+    #[test]
+    fn it_should_connect_with_custom_roots_and_server_name() {
+        let identity = Identity::from_pkcs8(
+            include_bytes!("../tests/certs/server.pem"),
+            include_bytes!("../tests/certs/server.key"),
+        )
+        .unwrap();
+        let acceptor = native_tls::TlsAcceptor::new(identity).unwrap();
+        let (port, server) = serve_tls(move |stream| acceptor.accept(stream).ok());
+
+        let result = Connection::connect(tls_options(port, trust_test_ca().build()));
+        assert!(result.is_err());
+
+        let options = tls_options(port, trust_test_ca().server_name("gremlin.test").build());
+        let mut connection = Connection::connect(options).unwrap();
+        assert_eq!(200, connection.recv().unwrap().status.code);
+        server.join().unwrap();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn it_should_present_the_client_certificate() {
+        use openssl::pkey::PKey;
+        use openssl::ssl::{SslAcceptor, SslMethod, SslVerifyMode};
+        use openssl::x509::X509;
+
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        let certificate = X509::from_pem(include_bytes!("../tests/certs/server.pem")).unwrap();
+        let key = PKey::private_key_from_pem(include_bytes!("../tests/certs/server.key")).unwrap();
+        let ca = X509::from_pem(include_bytes!("../tests/certs/ca.pem")).unwrap();
+        acceptor.set_certificate(&certificate).unwrap();
+        acceptor.set_private_key(&key).unwrap();
+        acceptor.cert_store_mut().add_cert(ca).unwrap();
+        acceptor.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+        let acceptor = acceptor.build();
+        let (port, server) = serve_tls(move |stream| acceptor.accept(stream).ok());
+
+        let options = || trust_test_ca().server_name("gremlin.test");
+        let result = Connection::connect(tls_options(port, options().build()))
+            .and_then(|mut connection| connection.recv());
+        assert!(result.is_err());
+
+        let options = tls_options(port, options().identity(client_identity()).build());
+        let mut connection = Connection::connect(options).unwrap();
+        assert_eq!(200, connection.recv().unwrap().status.code);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn it_should_load_tls_options() {
+        let options = trust_test_ca().identity(client_identity()).build();
+        assert!(options.tls_connector().is_ok());
+
+        let options = TlsOptions::builder()
+            .root_certificate(&b"not a certificate"[..])
+            .build();
+        assert!(options.tls_connector().is_err());
+    }
+
     #[test]
     fn connection_option_build_url() {
         let options = ConnectionOptions {
@@ -357,5 +1135,47 @@ mod tests {
         };
 
         assert_eq!(options.websocket_url(), "wss://localhost:8182/gremlin");
+        assert_eq!(options.http_url(), "https://localhost:8182/gremlin");
+    }
+
+    #[test]
+    fn connection_option_build_url_with_path() {
+        let options = ConnectionOptions::builder()
+            .host("::1")
+            .port(8182)
+            .path("graphs/g?token=abc")
+            .build();
+
+        assert_eq!(
+            options.websocket_url(),
+            "ws://[::1]:8182/graphs/g?token=abc"
+        );
+        assert_eq!(options.http_url(), "http://[::1]:8182/graphs/g?token=abc");
+    }
+
+    #[test]
+    fn connection_option_from_url() {
+        let options =
+            ConnectionOptions::from_url("wss://stephen:p%40ss@[::1]:8183/graphs/g?x=y").unwrap();
+
+        assert_eq!("::1", options.host);
+        assert_eq!(8183, options.port);
+        assert!(options.ssl);
+        assert_eq!(Transport::WebSocket, options.transport);
+        assert_eq!(options.websocket_url(), "wss://[::1]:8183/graphs/g?x=y");
+        let credentials = options.credentials.unwrap();
+        assert_eq!("stephen", credentials.username);
+        assert_eq!("p@ss", credentials.password);
+        assert!(options.authenticator.is_some());
+
+        let options = ConnectionOptions::from_url("https://example.com").unwrap();
+
+        assert_eq!(443, options.port);
+        assert_eq!(Transport::Http, options.transport);
+        assert_eq!(options.http_url(), "https://example.com:443/gremlin");
+        assert!(options.credentials.is_none());
+
+        assert!(ConnectionOptions::from_url("ftp://example.com").is_err());
+        assert!(ConnectionOptions::from_url("ws://u:%zz@example.com").is_err());
     }
 }
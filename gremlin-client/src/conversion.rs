@@ -5,8 +5,12 @@ use crate::{
     Property, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty, GID,
 };
 
+use crate::structure::Binding;
+use crate::structure::BulkSet;
+use crate::structure::Lambda;
 use crate::structure::Set;
 use crate::structure::Traverser;
+use crate::structure::Tree;
 
 use std::collections::HashMap;
 
@@ -51,11 +55,35 @@ macro_rules! impl_to_gvalue {
 
 impl_to_gvalue!(f32, GValue::Float);
 impl_to_gvalue!(f64, GValue::Double);
+impl_to_gvalue!(i8, GValue::Byte);
+impl_to_gvalue!(i16, GValue::Short);
 impl_to_gvalue!(i32, GValue::Int32);
 impl_to_gvalue!(i64, GValue::Int64);
 impl_to_gvalue!(chrono::DateTime<chrono::Utc>, GValue::Date);
+impl_to_gvalue!(
+    chrono::DateTime<chrono::FixedOffset>,
+    GValue::OffsetDateTime
+);
+impl_to_gvalue!(chrono::Duration, GValue::Duration);
+impl_to_gvalue!(chrono::NaiveDate, GValue::LocalDate);
+impl_to_gvalue!(chrono::NaiveDateTime, GValue::LocalDateTime);
 impl_to_gvalue!(uuid::Uuid, GValue::Uuid);
 impl_to_gvalue!(bool, GValue::Bool);
+impl_to_gvalue!(char, GValue::Char);
+
+#[cfg(feature = "big_numbers")]
+impl ToGValue for num_bigint::BigInt {
+    fn to_gvalue(&self) -> GValue {
+        GValue::BigInteger(self.clone())
+    }
+}
+
+#[cfg(feature = "big_numbers")]
+impl ToGValue for bigdecimal::BigDecimal {
+    fn to_gvalue(&self) -> GValue {
+        GValue::BigDecimal(self.clone())
+    }
+}
 
 impl ToGValue for &str {
     fn to_gvalue(&self) -> GValue {
@@ -63,6 +91,30 @@ impl ToGValue for &str {
     }
 }
 
+impl ToGValue for Vec<u8> {
+    fn to_gvalue(&self) -> GValue {
+        GValue::Bytes(self.clone())
+    }
+}
+
+impl ToGValue for &[u8] {
+    fn to_gvalue(&self) -> GValue {
+        GValue::Bytes(self.to_vec())
+    }
+}
+
+impl ToGValue for Binding {
+    fn to_gvalue(&self) -> GValue {
+        GValue::Binding(self.clone())
+    }
+}
+
+impl ToGValue for Lambda {
+    fn to_gvalue(&self) -> GValue {
+        GValue::Lambda(self.clone())
+    }
+}
+
 impl ToGValue for Predicate {
     fn to_gvalue(&self) -> GValue {
         GValue::P(self.clone())
@@ -120,19 +172,107 @@ impl_from_gvalue!(Token, GValue::Token);
 impl_from_gvalue!(Vertex, GValue::Vertex);
 impl_from_gvalue!(Edge, GValue::Edge);
 impl_from_gvalue!(Path, GValue::Path);
+impl_from_gvalue!(Tree, GValue::Tree);
+impl_from_gvalue!(BulkSet, GValue::BulkSet);
+impl_from_gvalue!(Lambda, GValue::Lambda);
+impl_from_gvalue!(Binding, GValue::Binding);
 impl_from_gvalue!(String, GValue::String);
 impl_from_gvalue!(f32, GValue::Float);
-impl_from_gvalue!(f64, GValue::Double);
+impl_from_gvalue!(i8, GValue::Byte);
+impl_from_gvalue!(i16, GValue::Short);
 impl_from_gvalue!(i32, GValue::Int32);
-impl_from_gvalue!(i64, GValue::Int64);
 impl_from_gvalue!(bool, GValue::Bool);
+impl_from_gvalue!(char, GValue::Char);
+impl_from_gvalue!(Vec<u8>, GValue::Bytes);
 impl_from_gvalue!(uuid::Uuid, GValue::Uuid);
 impl_from_gvalue!(Metric, GValue::Metric);
 impl_from_gvalue!(TraversalMetrics, GValue::TraversalMetrics);
 impl_from_gvalue!(TraversalExplanation, GValue::TraversalExplanation);
 impl_from_gvalue!(IntermediateRepr, GValue::IntermediateRepr);
-impl_from_gvalue!(chrono::DateTime<chrono::Utc>, GValue::Date);
+impl_from_gvalue!(chrono::Duration, GValue::Duration);
+impl_from_gvalue!(chrono::NaiveDate, GValue::LocalDate);
+impl_from_gvalue!(chrono::NaiveDateTime, GValue::LocalDateTime);
 impl_from_gvalue!(Traverser, GValue::Traverser);
+#[cfg(feature = "big_numbers")]
+impl_from_gvalue!(bigdecimal::BigDecimal, GValue::BigDecimal);
+
+// Instants and Dates both are points in time in UTC
+impl FromGValue for chrono::DateTime<chrono::Utc> {
+    fn from_gvalue(v: GValue) -> GremlinResult<chrono::DateTime<chrono::Utc>> {
+        match v {
+            GValue::Date(e) | GValue::Instant(e) => Ok(e),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to chrono::DateTime<chrono::Utc>",
+                v
+            ))),
+        }
+    }
+}
+
+impl FromGValue for chrono::DateTime<chrono::FixedOffset> {
+    fn from_gvalue(v: GValue) -> GremlinResult<chrono::DateTime<chrono::FixedOffset>> {
+        match v {
+            GValue::OffsetDateTime(e) | GValue::ZonedDateTime(e) => Ok(e),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to chrono::DateTime<chrono::FixedOffset>",
+                v
+            ))),
+        }
+    }
+}
+
+// Integral types are widened to i64
+impl FromGValue for i64 {
+    fn from_gvalue(v: GValue) -> GremlinResult<i64> {
+        match v {
+            GValue::Int64(e) => Ok(e),
+            GValue::Int32(e) => Ok(e.into()),
+            GValue::Short(e) => Ok(e.into()),
+            GValue::Byte(e) => Ok(e.into()),
+            #[cfg(feature = "big_numbers")]
+            GValue::BigInteger(ref e) => std::convert::TryFrom::try_from(e)
+                .map_err(|_| GremlinError::Cast(format!("Cannot convert {:?} to {}", v, "i64"))),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to {}",
+                v, "i64"
+            ))),
+        }
+    }
+}
+
+// Floating point and integral types that fit in the mantissa are widened to f64
+impl FromGValue for f64 {
+    fn from_gvalue(v: GValue) -> GremlinResult<f64> {
+        match v {
+            GValue::Double(e) => Ok(e),
+            GValue::Float(e) => Ok(e.into()),
+            GValue::Int32(e) => Ok(e.into()),
+            GValue::Short(e) => Ok(e.into()),
+            GValue::Byte(e) => Ok(e.into()),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to {}",
+                v, "f64"
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "big_numbers")]
+impl FromGValue for num_bigint::BigInt {
+    fn from_gvalue(v: GValue) -> GremlinResult<num_bigint::BigInt> {
+        match v {
+            GValue::BigInteger(e) => Ok(e),
+            GValue::Int64(e) => Ok(e.into()),
+            GValue::Int32(e) => Ok(e.into()),
+            GValue::Short(e) => Ok(e.into()),
+            GValue::Byte(e) => Ok(e.into()),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to {}",
+                v, "BigInt"
+            ))),
+        }
+    }
+}
 
 impl FromGValue for Null {
     fn from_gvalue(v: GValue) -> GremlinResult<Self> {
@@ -178,6 +318,8 @@ impl<T: FromGValue> FromGValue for Vec<T> {
                     l.take().into_iter().map(T::from_gvalue).collect();
                 Ok(results?)
             }
+            // Bulks are expanded, an element with a bulk of 2 is returned twice
+            GValue::BulkSet(s) => s.into_expanded().map(T::from_gvalue).collect(),
             _ => Err(GremlinError::Cast(format!(
                 "Cannot convert {:?} to List of T",
                 v
@@ -216,15 +358,53 @@ impl_borrow_from_gvalue!(List, GValue::List);
 impl_borrow_from_gvalue!(Vertex, GValue::Vertex);
 impl_borrow_from_gvalue!(Edge, GValue::Edge);
 impl_borrow_from_gvalue!(Path, GValue::Path);
+impl_borrow_from_gvalue!(Tree, GValue::Tree);
+impl_borrow_from_gvalue!(BulkSet, GValue::BulkSet);
+impl_borrow_from_gvalue!(Lambda, GValue::Lambda);
+impl_borrow_from_gvalue!(Binding, GValue::Binding);
 impl_borrow_from_gvalue!(String, GValue::String);
 impl_borrow_from_gvalue!(Token, GValue::Token);
 impl_borrow_from_gvalue!(f32, GValue::Float);
 impl_borrow_from_gvalue!(f64, GValue::Double);
+impl_borrow_from_gvalue!(i8, GValue::Byte);
+impl_borrow_from_gvalue!(i16, GValue::Short);
 impl_borrow_from_gvalue!(i32, GValue::Int32);
 impl_borrow_from_gvalue!(i64, GValue::Int64);
 impl_borrow_from_gvalue!(uuid::Uuid, GValue::Uuid);
-impl_borrow_from_gvalue!(chrono::DateTime<chrono::Utc>, GValue::Date);
+impl_borrow_from_gvalue!(chrono::Duration, GValue::Duration);
+impl_borrow_from_gvalue!(chrono::NaiveDate, GValue::LocalDate);
+impl_borrow_from_gvalue!(chrono::NaiveDateTime, GValue::LocalDateTime);
 impl_borrow_from_gvalue!(bool, GValue::Bool);
+impl_borrow_from_gvalue!(char, GValue::Char);
+impl_borrow_from_gvalue!(Vec<u8>, GValue::Bytes);
+#[cfg(feature = "big_numbers")]
+impl_borrow_from_gvalue!(num_bigint::BigInt, GValue::BigInteger);
+#[cfg(feature = "big_numbers")]
+impl_borrow_from_gvalue!(bigdecimal::BigDecimal, GValue::BigDecimal);
+
+impl BorrowFromGValue for chrono::DateTime<chrono::Utc> {
+    fn from_gvalue<'a>(v: &'a GValue) -> GremlinResult<&'a chrono::DateTime<chrono::Utc>> {
+        match v {
+            GValue::Date(e) | GValue::Instant(e) => Ok(e),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to chrono::DateTime<chrono::Utc>",
+                v
+            ))),
+        }
+    }
+}
+
+impl BorrowFromGValue for chrono::DateTime<chrono::FixedOffset> {
+    fn from_gvalue<'a>(v: &'a GValue) -> GremlinResult<&'a chrono::DateTime<chrono::FixedOffset>> {
+        match v {
+            GValue::OffsetDateTime(e) | GValue::ZonedDateTime(e) => Ok(e),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to chrono::DateTime<chrono::FixedOffset>",
+                v
+            ))),
+        }
+    }
+}
 
 #[test]
 fn to_gvalue_for_vec_gvalue() {
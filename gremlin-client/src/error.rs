@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::message::ResponseStatusCode;
 use crate::structure::GValue;
 
 use thiserror::Error;
@@ -28,9 +29,36 @@ pub enum GremlinError {
     #[error("JSON error: {0}")]
     Json(String),
 
+    #[error("GraphBinary error: {0}")]
+    GraphBinary(String),
+
+    #[error("GraphBinary error: unexpected end of buffer, expected {expected} more bytes but found {found}")]
+    EndOfBuffer { expected: usize, found: usize },
+
+    /// Server errors are returned as [Server](GremlinError::Server), this variant is no longer
+    /// constructed by the client.
+    #[deprecated(note = "server errors are returned as GremlinError::Server")]
     #[error("Request error: {0:?} ")]
     Request((i16, String)),
 
+    /// A request failed on the server side.
+    #[error("Server error {code}: {message}")]
+    Server {
+        code: ResponseStatusCode,
+        message: String,
+        /// Exception classes of the failure, from the most specific one
+        exceptions: Vec<String>,
+        stack_trace: Option<String>,
+    },
+
+    /// The server closed the connection, with the code and reason of its close frame.
+    #[error("Connection closed by the server: {0}")]
+    Closed(String),
+
+    /// The authentication exchange did not complete.
+    #[error("Authentication error: {0}")]
+    Authentication(String),
+
     #[error(transparent)]
     Serde(#[from] serde_json::Error),
 
@@ -45,6 +73,42 @@ pub enum GremlinError {
     ChannelSend(#[from] futures::channel::mpsc::SendError),
     #[error(transparent)]
     Uuid(#[from] uuid::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl GremlinError {
+    /// Status code of a server error.
+    pub fn status_code(&self) -> Option<ResponseStatusCode> {
+        match self {
+            GremlinError::Server { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Whether the request may succeed if it is sent again, see
+    /// [ResponseStatusCode::is_retryable](ResponseStatusCode::is_retryable).
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.status_code(), Some(code) if code.is_retryable())
+    }
+
+    /// Whether the request exceeded its evaluation timeout.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.status_code(), Some(code) if code.is_timeout())
+    }
+}
+
+impl serde::de::Error for GremlinError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        GremlinError::Cast(msg.to_string())
+    }
+}
+
+impl serde::ser::Error for GremlinError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        GremlinError::Cast(msg.to_string())
+    }
 }
 
 #[cfg(feature = "async_gremlin")]
@@ -0,0 +1,1481 @@
+//! GraphBinary V1 [docs](https://tinkerpop.apache.org/docs/current/dev/io/#graphbinary)
+//!
+
+use crate::conversion::FromGValue;
+use crate::io::temporal;
+use crate::message::{ReponseStatus, Response, ResponseResult};
+use crate::process::traversal::{Bytecode, Order, Scope};
+use crate::structure::{
+    Binding, BulkSet, Cardinality, Column, Direction, Edge, GKey, GValue, Lambda, List, Map, Merge,
+    Metric, Path, Pop, Property, TextP, Token, TraversalMetrics, Traverser, Tree, Vertex,
+    VertexProperty, GID, P, T,
+};
+use crate::{GremlinError, GremlinResult};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Timelike, Utc,
+};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use uuid::Uuid;
+
+pub(crate) const CONTENT_TYPE: &str = "application/vnd.graphbinary-v1.0";
+
+const VERSION: u8 = 0x81;
+
+const VALUE_FLAG_NONE: u8 = 0x00;
+const VALUE_FLAG_NULL: u8 = 0x01;
+
+// Data type codes [docs](https://tinkerpop.apache.org/docs/current/dev/io/#_data_type_codes)
+const INT: u8 = 0x01;
+const LONG: u8 = 0x02;
+const STRING: u8 = 0x03;
+const DATE: u8 = 0x04;
+const TIMESTAMP: u8 = 0x05;
+const CLASS: u8 = 0x06;
+const DOUBLE: u8 = 0x07;
+const FLOAT: u8 = 0x08;
+const LIST: u8 = 0x09;
+const MAP: u8 = 0x0a;
+const SET: u8 = 0x0b;
+const UUID: u8 = 0x0c;
+const EDGE: u8 = 0x0d;
+const PATH: u8 = 0x0e;
+const PROPERTY: u8 = 0x0f;
+const VERTEX: u8 = 0x11;
+const VERTEX_PROPERTY: u8 = 0x12;
+const BINDING: u8 = 0x14;
+const BYTECODE: u8 = 0x15;
+const CARDINALITY: u8 = 0x16;
+const COLUMN: u8 = 0x17;
+const DIRECTION: u8 = 0x18;
+const ORDER: u8 = 0x1a;
+const POP: u8 = 0x1c;
+const LAMBDA: u8 = 0x1d;
+const P: u8 = 0x1e;
+const SCOPE: u8 = 0x1f;
+const T: u8 = 0x20;
+const TRAVERSER: u8 = 0x21;
+const BIG_DECIMAL: u8 = 0x22;
+const BIG_INTEGER: u8 = 0x23;
+const BYTE: u8 = 0x24;
+const BYTE_BUFFER: u8 = 0x25;
+const SHORT: u8 = 0x26;
+const BOOLEAN: u8 = 0x27;
+const TEXT_P: u8 = 0x28;
+const BULK_SET: u8 = 0x2a;
+const TREE: u8 = 0x2b;
+const METRICS: u8 = 0x2c;
+const TRAVERSAL_METRICS: u8 = 0x2d;
+const MERGE: u8 = 0x2e;
+const CHAR: u8 = 0x80;
+const DURATION: u8 = 0x81;
+const INSTANT: u8 = 0x83;
+const LOCAL_DATE: u8 = 0x84;
+const LOCAL_DATE_TIME: u8 = 0x85;
+const OFFSET_DATE_TIME: u8 = 0x88;
+const YEAR: u8 = 0x8b;
+const ZONED_DATE_TIME: u8 = 0x8d;
+const UNSPECIFIED_NULL: u8 = 0xfe;
+
+const NANOS_PER_MILLI: f64 = 1_000_000.0;
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+// Request message [docs](https://tinkerpop.apache.org/docs/current/dev/io/#_request_message)
+pub(crate) fn write_request(
+    op: &str,
+    processor: &str,
+    args: &HashMap<String, GValue>,
+    request_id: Uuid,
+) -> GremlinResult<Vec<u8>> {
+    let mut buf = vec![VERSION];
+    buf.extend_from_slice(request_id.as_bytes());
+    write_string(op, &mut buf)?;
+    write_string(processor, &mut buf)?;
+    write_len(args.len(), &mut buf)?;
+    for (key, value) in args {
+        write_header(STRING, &mut buf);
+        write_string(key, &mut buf)?;
+        write(value, &mut buf)?;
+    }
+    Ok(buf)
+}
+
+// Response message [docs](https://tinkerpop.apache.org/docs/current/dev/io/#_response_message)
+pub(crate) fn read_response(response: &[u8]) -> GremlinResult<Response> {
+    let buf = &mut &response[..];
+
+    let version = read_u8(buf)?;
+    if version != VERSION {
+        return Err(GremlinError::GraphBinary(format!(
+            "Unsupported response version 0x{:02x}",
+            version
+        )));
+    }
+
+    let request_id = match read_value_flag(buf)? {
+        VALUE_FLAG_NULL => Uuid::nil(),
+        _ => read_uuid(buf)?,
+    };
+    let code = read_i32(buf)? as i16;
+    let message = match read_value_flag(buf)? {
+        VALUE_FLAG_NULL => String::default(),
+        _ => read_string(buf)?,
+    };
+    let attributes = read_map(buf)?;
+    let meta = read_map(buf)?;
+
+    let data = match read(buf)? {
+        GValue::Null => None,
+        data => Some(data),
+    };
+
+    Ok(Response {
+        request_id,
+        result: ResponseResult { data, meta },
+        status: ReponseStatus {
+            code,
+            message,
+            attributes,
+        },
+    })
+}
+
+// Write a fully qualified value {type_code}{value_flag}{value}
+pub(crate) fn write(value: &GValue, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    match value {
+        GValue::Null => {
+            buf.push(UNSPECIFIED_NULL);
+            buf.push(VALUE_FLAG_NULL);
+        }
+        GValue::Int32(i) => {
+            write_header(INT, buf);
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        GValue::Int64(i) => {
+            write_header(LONG, buf);
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        GValue::Short(i) => {
+            write_header(SHORT, buf);
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        GValue::Byte(i) => {
+            write_header(BYTE, buf);
+            buf.extend_from_slice(&i.to_be_bytes());
+        }
+        #[cfg(feature = "big_numbers")]
+        GValue::BigInteger(i) => {
+            write_header(BIG_INTEGER, buf);
+            write_big_integer(i, buf)?;
+        }
+        #[cfg(feature = "big_numbers")]
+        GValue::BigDecimal(d) => {
+            write_header(BIG_DECIMAL, buf);
+            let (unscaled, scale) = d.as_bigint_and_exponent();
+            let scale = i32::try_from(scale)
+                .map_err(|_| GremlinError::GraphBinary(format!("Invalid scale {}", scale)))?;
+            buf.extend_from_slice(&scale.to_be_bytes());
+            write_big_integer(&unscaled, buf)?;
+        }
+        GValue::Float(f) => {
+            write_header(FLOAT, buf);
+            buf.extend_from_slice(&f.to_be_bytes());
+        }
+        GValue::Double(d) => {
+            write_header(DOUBLE, buf);
+            buf.extend_from_slice(&d.to_be_bytes());
+        }
+        GValue::String(s) => {
+            write_header(STRING, buf);
+            write_string(s, buf)?;
+        }
+        GValue::Bool(b) => {
+            write_header(BOOLEAN, buf);
+            buf.push(*b as u8);
+        }
+        GValue::Char(c) => {
+            write_header(CHAR, buf);
+            let mut utf8 = [0; 4];
+            buf.extend_from_slice(c.encode_utf8(&mut utf8).as_bytes());
+        }
+        GValue::Bytes(bytes) => {
+            write_header(BYTE_BUFFER, buf);
+            write_len(bytes.len(), buf)?;
+            buf.extend_from_slice(bytes);
+        }
+        GValue::Uuid(uuid) => {
+            write_header(UUID, buf);
+            buf.extend_from_slice(uuid.as_bytes());
+        }
+        GValue::Date(date) => {
+            write_header(DATE, buf);
+            buf.extend_from_slice(&date.timestamp_millis().to_be_bytes());
+        }
+        GValue::Duration(duration) => {
+            write_header(DURATION, buf);
+            let (seconds, nanos) = temporal::split_duration(duration);
+            buf.extend_from_slice(&seconds.to_be_bytes());
+            buf.extend_from_slice(&nanos.to_be_bytes());
+        }
+        GValue::Instant(instant) => {
+            write_header(INSTANT, buf);
+            buf.extend_from_slice(&instant.timestamp().to_be_bytes());
+            buf.extend_from_slice(&instant.timestamp_subsec_nanos().to_be_bytes());
+        }
+        GValue::LocalDate(date) => {
+            write_header(LOCAL_DATE, buf);
+            write_local_date(date, buf);
+        }
+        GValue::LocalDateTime(date_time) => {
+            write_header(LOCAL_DATE_TIME, buf);
+            write_local_date_time(date_time, buf);
+        }
+        GValue::OffsetDateTime(date_time) => {
+            write_header(OFFSET_DATE_TIME, buf);
+            write_offset_date_time(date_time, buf);
+        }
+        GValue::ZonedDateTime(date_time) => {
+            write_header(ZONED_DATE_TIME, buf);
+            write_offset_date_time(date_time, buf);
+        }
+        GValue::Year(year) => {
+            write_header(YEAR, buf);
+            buf.extend_from_slice(&year.to_be_bytes());
+        }
+        GValue::List(list) => {
+            write_header(LIST, buf);
+            write_list(list.iter(), list.len(), buf)?;
+        }
+        GValue::Set(set) => {
+            let elements: Vec<&GValue> = set.iter().collect();
+            write_header(SET, buf);
+            write_list(elements.into_iter(), set.iter().count(), buf)?;
+        }
+        GValue::Map(map) => {
+            write_header(MAP, buf);
+            write_map(map, buf)?;
+        }
+        GValue::Vertex(vertex) => {
+            write_header(VERTEX, buf);
+            write(&vertex.id().into(), buf)?;
+            write_string(vertex.label(), buf)?;
+            write(&GValue::Null, buf)?;
+        }
+        GValue::Edge(edge) => {
+            write_header(EDGE, buf);
+            write(&edge.id().into(), buf)?;
+            write_string(edge.label(), buf)?;
+            write(&edge.in_v().id().into(), buf)?;
+            write_string(edge.in_v().label(), buf)?;
+            write(&edge.out_v().id().into(), buf)?;
+            write_string(edge.out_v().label(), buf)?;
+            // parent
+            write(&GValue::Null, buf)?;
+            // properties
+            write(&GValue::Null, buf)?;
+        }
+        GValue::VertexProperty(property) => {
+            write_header(VERTEX_PROPERTY, buf);
+            write(&property.id().into(), buf)?;
+            write_string(property.label(), buf)?;
+            write(property.value(), buf)?;
+            // parent
+            write(&GValue::Null, buf)?;
+            // properties
+            write(&GValue::Null, buf)?;
+        }
+        GValue::Property(property) => {
+            write_header(PROPERTY, buf);
+            write_string(property.label(), buf)?;
+            write(property.value(), buf)?;
+            // parent
+            write(&GValue::Null, buf)?;
+        }
+        GValue::BulkSet(set) => {
+            write_header(BULK_SET, buf);
+            write_len(set.len(), buf)?;
+            for (value, bulk) in set.iter() {
+                write(value, buf)?;
+                buf.extend_from_slice(&bulk.to_be_bytes());
+            }
+        }
+        GValue::Tree(tree) => {
+            write_header(TREE, buf);
+            write_tree(tree, buf)?;
+        }
+        GValue::Path(path) => {
+            write_header(PATH, buf);
+            // labels are a list of sets of strings
+            match path.labels() {
+                GValue::List(labels) => {
+                    write_header(LIST, buf);
+                    write_len(labels.len(), buf)?;
+                    for label in labels.iter() {
+                        match label {
+                            GValue::List(l) => {
+                                write_header(SET, buf);
+                                write_list(l.iter(), l.len(), buf)?;
+                            }
+                            other => write(other, buf)?,
+                        }
+                    }
+                }
+                other => write(other, buf)?,
+            }
+            write_header(LIST, buf);
+            write_list(path.objects().iter(), path.objects().len(), buf)?;
+        }
+        GValue::Traverser(traverser) => {
+            write_header(TRAVERSER, buf);
+            buf.extend_from_slice(&traverser.bulk().to_be_bytes());
+            write(traverser.value(), buf)?;
+        }
+        GValue::Bytecode(code) => {
+            write_header(BYTECODE, buf);
+            write_bytecode(code, buf)?;
+        }
+        GValue::Lambda(lambda) => {
+            write_header(LAMBDA, buf);
+            write_string(lambda.language(), buf)?;
+            write_string(lambda.script(), buf)?;
+            buf.extend_from_slice(&lambda.arguments_length().to_be_bytes());
+        }
+        GValue::Binding(binding) => {
+            write_header(BINDING, buf);
+            write_string(binding.key(), buf)?;
+            write(binding.value(), buf)?;
+        }
+        GValue::P(p) => {
+            write_header(P, buf);
+            write_predicate(p.operator(), p.value(), buf)?;
+        }
+        GValue::TextP(text_p) => {
+            write_header(TEXT_P, buf);
+            write_predicate(text_p.operator(), text_p.value(), buf)?;
+        }
+        GValue::T(t) => {
+            let v = match t {
+                T::Id => "id",
+                T::Key => "key",
+                T::Label => "label",
+                T::Value => "value",
+            };
+            write_enum(T, v, buf)?;
+        }
+        GValue::Token(token) => write_enum(T, token.value(), buf)?,
+        GValue::Scope(s) => {
+            let v = match s {
+                Scope::Global => "global",
+                Scope::Local => "local",
+            };
+            write_enum(SCOPE, v, buf)?;
+        }
+        GValue::Order(o) => {
+            let v = match o {
+                Order::Asc => "asc",
+                Order::Desc => "desc",
+                Order::Shuffle => "shuffle",
+            };
+            write_enum(ORDER, v, buf)?;
+        }
+        GValue::Pop(pop) => write_enum(POP, &pop.to_string(), buf)?,
+        GValue::Cardinality(cardinality) => {
+            let v = match cardinality {
+                Cardinality::List => "list",
+                Cardinality::Single => "single",
+                Cardinality::Set => "set",
+            };
+            write_enum(CARDINALITY, v, buf)?;
+        }
+        GValue::Merge(merge) => {
+            let v = match merge {
+                Merge::OnCreate => "onCreate",
+                Merge::OnMatch => "onMatch",
+                Merge::OutV => "outV",
+                Merge::InV => "inV",
+            };
+            write_enum(MERGE, v, buf)?;
+        }
+        GValue::Direction(direction) => {
+            let v = match direction {
+                Direction::Out | Direction::From => "OUT",
+                Direction::In | Direction::To => "IN",
+            };
+            write_enum(DIRECTION, v, buf)?;
+        }
+        GValue::Column(column) => {
+            let v = match column {
+                Column::Keys => "keys",
+                Column::Values => "values",
+            };
+            write_enum(COLUMN, v, buf)?;
+        }
+        GValue::Metric(metric) => {
+            write_header(METRICS, buf);
+            write_metric(metric, buf)?;
+        }
+        GValue::TraversalMetrics(metrics) => {
+            write_header(TRAVERSAL_METRICS, buf);
+            buf.extend_from_slice(&millis_to_nanos(*metrics.duration()).to_be_bytes());
+            write_len(metrics.metrics().len(), buf)?;
+            for metric in metrics.metrics() {
+                write_header(METRICS, buf);
+                write_metric(metric, buf)?;
+            }
+        }
+        GValue::TraversalExplanation(_) | GValue::IntermediateRepr(_) => {
+            return Err(GremlinError::GraphBinary(format!(
+                "Type {:?} has no GraphBinary representation",
+                value
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn write_header(type_code: u8, buf: &mut Vec<u8>) {
+    buf.push(type_code);
+    buf.push(VALUE_FLAG_NONE);
+}
+
+pub(super) fn write_len(len: usize, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    let len = i32::try_from(len)
+        .map_err(|_| GremlinError::GraphBinary(format!("Length {} exceeds i32", len)))?;
+    buf.extend_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+// BigIntegers are written as {length}{two's complement big endian bytes}
+#[cfg(feature = "big_numbers")]
+fn write_big_integer(i: &num_bigint::BigInt, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    let bytes = i.to_signed_bytes_be();
+    write_len(bytes.len(), buf)?;
+    buf.extend_from_slice(&bytes);
+    Ok(())
+}
+
+// LocalDate {year}{month}{day}
+fn write_local_date(date: &NaiveDate, buf: &mut Vec<u8>) {
+    buf.extend_from_slice(&date.year().to_be_bytes());
+    buf.push(date.month() as u8);
+    buf.push(date.day() as u8);
+}
+
+// LocalDateTime {LocalDate}{LocalTime}, where LocalTime is the nanoseconds of the day
+fn write_local_date_time(date_time: &NaiveDateTime, buf: &mut Vec<u8>) {
+    write_local_date(&date_time.date(), buf);
+    let nanos = i64::from(date_time.num_seconds_from_midnight()) * NANOS_PER_SECOND
+        + i64::from(date_time.nanosecond());
+    buf.extend_from_slice(&nanos.to_be_bytes());
+}
+
+// OffsetDateTime {LocalDateTime}{ZoneOffset}, where ZoneOffset is the total seconds of the offset
+pub(super) fn write_offset_date_time(date_time: &DateTime<FixedOffset>, buf: &mut Vec<u8>) {
+    write_local_date_time(&date_time.naive_local(), buf);
+    buf.extend_from_slice(&date_time.offset().local_minus_utc().to_be_bytes());
+}
+
+pub(super) fn write_string(s: &str, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    write_len(s.len(), buf)?;
+    buf.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+fn write_enum(type_code: u8, value: &str, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    write_header(type_code, buf);
+    write_header(STRING, buf);
+    write_string(value, buf)
+}
+
+fn write_list<'a, I>(elements: I, len: usize, buf: &mut Vec<u8>) -> GremlinResult<()>
+where
+    I: Iterator<Item = &'a GValue>,
+{
+    write_len(len, buf)?;
+    for e in elements {
+        write(e, buf)?;
+    }
+    Ok(())
+}
+
+fn write_map(map: &Map, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    write_len(map.len(), buf)?;
+    for (k, v) in map.iter() {
+        write(&k.clone().into(), buf)?;
+        write(v, buf)?;
+    }
+    Ok(())
+}
+
+// Tree {length}{key_0}{tree_0}...{key_n}{tree_n}, subtrees are written without a type code
+fn write_tree(tree: &Tree, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    write_len(tree.len(), buf)?;
+    for (key, subtree) in tree.iter() {
+        write(key, buf)?;
+        write_tree(subtree, buf)?;
+    }
+    Ok(())
+}
+
+fn write_bytecode(code: &Bytecode, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    for instructions in [code.steps(), code.sources()].iter() {
+        write_len(instructions.len(), buf)?;
+        for instruction in instructions.iter() {
+            write_string(instruction.operator(), buf)?;
+            write_list(instruction.args().iter(), instruction.args().len(), buf)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_predicate(operator: &str, value: &GValue, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    write_string(operator, buf)?;
+    match value {
+        GValue::List(values) => write_list(values.iter(), values.len(), buf),
+        value => write_list(std::iter::once(value), 1, buf),
+    }
+}
+
+fn write_metric(metric: &Metric, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    write_string(metric.id(), buf)?;
+    write_string(metric.name(), buf)?;
+    buf.extend_from_slice(&millis_to_nanos(*metric.duration()).to_be_bytes());
+
+    let mut counts = HashMap::new();
+    counts.insert(
+        String::from("traverserCount"),
+        GValue::Int64(*metric.traversers()),
+    );
+    counts.insert(String::from("elementCount"), GValue::Int64(*metric.count()));
+    write_map(&counts.into(), buf)?;
+
+    let mut annotations = HashMap::new();
+    annotations.insert(
+        String::from("percentDur"),
+        GValue::Double(*metric.perc_duration()),
+    );
+    write_map(&annotations.into(), buf)?;
+
+    write_len(metric.nested().len(), buf)?;
+    for nested in metric.nested() {
+        write_header(METRICS, buf);
+        write_metric(nested, buf)?;
+    }
+    Ok(())
+}
+
+fn millis_to_nanos(millis: f64) -> i64 {
+    (millis * NANOS_PER_MILLI) as i64
+}
+
+// Read a fully qualified value {type_code}{value_flag}{value}
+pub(crate) fn read(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let type_code = read_u8(buf)?;
+    match read_value_flag(buf)? {
+        VALUE_FLAG_NULL => Ok(GValue::Null),
+        _ => read_value(type_code, buf),
+    }
+}
+
+pub(super) fn read_value(type_code: u8, buf: &mut &[u8]) -> GremlinResult<GValue> {
+    match type_code {
+        INT => Ok(GValue::Int32(read_i32(buf)?)),
+        LONG => Ok(GValue::Int64(read_i64(buf)?)),
+        STRING | CLASS => Ok(GValue::String(read_string(buf)?)),
+        DATE | TIMESTAMP => {
+            let millis = read_i64(buf)?;
+            Utc.timestamp_millis_opt(millis)
+                .single()
+                .map(GValue::Date)
+                .ok_or_else(|| GremlinError::GraphBinary(format!("Invalid date {}", millis)))
+        }
+        DOUBLE => Ok(GValue::Double(f64::from_be_bytes(read_array(buf)?))),
+        FLOAT => Ok(GValue::Float(f32::from_be_bytes(read_array(buf)?))),
+        SHORT => Ok(GValue::Short(i16::from_be_bytes(read_array(buf)?))),
+        BYTE => Ok(GValue::Byte(i8::from_be_bytes(read_array(buf)?))),
+        BIG_INTEGER => read_big_integer(buf),
+        BIG_DECIMAL => read_big_decimal(buf),
+        // Sets are read as lists, the same way GraphSON V3 does
+        LIST | SET => Ok(read_list(buf)?.into()),
+        MAP => Ok(GValue::Map(read_map(buf)?)),
+        UUID => Ok(GValue::Uuid(read_uuid(buf)?)),
+        EDGE => read_edge(buf),
+        BULK_SET => {
+            let len = read_len(buf)?;
+            let mut items = Vec::with_capacity(capacity(len, buf));
+            for _ in 0..len {
+                let value = read(buf)?;
+                items.push((value, read_i64(buf)?));
+            }
+            Ok(BulkSet::new(items).into())
+        }
+        TREE => Ok(GValue::Tree(read_tree(buf)?)),
+        PATH => {
+            let labels = read(buf)?;
+            let objects = read(buf)?.take::<List>()?;
+            Ok(Path::new(labels, objects).into())
+        }
+        PROPERTY => {
+            let key = read_string(buf)?;
+            let value = read(buf)?;
+            // parent
+            read(buf)?;
+            Ok(Property::new(key, value).into())
+        }
+        VERTEX => read_vertex(buf),
+        VERTEX_PROPERTY => {
+            let id = read_id(buf)?;
+            let label = read_string(buf)?;
+            let value = read(buf)?;
+            // parent
+            read(buf)?;
+            // properties
+            read(buf)?;
+            Ok(VertexProperty::new(id, label, value).into())
+        }
+        BYTECODE => read_bytecode(buf),
+        CARDINALITY => match read_enum(buf)?.as_str() {
+            "list" => Ok(GValue::Cardinality(Cardinality::List)),
+            "set" => Ok(GValue::Cardinality(Cardinality::Set)),
+            "single" => Ok(GValue::Cardinality(Cardinality::Single)),
+            other => Err(unknown_literal("cardinality", other)),
+        },
+        COLUMN => match read_enum(buf)?.as_str() {
+            "keys" => Ok(GValue::Column(Column::Keys)),
+            "values" => Ok(GValue::Column(Column::Values)),
+            other => Err(unknown_literal("column", other)),
+        },
+        DIRECTION => match read_enum(buf)?.as_str() {
+            "OUT" => Ok(GValue::Direction(Direction::Out)),
+            "IN" => Ok(GValue::Direction(Direction::In)),
+            other => Err(unknown_literal("direction", other)),
+        },
+        ORDER => match read_enum(buf)?.as_str() {
+            "asc" | "incr" => Ok(GValue::Order(Order::Asc)),
+            "desc" | "decr" => Ok(GValue::Order(Order::Desc)),
+            "shuffle" => Ok(GValue::Order(Order::Shuffle)),
+            other => Err(unknown_literal("order", other)),
+        },
+        POP => match read_enum(buf)?.as_str() {
+            "all" => Ok(GValue::Pop(Pop::All)),
+            "first" => Ok(GValue::Pop(Pop::First)),
+            "last" => Ok(GValue::Pop(Pop::Last)),
+            "mixed" => Ok(GValue::Pop(Pop::Mixed)),
+            other => Err(unknown_literal("pop", other)),
+        },
+        SCOPE => match read_enum(buf)?.as_str() {
+            "global" => Ok(GValue::Scope(Scope::Global)),
+            "local" => Ok(GValue::Scope(Scope::Local)),
+            other => Err(unknown_literal("scope", other)),
+        },
+        MERGE => match read_enum(buf)?.as_str() {
+            "onCreate" => Ok(GValue::Merge(Merge::OnCreate)),
+            "onMatch" => Ok(GValue::Merge(Merge::OnMatch)),
+            "outV" => Ok(GValue::Merge(Merge::OutV)),
+            "inV" => Ok(GValue::Merge(Merge::InV)),
+            other => Err(unknown_literal("merge", other)),
+        },
+        // Tokens are read the same way GraphSON V3 does
+        T => Ok(GValue::Token(Token::new(read_enum(buf)?))),
+        LAMBDA => {
+            let language = read_string(buf)?;
+            let script = read_string(buf)?;
+            let arguments = read_i32(buf)?;
+            Ok(Lambda::with_language(script, language)
+                .arguments(arguments)
+                .into())
+        }
+        BINDING => {
+            let key = read_string(buf)?;
+            let value = read(buf)?;
+            Ok(Binding::new(key, value).into())
+        }
+        P => {
+            let (operator, value) = read_predicate(buf)?;
+            Ok(GValue::P(P::new(operator, value)))
+        }
+        TEXT_P => {
+            let (operator, value) = read_predicate(buf)?;
+            Ok(GValue::TextP(TextP::new(operator, value)))
+        }
+        TRAVERSER => {
+            let bulk = read_i64(buf)?;
+            let value = read(buf)?;
+            Ok(Traverser::new(bulk, value).into())
+        }
+        BOOLEAN => Ok(GValue::Bool(read_u8(buf)? != 0)),
+        METRICS => Ok(read_metric(buf)?.into()),
+        TRAVERSAL_METRICS => {
+            let duration = read_i64(buf)? as f64 / NANOS_PER_MILLI;
+            let len = read_len(buf)?;
+            let mut metrics = Vec::with_capacity(capacity(len, buf));
+            for _ in 0..len {
+                metrics.push(read(buf)?.take::<Metric>()?);
+            }
+            Ok(TraversalMetrics::new(duration, metrics).into())
+        }
+        CHAR => read_char(buf),
+        BYTE_BUFFER => {
+            let len = read_len(buf)?;
+            Ok(GValue::Bytes(read_bytes(buf, len)?.to_vec()))
+        }
+        DURATION => {
+            let seconds = read_i64(buf)?;
+            let nanos = read_i32(buf)?;
+            Duration::try_seconds(seconds)
+                .and_then(|d| d.checked_add(&Duration::nanoseconds(nanos.into())))
+                .map(GValue::Duration)
+                .ok_or_else(|| {
+                    GremlinError::GraphBinary(format!("Invalid duration {}s {}ns", seconds, nanos))
+                })
+        }
+        INSTANT => {
+            let seconds = read_i64(buf)?;
+            let nanos = read_i32(buf)?;
+            u32::try_from(nanos)
+                .ok()
+                .and_then(|nanos| Utc.timestamp_opt(seconds, nanos).single())
+                .map(GValue::Instant)
+                .ok_or_else(|| {
+                    GremlinError::GraphBinary(format!("Invalid instant {}s {}ns", seconds, nanos))
+                })
+        }
+        LOCAL_DATE => Ok(GValue::LocalDate(read_local_date(buf)?)),
+        LOCAL_DATE_TIME => Ok(GValue::LocalDateTime(read_local_date_time(buf)?)),
+        OFFSET_DATE_TIME => Ok(GValue::OffsetDateTime(read_offset_date_time(buf)?)),
+        ZONED_DATE_TIME => Ok(GValue::ZonedDateTime(read_offset_date_time(buf)?)),
+        YEAR => Ok(GValue::Year(read_i32(buf)?)),
+        UNSPECIFIED_NULL => Ok(GValue::Null),
+        other => Err(GremlinError::GraphBinary(format!(
+            "Type code 0x{:02x} not supported",
+            other
+        ))),
+    }
+}
+
+fn read_vertex(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let id = read_id(buf)?;
+    let label = read_string(buf)?;
+
+    let mut properties: HashMap<String, Vec<VertexProperty>> = HashMap::new();
+    if let GValue::List(list) = read(buf)? {
+        for property in list {
+            let property = property.take::<VertexProperty>()?;
+            properties
+                .entry(property.label().clone())
+                .or_default()
+                .push(property);
+        }
+    }
+
+    Ok(Vertex::new(id, label, properties).into())
+}
+
+fn read_edge(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let id = read_id(buf)?;
+    let label = read_string(buf)?;
+    let in_v_id = read_id(buf)?;
+    let in_v_label = read_string(buf)?;
+    let out_v_id = read_id(buf)?;
+    let out_v_label = read_string(buf)?;
+    // parent
+    read(buf)?;
+
+    let mut properties = HashMap::new();
+    if let GValue::List(list) = read(buf)? {
+        for property in list {
+            let property = property.take::<Property>()?;
+            properties.insert(property.label().clone(), property);
+        }
+    }
+
+    Ok(Edge::new(
+        id,
+        label,
+        in_v_id,
+        in_v_label,
+        out_v_id,
+        out_v_label,
+        properties,
+    )
+    .into())
+}
+
+fn read_tree(buf: &mut &[u8]) -> GremlinResult<Tree> {
+    let len = read_len(buf)?;
+    let mut branches = Vec::with_capacity(capacity(len, buf));
+    for _ in 0..len {
+        let key = read(buf)?;
+        branches.push((key, read_tree(buf)?));
+    }
+    Ok(Tree::new(branches))
+}
+
+fn read_bytecode(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let mut code = Bytecode::new();
+    for _ in 0..read_len(buf)? {
+        let name = read_string(buf)?;
+        code.add_step(name, read_list(buf)?);
+    }
+    for _ in 0..read_len(buf)? {
+        let name = read_string(buf)?;
+        code.add_source(name, read_list(buf)?);
+    }
+    Ok(GValue::Bytecode(code))
+}
+
+fn read_predicate(buf: &mut &[u8]) -> GremlinResult<(String, GValue)> {
+    let operator = read_string(buf)?;
+    let mut values = read_list(buf)?;
+    let value = match values.len() {
+        1 => values.pop().unwrap_or(GValue::Null),
+        _ => values.into(),
+    };
+    Ok((operator, value))
+}
+
+fn read_metric(buf: &mut &[u8]) -> GremlinResult<Metric> {
+    let id = read_string(buf)?;
+    let name = read_string(buf)?;
+    let duration = read_i64(buf)? as f64 / NANOS_PER_MILLI;
+
+    let counts = read_map(buf)?;
+    let traversers = counts.try_get::<_, Option<i64>>("traverserCount")?;
+    let count = counts.try_get::<_, Option<i64>>("elementCount")?;
+
+    let annotations = read_map(buf)?;
+    let perc_duration = annotations.try_get::<_, Option<f64>>("percentDur")?;
+
+    let len = read_len(buf)?;
+    let mut nested = Vec::with_capacity(capacity(len, buf));
+    for _ in 0..len {
+        nested.push(read(buf)?.take::<Metric>()?);
+    }
+
+    Ok(Metric::new(
+        id,
+        name,
+        duration,
+        count.unwrap_or_default(),
+        traversers.unwrap_or_default(),
+        perc_duration.unwrap_or_default(),
+        nested,
+    ))
+}
+
+fn read_id(buf: &mut &[u8]) -> GremlinResult<GID> {
+    match read(buf)? {
+        GValue::String(s) => Ok(GID::String(s)),
+        GValue::Int32(i) => Ok(GID::Int32(i)),
+        GValue::Int64(i) => Ok(GID::Int64(i)),
+        GValue::Uuid(uuid) => Ok(uuid.into()),
+        other => Err(GremlinError::GraphBinary(format!(
+            "{:?} cannot be an id",
+            other
+        ))),
+    }
+}
+
+fn read_list(buf: &mut &[u8]) -> GremlinResult<Vec<GValue>> {
+    let len = read_len(buf)?;
+    let mut elements = Vec::with_capacity(capacity(len, buf));
+    for _ in 0..len {
+        elements.push(read(buf)?);
+    }
+    Ok(elements)
+}
+
+fn read_map(buf: &mut &[u8]) -> GremlinResult<Map> {
+    let len = read_len(buf)?;
+    let mut map = HashMap::with_capacity(capacity(len, buf));
+    for _ in 0..len {
+        let key = GKey::from_gvalue(read(buf)?)?;
+        let value = read(buf)?;
+        map.insert(key, value);
+    }
+    Ok(map.into())
+}
+
+fn read_enum(buf: &mut &[u8]) -> GremlinResult<String> {
+    read(buf)?.take::<String>()
+}
+
+fn unknown_literal(kind: &str, literal: &str) -> GremlinError {
+    GremlinError::GraphBinary(format!("Unknown {} literal {}", kind, literal))
+}
+
+fn read_value_flag(buf: &mut &[u8]) -> GremlinResult<u8> {
+    match read_u8(buf)? {
+        flag @ VALUE_FLAG_NONE | flag @ VALUE_FLAG_NULL => Ok(flag),
+        flag => Err(GremlinError::GraphBinary(format!(
+            "Unexpected value flag 0x{:02x}",
+            flag
+        ))),
+    }
+}
+
+#[cfg(feature = "big_numbers")]
+fn read_big_integer(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let len = read_len(buf)?;
+    let bytes = read_bytes(buf, len)?;
+    Ok(GValue::BigInteger(
+        num_bigint::BigInt::from_signed_bytes_be(bytes),
+    ))
+}
+
+// Without the big_numbers feature BigIntegers are read as Int64 when they fit
+#[cfg(not(feature = "big_numbers"))]
+fn read_big_integer(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    read_unscaled(buf).map(GValue::Int64)
+}
+
+#[cfg(feature = "big_numbers")]
+fn read_big_decimal(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let scale = read_i32(buf)?;
+    let len = read_len(buf)?;
+    let unscaled = num_bigint::BigInt::from_signed_bytes_be(read_bytes(buf, len)?);
+    Ok(GValue::BigDecimal(bigdecimal::BigDecimal::new(
+        unscaled,
+        i64::from(scale),
+    )))
+}
+
+// Without the big_numbers feature BigDecimals are read as Double
+#[cfg(not(feature = "big_numbers"))]
+fn read_big_decimal(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let scale = read_i32(buf)?;
+    let unscaled = read_unscaled(buf)?;
+    Ok(GValue::Double(unscaled as f64 / 10f64.powi(scale)))
+}
+
+// Sign extends a {length}{two's complement big endian bytes} integer into an i64
+#[cfg(not(feature = "big_numbers"))]
+fn read_unscaled(buf: &mut &[u8]) -> GremlinResult<i64> {
+    let len = read_len(buf)?;
+    let bytes = read_bytes(buf, len)?;
+    if bytes.len() > 8 {
+        return Err(GremlinError::GraphBinary(format!(
+            "BigInteger of {} bytes requires the big_numbers feature",
+            bytes.len()
+        )));
+    }
+    let fill = match bytes.first() {
+        Some(b) if *b & 0x80 != 0 => 0xff,
+        _ => 0x00,
+    };
+    let mut array = [fill; 8];
+    array[8 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i64::from_be_bytes(array))
+}
+
+fn read_local_date(buf: &mut &[u8]) -> GremlinResult<NaiveDate> {
+    let year = read_i32(buf)?;
+    let month = read_u8(buf)?;
+    let day = read_u8(buf)?;
+    NaiveDate::from_ymd_opt(year, month.into(), day.into()).ok_or_else(|| {
+        GremlinError::GraphBinary(format!("Invalid date {}-{}-{}", year, month, day))
+    })
+}
+
+fn read_local_date_time(buf: &mut &[u8]) -> GremlinResult<NaiveDateTime> {
+    let date = read_local_date(buf)?;
+    let nanos = read_i64(buf)?;
+    u32::try_from(nanos / NANOS_PER_SECOND)
+        .ok()
+        .and_then(|seconds| {
+            NaiveTime::from_num_seconds_from_midnight_opt(
+                seconds,
+                (nanos % NANOS_PER_SECOND) as u32,
+            )
+        })
+        .map(|time| date.and_time(time))
+        .ok_or_else(|| GremlinError::GraphBinary(format!("Invalid time of day {}ns", nanos)))
+}
+
+pub(super) fn read_offset_date_time(buf: &mut &[u8]) -> GremlinResult<DateTime<FixedOffset>> {
+    let date_time = read_local_date_time(buf)?;
+    let offset = read_i32(buf)?;
+    FixedOffset::east_opt(offset)
+        .and_then(|offset| offset.from_local_datetime(&date_time).single())
+        .ok_or_else(|| GremlinError::GraphBinary(format!("Invalid zone offset {}s", offset)))
+}
+
+// Chars are written as their UTF-8 encoding, its leading byte gives the length
+fn read_char(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let len = match buf.first() {
+        Some(b) if *b < 0x80 => 1,
+        Some(b) if *b & 0xe0 == 0xc0 => 2,
+        Some(b) if *b & 0xf0 == 0xe0 => 3,
+        Some(b) if *b & 0xf8 == 0xf0 => 4,
+        _ => return Err(GremlinError::GraphBinary(String::from("Invalid char"))),
+    };
+    std::str::from_utf8(read_bytes(buf, len)?)
+        .ok()
+        .and_then(|s| s.chars().next())
+        .map(GValue::Char)
+        .ok_or_else(|| GremlinError::GraphBinary(String::from("Invalid char")))
+}
+
+pub(super) fn read_string(buf: &mut &[u8]) -> GremlinResult<String> {
+    let len = read_len(buf)?;
+    String::from_utf8(read_bytes(buf, len)?.to_vec())
+        .map_err(|e| GremlinError::GraphBinary(e.to_string()))
+}
+
+fn read_uuid(buf: &mut &[u8]) -> GremlinResult<Uuid> {
+    Ok(Uuid::from_bytes(read_array(buf)?))
+}
+
+pub(super) fn read_len(buf: &mut &[u8]) -> GremlinResult<usize> {
+    let len = read_i32(buf)?;
+    usize::try_from(len).map_err(|_| GremlinError::GraphBinary(format!("Invalid length {}", len)))
+}
+
+// Each element takes at least a byte, so a length read from the wire never preallocates more
+// than the remaining buffer
+pub(super) fn capacity(len: usize, buf: &[u8]) -> usize {
+    len.min(buf.len())
+}
+
+pub(super) fn read_u8(buf: &mut &[u8]) -> GremlinResult<u8> {
+    Ok(read_bytes(buf, 1)?[0])
+}
+
+pub(super) fn read_i32(buf: &mut &[u8]) -> GremlinResult<i32> {
+    Ok(i32::from_be_bytes(read_array(buf)?))
+}
+
+pub(super) fn read_i64(buf: &mut &[u8]) -> GremlinResult<i64> {
+    Ok(i64::from_be_bytes(read_array(buf)?))
+}
+
+fn read_array<const N: usize>(buf: &mut &[u8]) -> GremlinResult<[u8; N]> {
+    let mut array = [0; N];
+    array.copy_from_slice(read_bytes(buf, N)?);
+    Ok(array)
+}
+
+fn read_bytes<'a>(buf: &mut &'a [u8], len: usize) -> GremlinResult<&'a [u8]> {
+    if buf.len() < len {
+        return Err(GremlinError::EndOfBuffer {
+            expected: len,
+            found: buf.len(),
+        });
+    }
+    let (bytes, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(bytes)
+}
+
+// TESTS
+#[cfg(test)]
+mod tests {
+
+    use super::{read, read_response, write, write_request};
+    use crate::process::traversal::{Bytecode, Order};
+    use crate::structure::{
+        Binding, BulkSet, GValue, Lambda, Metric, Path, Property, TextP, Token, TraversalMetrics,
+        Traverser, Tree, Vertex, VertexProperty, GID, P,
+    };
+    use crate::{edge, vertex};
+    use chrono::offset::TimeZone;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn round_trip(value: GValue) -> GValue {
+        let mut buf = vec![];
+        write(&value, &mut buf).expect("Failed to serialize");
+        let mut slice = &buf[..];
+        let result = read(&mut slice).expect("Failed to deserialize");
+        assert!(slice.is_empty(), "Trailing bytes left after read");
+        result
+    }
+
+    #[test]
+    fn test_huge_lengths() {
+        let len = [0x7f, 0xff, 0xff, 0xff];
+        let frames = [
+            [&[0x09, 0x00][..], &len].concat(),
+            [&[0x0a, 0x00][..], &len].concat(),
+            [&[0x2a, 0x00][..], &len].concat(),
+            [&[0x2b, 0x00][..], &len].concat(),
+            [&[0x2d, 0x00, 0, 0, 0, 0, 0, 0, 0, 0][..], &len].concat(),
+        ];
+
+        for frame in &frames {
+            assert!(read(&mut &frame[..]).is_err(), "{:?}", frame);
+        }
+    }
+
+    #[test]
+    fn test_number_input() {
+        let value = read(&mut &[0x01, 0x00, 0x00, 0x00, 0x00, 0x1f][..])
+            .expect("Failed to deserialize an Int32");
+        assert_eq!(value, GValue::Int32(31));
+
+        let value = read(&mut &[0x02, 0x00, 0, 0, 0, 0, 0, 0, 0, 0x1f][..])
+            .expect("Failed to deserialize an Int64");
+        assert_eq!(value, GValue::Int64(31));
+
+        assert_eq!(round_trip(GValue::Float(31.3)), GValue::Float(31.3));
+        assert_eq!(round_trip(GValue::Double(31.3)), GValue::Double(31.3));
+        assert_eq!(round_trip(GValue::Bool(true)), GValue::Bool(true));
+
+        let date = GValue::Date(chrono::Utc.timestamp_millis_opt(1551825863).unwrap());
+        assert_eq!(round_trip(date.clone()), date);
+
+        let uuid = GValue::Uuid(Uuid::parse_str("41d2e28a-20a4-4ab0-b379-d810dede3786").unwrap());
+        assert_eq!(round_trip(uuid.clone()), uuid);
+    }
+
+    #[test]
+    fn test_byte_and_short() {
+        let value = read(&mut &[0x24, 0x00, 0xe1][..]).expect("Failed to deserialize a Byte");
+        assert_eq!(value, GValue::Byte(-31));
+
+        let value =
+            read(&mut &[0x26, 0x00, 0x0c, 0x1c][..]).expect("Failed to deserialize a Short");
+        assert_eq!(value, GValue::Short(3100));
+
+        assert_eq!(round_trip(GValue::Byte(i8::MIN)), GValue::Byte(i8::MIN));
+        assert_eq!(round_trip(GValue::Short(i16::MAX)), GValue::Short(i16::MAX));
+    }
+
+    #[test]
+    #[cfg(feature = "big_numbers")]
+    fn test_big_numbers() {
+        let value = read(&mut &[0x23, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x38][..])
+            .expect("Failed to deserialize a BigInteger");
+        assert_eq!(value, GValue::BigInteger((-200).into()));
+
+        let big: num_bigint::BigInt = "-123456789012345678901234567890".parse().unwrap();
+        assert_eq!(
+            round_trip(GValue::BigInteger(big.clone())),
+            GValue::BigInteger(big)
+        );
+
+        let decimal: bigdecimal::BigDecimal = "1234567890.0123456789".parse().unwrap();
+        assert_eq!(
+            round_trip(GValue::BigDecimal(decimal.clone())),
+            GValue::BigDecimal(decimal)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "big_numbers"))]
+    fn test_big_numbers_fallback() {
+        let value = read(&mut &[0x23, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x38][..])
+            .expect("Failed to deserialize a BigInteger");
+        assert_eq!(value, GValue::Int64(-200));
+
+        let value = read(
+            &mut &[
+                0x22, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x7b,
+            ][..],
+        )
+        .expect("Failed to deserialize a BigDecimal");
+        assert_eq!(value, GValue::Double(1.23));
+
+        let mut buf = vec![0x23, 0x00, 0x00, 0x00, 0x00, 0x09];
+        buf.extend_from_slice(&[0x01; 9]);
+        assert!(read(&mut &buf[..]).is_err());
+    }
+
+    #[test]
+    fn test_temporal() {
+        let value = read(
+            &mut &[
+                0x81, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x1d, 0xcd, 0x65, 0x00,
+            ][..],
+        )
+        .expect("Failed to deserialize a Duration");
+        assert_eq!(
+            value,
+            GValue::Duration(chrono::Duration::milliseconds(-500))
+        );
+
+        let value = read(&mut &[0x84, 0x00, 0x00, 0x00, 0x07, 0xe0, 0x01, 0x1f][..])
+            .expect("Failed to deserialize a LocalDate");
+        let date = chrono::NaiveDate::from_ymd_opt(2016, 1, 31).unwrap();
+        assert_eq!(value, GValue::LocalDate(date));
+
+        let date_time = date.and_hms_nano_opt(12, 30, 15, 123).unwrap();
+        let offset = chrono::FixedOffset::west_opt(5 * 3600).unwrap();
+        let offset_date_time = offset.from_local_datetime(&date_time).unwrap();
+        let values = vec![
+            GValue::Duration(chrono::Duration::seconds(3723) + chrono::Duration::nanoseconds(4)),
+            GValue::Instant(chrono::Utc.timestamp_opt(1481733559, 349).unwrap()),
+            GValue::LocalDateTime(date_time),
+            GValue::OffsetDateTime(offset_date_time),
+            GValue::ZonedDateTime(offset_date_time),
+            GValue::Year(2016),
+        ];
+        for value in values {
+            assert_eq!(round_trip(value.clone()), value);
+        }
+    }
+
+    #[test]
+    fn test_char_and_bytes() {
+        let value = read(&mut &[0x80, 0x00, 0xc3, 0xa9][..]).expect("Failed to deserialize a Char");
+        assert_eq!(value, GValue::Char('é'));
+        assert_eq!(round_trip(GValue::Char('a')), GValue::Char('a'));
+        assert_eq!(round_trip(GValue::Char('🦀')), GValue::Char('🦀'));
+        assert!(read(&mut &[0x80, 0x00, 0xff][..]).is_err());
+
+        let value = read(&mut &[0x25, 0x00, 0x00, 0x00, 0x00, 0x02, 0xca, 0xfe][..])
+            .expect("Failed to deserialize a ByteBuffer");
+        assert_eq!(value, GValue::Bytes(vec![0xca, 0xfe]));
+        assert_eq!(round_trip(GValue::Bytes(vec![])), GValue::Bytes(vec![]));
+    }
+
+    #[test]
+    fn test_tree() {
+        let leaf = Tree::new(vec![(GValue::from("leaf"), Tree::default())]);
+        let tree = GValue::Tree(Tree::new(vec![
+            (GValue::from("root"), leaf),
+            (GValue::Int32(1), Tree::default()),
+        ]));
+        assert_eq!(round_trip(tree.clone()), tree);
+    }
+
+    #[test]
+    fn test_bulk_set() {
+        let value = read(
+            &mut &[
+                0x2a, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+            ][..],
+        )
+        .expect("Failed to deserialize a BulkSet");
+        assert_eq!(
+            value,
+            GValue::BulkSet(BulkSet::new(vec![(GValue::Int32(31), 3)]))
+        );
+
+        let set = GValue::BulkSet(BulkSet::new(vec![
+            (GValue::from("a"), 2),
+            (GValue::from("b"), 1),
+        ]));
+        assert_eq!(round_trip(set.clone()), set);
+    }
+
+    #[test]
+    fn test_lambda() {
+        let lambda = GValue::Lambda(Lambda::new("it.get()"));
+        assert_eq!(round_trip(lambda.clone()), lambda);
+
+        let mut buf = vec![];
+        write(&lambda, &mut buf).expect("Failed to serialize a Lambda");
+        assert_eq!(&buf[..6], &[0x1d, 0x00, 0x00, 0x00, 0x00, 0x0e]);
+        assert_eq!(&buf[6..20], b"gremlin-groovy");
+        assert_eq!(&buf[buf.len() - 4..], &[0xff, 0xff, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn test_binding() {
+        let binding = GValue::Binding(Binding::new("x", 1));
+        assert_eq!(round_trip(binding.clone()), binding);
+
+        let mut buf = vec![];
+        write(&binding, &mut buf).expect("Failed to serialize a Binding");
+        assert_eq!(
+            buf,
+            vec![0x14, 0x00, 0x00, 0x00, 0x00, 0x01, b'x', 0x01, 0x00, 0x00, 0x00, 0x00, 0x01]
+        );
+    }
+
+    #[test]
+    fn test_string() {
+        let mut buf = vec![];
+        write(&GValue::from("abc"), &mut buf).expect("Failed to serialize a String");
+        assert_eq!(
+            buf,
+            vec![0x03, 0x00, 0x00, 0x00, 0x00, 0x03, b'a', b'b', b'c']
+        );
+
+        let value = read(&mut &[0x03, 0x01][..]).expect("Failed to deserialize a null String");
+        assert_eq!(value, GValue::Null);
+    }
+
+    #[test]
+    fn test_collections() {
+        let list: GValue = vec![GValue::Int32(1), GValue::from("2")].into();
+        assert_eq!(round_trip(list.clone()), list);
+
+        let set = GValue::Set(vec![GValue::Int32(1), GValue::Int32(2)].into());
+        assert_eq!(
+            round_trip(set),
+            vec![GValue::Int32(1), GValue::Int32(2)].into()
+        );
+
+        let mut map = HashMap::new();
+        map.insert(String::from("a"), GValue::Int32(1));
+        map.insert(String::from("b"), GValue::from("marko"));
+        let map = GValue::from(map);
+        assert_eq!(round_trip(map.clone()), map);
+    }
+
+    #[test]
+    fn test_elements() {
+        let vertex: GValue = vertex!({
+            id => 1,
+            label => "person",
+            properties => {}
+        })
+        .into();
+        assert_eq!(round_trip(vertex.clone()), vertex);
+
+        let edge: GValue = edge!({
+            id => 13,
+            label => "develops",
+            inV => { id => 10, label => "software" },
+            outV => { id => 1, label => "person" },
+            properties => {}
+        })
+        .into();
+        assert_eq!(round_trip(edge.clone()), edge);
+
+        let property: GValue = VertexProperty::new(GID::Int64(0), "name", "marko").into();
+        assert_eq!(round_trip(property.clone()), property);
+
+        let property: GValue = Property::new("since", 2009).into();
+        assert_eq!(round_trip(property.clone()), property);
+    }
+
+    #[test]
+    fn test_vertex_with_properties() {
+        let mut buf = vec![0x11, 0x00];
+        write(&GValue::Int32(1), &mut buf).unwrap();
+        buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x06]);
+        buf.extend_from_slice(b"person");
+        buf.extend_from_slice(&[0x09, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        write(
+            &VertexProperty::new(GID::Int64(0), "name", "marko").into(),
+            &mut buf,
+        )
+        .unwrap();
+
+        let result = read(&mut &buf[..]).expect("Failed to deserialize a Vertex");
+
+        assert_eq!(
+            result,
+            vertex!({
+                id => 1,
+                label => "person",
+                properties => {
+                    "name" => [ { id => 0_i64, value => "marko"}]
+                }
+            })
+            .into()
+        );
+        let vertex = result.take::<Vertex>().unwrap();
+        assert_eq!(
+            vertex.property("name").unwrap().get::<String>().unwrap(),
+            "marko"
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        let empty: GValue = vec![].into();
+        let path: GValue = Path::new(
+            vec![empty.clone(), empty].into(),
+            vec![
+                vertex!({ id => 1, label => "person", properties => {}}).into(),
+                vertex!({ id => 10, label => "software", properties => {}}).into(),
+            ]
+            .into(),
+        )
+        .into();
+        assert_eq!(round_trip(path.clone()), path);
+    }
+
+    #[test]
+    fn test_traversal_types() {
+        let mut code = Bytecode::new();
+        code.add_source(String::from("withSideEffect"), vec!["a".into(), 1.into()]);
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("has"),
+            vec!["name".into(), P::eq("marko").into()],
+        );
+        code.add_step(String::from("order"), vec![]);
+        code.add_step(String::from("by"), vec!["age".into(), Order::Desc.into()]);
+        let code = GValue::Bytecode(code);
+        assert_eq!(round_trip(code.clone()), code);
+
+        let p = GValue::P(P::within(vec![1, 2, 3]));
+        assert_eq!(round_trip(p.clone()), p);
+
+        let text_p = GValue::TextP(TextP::containing("ark"));
+        assert_eq!(round_trip(text_p.clone()), text_p);
+
+        assert_eq!(
+            round_trip(GValue::T(crate::structure::T::Label)),
+            GValue::Token(Token::new("label"))
+        );
+
+        let traverser: GValue = Traverser::new(2, GValue::Int32(1)).into();
+        assert_eq!(round_trip(traverser.clone()), traverser);
+    }
+
+    #[test]
+    fn test_traversal_metrics() {
+        let metrics: GValue = TraversalMetrics::new(
+            0.004,
+            vec![Metric::new(
+                "7.0.0()",
+                "TinkerGraphStep(vertex,[~label.eq(person)])",
+                100.0,
+                4,
+                4,
+                25.0,
+                vec![],
+            )],
+        )
+        .into();
+        assert_eq!(round_trip(metrics.clone()), metrics);
+    }
+
+    #[test]
+    fn test_request_response() {
+        let id = Uuid::new_v4();
+        let mut args = HashMap::new();
+        args.insert(String::from("gremlin"), GValue::from("g.V()"));
+        let request = write_request("eval", "", &args, id).expect("Failed to write request");
+
+        assert_eq!(request[0], 0x81);
+        assert_eq!(&request[1..17], id.as_bytes());
+
+        let mut response = vec![0x81, 0x00];
+        response.extend_from_slice(id.as_bytes());
+        // status code, null message, attributes with one entry and empty meta
+        response.extend_from_slice(&[0x00, 0x00, 0x00, 0xc8, 0x01]);
+        response.extend_from_slice(&[0, 0, 0, 1]);
+        write(&GValue::from("host"), &mut response).unwrap();
+        write(&GValue::from("/127.0.0.1:8182"), &mut response).unwrap();
+        response.extend_from_slice(&[0, 0, 0, 0]);
+        write(
+            &vec![Traverser::new(1, GValue::Int64(6)).into()].into(),
+            &mut response,
+        )
+        .unwrap();
+
+        let response = read_response(&response).expect("Failed to read response");
+
+        assert_eq!(response.request_id, id);
+        assert_eq!(response.status.code, 200);
+        assert_eq!(response.status.message, "");
+        assert_eq!(
+            response.status.attributes.get("host"),
+            Some(&GValue::from("/127.0.0.1:8182"))
+        );
+        assert!(response.result.meta.is_empty());
+        assert_eq!(
+            response.result.data,
+            Some(vec![Traverser::new(1, GValue::Int64(6)).into()].into())
+        );
+    }
+}
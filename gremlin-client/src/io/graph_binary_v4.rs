@@ -0,0 +1,745 @@
+//! GraphBinary 4, the serialization format of the TinkerPop 4 HTTP protocol
+//! [docs](https://tinkerpop.apache.org/docs/4.0.0/dev/io/#graphbinary).
+//!
+//! Scalars keep their GraphBinary 1 encoding, graph elements carry lists of labels and the
+//! response streams its results followed by a status.
+
+use super::graph_binary_v1::{
+    self as v1, capacity, read_i32, read_i64, read_len, read_offset_date_time, read_string,
+    read_u8, write_header, write_len, write_offset_date_time, write_string,
+};
+use crate::message::{ReponseStatus, Response, ResponseResult};
+use crate::process::traversal::gremlin_lang;
+use crate::structure::{
+    BulkSet, Edge, GKey, List, Map, Path, Property, Traverser, Tree, Vertex, VertexProperty, GID,
+};
+use crate::{FromGValue, GValue, GremlinError, GremlinResult};
+use chrono::FixedOffset;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub(crate) const CONTENT_TYPE: &str = "application/vnd.graphbinary-v4.0";
+
+const VERSION: u8 = 0x81;
+
+const VALUE_FLAG_NONE: u8 = 0x00;
+const VALUE_FLAG_NULL: u8 = 0x01;
+const VALUE_FLAG_BULK: u8 = 0x02;
+
+// Data types [docs](https://tinkerpop.apache.org/docs/4.0.0/dev/io/#_data_type_codes_2)
+const INT: u8 = 0x01;
+const LONG: u8 = 0x02;
+const STRING: u8 = 0x03;
+const DATE_TIME: u8 = 0x04;
+const DOUBLE: u8 = 0x07;
+const FLOAT: u8 = 0x08;
+const LIST: u8 = 0x09;
+const MAP: u8 = 0x0a;
+const SET: u8 = 0x0b;
+const UUID: u8 = 0x0c;
+const EDGE: u8 = 0x0d;
+const PATH: u8 = 0x0e;
+const PROPERTY: u8 = 0x0f;
+const VERTEX: u8 = 0x11;
+const VERTEX_PROPERTY: u8 = 0x12;
+const DIRECTION: u8 = 0x18;
+const T: u8 = 0x20;
+const BIG_DECIMAL: u8 = 0x22;
+const BIG_INTEGER: u8 = 0x23;
+const BYTE: u8 = 0x24;
+const BINARY: u8 = 0x25;
+const SHORT: u8 = 0x26;
+const BOOLEAN: u8 = 0x27;
+const TREE: u8 = 0x2b;
+const MERGE: u8 = 0x2e;
+const CHAR: u8 = 0x80;
+const DURATION: u8 = 0x81;
+const MARKER: u8 = 0xfd;
+const UNSPECIFIED_NULL: u8 = 0xfe;
+
+// Request message {version}{fields}{gremlin}, where fields is a map of the request settings
+pub(crate) fn write_request(op: &str, args: &HashMap<String, GValue>) -> GremlinResult<Vec<u8>> {
+    if args.contains_key("session") {
+        return Err(GremlinError::Generic(String::from(
+            "Sessions are not supported by the TinkerPop 4 protocol",
+        )));
+    }
+
+    let mut fields: HashMap<String, GValue> = HashMap::new();
+    let gremlin = match (op, args.get("gremlin")) {
+        ("eval", Some(GValue::String(script))) => {
+            let language = args
+                .get("language")
+                .cloned()
+                .unwrap_or_else(|| GValue::from("gremlin-lang"));
+            fields.insert(String::from("language"), language);
+            if let Some(bindings) = args.get("bindings") {
+                fields.insert(String::from("bindings"), bindings.clone());
+            }
+            script.clone()
+        }
+        ("bytecode", Some(GValue::Bytecode(code))) => {
+            let script = gremlin_lang::translate(code)?;
+            fields.insert(String::from("language"), GValue::from("gremlin-lang"));
+            fields.insert(String::from("bindings"), GValue::from(script.bindings));
+            // Traversal results are read back as traversers
+            fields.insert(String::from("bulkResults"), GValue::Bool(true));
+            script.gremlin
+        }
+        _ => {
+            return Err(GremlinError::Generic(format!(
+                "Operation {} is not supported by the TinkerPop 4 protocol",
+                op
+            )))
+        }
+    };
+    if let Some(GValue::Map(aliases)) = args.get("aliases") {
+        if let Some(alias) = aliases.get("g") {
+            fields.insert(String::from("g"), alias.clone());
+        }
+    }
+    // Request options, the evaluation timeout was renamed in TinkerPop 4
+    for (key, value) in args {
+        let key = match key.as_str() {
+            "gremlin" | "language" | "bindings" | "aliases" => continue,
+            "evaluationTimeout" => "timeoutMs",
+            key => key,
+        };
+        fields
+            .entry(String::from(key))
+            .or_insert_with(|| value.clone());
+    }
+
+    let mut buf = vec![VERSION];
+    write_len(fields.len(), &mut buf)?;
+    for (key, value) in fields {
+        write(&GValue::String(key), &mut buf)?;
+        write(&value, &mut buf)?;
+    }
+    write_string(&gremlin, &mut buf)?;
+    Ok(buf)
+}
+
+/// Reads the results of a response body as it is streamed by the server.
+///
+/// Every call decodes the complete values buffered so far, the final response carries the
+/// status sent after the last result.
+#[derive(Debug, Default)]
+pub(crate) struct ResponseReader {
+    bulked: Option<bool>,
+    finished: bool,
+    // Length of the buffer needed to get past the point where the last value was cut
+    resume_at: usize,
+}
+
+impl ResponseReader {
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Decode the values available at the start of `buffer`, removing the bytes consumed.
+    ///
+    /// Returns a partial response (206) holding those values, the final response once the
+    /// status has been read, or `None` when more bytes are needed. Once `complete` marks the
+    /// end of the body, a value cut by the end of the buffer is returned as an error.
+    pub(crate) fn read(
+        &mut self,
+        buffer: &mut Vec<u8>,
+        complete: bool,
+    ) -> GremlinResult<Option<Response>> {
+        if self.finished || (buffer.len() < self.resume_at && !complete) {
+            return Ok(None);
+        }
+        let mut buf = &buffer[..];
+        let bulked = match self.bulked {
+            Some(bulked) => bulked,
+            None if buf.len() < 2 => return Ok(None),
+            None => {
+                let version = read_u8(&mut buf)?;
+                if version != VERSION {
+                    return Err(GremlinError::GraphBinary(format!(
+                        "Unsupported response version 0x{:02x}",
+                        version
+                    )));
+                }
+                let bulked = read_u8(&mut buf)? == 0x01;
+                self.bulked = Some(bulked);
+                bulked
+            }
+        };
+
+        let mut results = vec![];
+        let mut status = None;
+        self.resume_at = 0;
+        while status.is_none() && !buf.is_empty() {
+            let mut next = buf;
+            let result = match next.first() == Some(&MARKER) {
+                true => read_status(&mut next).map(|s| status = Some(s)),
+                false => read(&mut next)
+                    .and_then(|value| match bulked {
+                        true => Ok(Traverser::new(read_i64(&mut next)?, value).into()),
+                        false => Ok(value),
+                    })
+                    .map(|value| results.push(value)),
+            };
+            match result {
+                Ok(()) => buf = next,
+                // A value cut by the end of the buffer is decoded again once the missing
+                // bytes arrived
+                Err(GremlinError::EndOfBuffer { expected, found }) if !complete => {
+                    self.resume_at = buf.len() + expected - found;
+                    break;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        let consumed = buffer.len() - buf.len();
+        buffer.drain(..consumed);
+
+        let data = match results.is_empty() {
+            true => None,
+            false => Some(GValue::List(results.into())),
+        };
+        match status {
+            Some(status) => {
+                self.finished = true;
+                Ok(Some(Response {
+                    request_id: Uuid::nil(),
+                    result: ResponseResult {
+                        data,
+                        meta: Map::default(),
+                    },
+                    status,
+                }))
+            }
+            None if data.is_some() => Ok(Some(Response {
+                request_id: Uuid::nil(),
+                result: ResponseResult {
+                    data,
+                    meta: Map::default(),
+                },
+                status: ReponseStatus {
+                    code: 206,
+                    message: String::default(),
+                    attributes: Map::default(),
+                },
+            })),
+            None => Ok(None),
+        }
+    }
+}
+
+// Response message [docs](https://tinkerpop.apache.org/docs/4.0.0/dev/io/#_response_message_2)
+pub(crate) fn read_response(response: &[u8]) -> GremlinResult<Response> {
+    let mut buffer = response.to_vec();
+    let mut reader = ResponseReader::default();
+    let mut results = vec![];
+    while let Some(mut response) = reader.read(&mut buffer, true)? {
+        if let Some(GValue::List(list)) = response.result.data.take() {
+            results.extend(list);
+        }
+        if reader.is_finished() {
+            response.result.data = match results.is_empty() {
+                true => None,
+                false => Some(GValue::List(results.into())),
+            };
+            return Ok(response);
+        }
+    }
+    Err(GremlinError::GraphBinary(String::from(
+        "Response ended before its status",
+    )))
+}
+
+// Status {marker}{code}{message}{exception}, where message and exception are nullable strings
+fn read_status(buf: &mut &[u8]) -> GremlinResult<ReponseStatus> {
+    // {type_code}{value_flag}{value} of the end of stream marker
+    for _ in 0..3 {
+        read_u8(buf)?;
+    }
+    let code = read_i32(buf)? as i16;
+    let message = read_nullable_string(buf)?;
+    let exception = read_nullable_string(buf)?;
+    let message = match (message, exception) {
+        (Some(message), _) => message,
+        (None, Some(exception)) => exception,
+        (None, None) => String::default(),
+    };
+    // TinkerPop 4 dropped status attributes and result meta from the response
+    Ok(ReponseStatus {
+        code,
+        message,
+        attributes: Map::default(),
+    })
+}
+
+fn read_nullable_string(buf: &mut &[u8]) -> GremlinResult<Option<String>> {
+    match read_value_flag(buf)? {
+        VALUE_FLAG_NULL => Ok(None),
+        _ => read_string(buf).map(Some),
+    }
+}
+
+// Write a fully qualified value {type_code}{value_flag}{value}
+pub(crate) fn write(value: &GValue, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    match value {
+        GValue::Null
+        | GValue::Int32(_)
+        | GValue::Int64(_)
+        | GValue::Short(_)
+        | GValue::Byte(_)
+        | GValue::Float(_)
+        | GValue::Double(_)
+        | GValue::String(_)
+        | GValue::Bool(_)
+        | GValue::Char(_)
+        | GValue::Bytes(_)
+        | GValue::Uuid(_)
+        | GValue::Duration(_)
+        | GValue::T(_)
+        | GValue::Token(_)
+        | GValue::Merge(_)
+        | GValue::Direction(_) => v1::write(value, buf)?,
+        #[cfg(feature = "big_numbers")]
+        GValue::BigInteger(_) | GValue::BigDecimal(_) => v1::write(value, buf)?,
+        GValue::Date(date) | GValue::Instant(date) => {
+            write_header(DATE_TIME, buf);
+            write_offset_date_time(&date.with_timezone(&FixedOffset::east_opt(0).unwrap()), buf);
+        }
+        GValue::OffsetDateTime(date) | GValue::ZonedDateTime(date) => {
+            write_header(DATE_TIME, buf);
+            write_offset_date_time(date, buf);
+        }
+        GValue::List(list) => {
+            write_header(LIST, buf);
+            write_list(list.iter(), list.len(), buf)?;
+        }
+        GValue::Set(set) => {
+            write_header(SET, buf);
+            write_list(set.iter(), set.iter().count(), buf)?;
+        }
+        GValue::Map(map) => {
+            write_header(MAP, buf);
+            write_len(map.len(), buf)?;
+            for (k, v) in map.iter() {
+                write(&k.clone().into(), buf)?;
+                write(v, buf)?;
+            }
+        }
+        GValue::Vertex(vertex) => {
+            write_header(VERTEX, buf);
+            write(&vertex.id().into(), buf)?;
+            write_label(vertex.label(), buf)?;
+            // properties
+            write(&GValue::Null, buf)?;
+        }
+        GValue::Edge(edge) => {
+            write_header(EDGE, buf);
+            write(&edge.id().into(), buf)?;
+            write_label(edge.label(), buf)?;
+            write(&edge.in_v().id().into(), buf)?;
+            write_label(edge.in_v().label(), buf)?;
+            write(&edge.out_v().id().into(), buf)?;
+            write_label(edge.out_v().label(), buf)?;
+            // parent
+            write(&GValue::Null, buf)?;
+            // properties
+            write(&GValue::Null, buf)?;
+        }
+        GValue::VertexProperty(property) => {
+            write_header(VERTEX_PROPERTY, buf);
+            write(&property.id().into(), buf)?;
+            write_label(property.label(), buf)?;
+            write(property.value(), buf)?;
+            // parent
+            write(&GValue::Null, buf)?;
+            // properties
+            write(&GValue::Null, buf)?;
+        }
+        GValue::Property(property) => {
+            write_header(PROPERTY, buf);
+            write_string(property.label(), buf)?;
+            write(property.value(), buf)?;
+            // parent
+            write(&GValue::Null, buf)?;
+        }
+        other => {
+            return Err(GremlinError::GraphBinary(format!(
+                "Type {:?} has no GraphBinary 4 representation",
+                other
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn write_list<'a, I>(elements: I, len: usize, buf: &mut Vec<u8>) -> GremlinResult<()>
+where
+    I: Iterator<Item = &'a GValue>,
+{
+    write_len(len, buf)?;
+    for e in elements {
+        write(e, buf)?;
+    }
+    Ok(())
+}
+
+// Element labels are written as a list of strings, without a type code
+fn write_label(label: &str, buf: &mut Vec<u8>) -> GremlinResult<()> {
+    write_len(1, buf)?;
+    write(&GValue::from(label), buf)
+}
+
+// Read a fully qualified value {type_code}{value_flag}{value}
+pub(crate) fn read(buf: &mut &[u8]) -> GremlinResult<GValue> {
+    let type_code = read_u8(buf)?;
+    match read_value_flag(buf)? {
+        VALUE_FLAG_NULL => Ok(GValue::Null),
+        VALUE_FLAG_BULK if type_code == LIST => {
+            let len = read_len(buf)?;
+            let mut items = Vec::with_capacity(capacity(len, buf));
+            for _ in 0..len {
+                let value = read(buf)?;
+                items.push((value, read_i64(buf)?));
+            }
+            Ok(BulkSet::new(items).into())
+        }
+        VALUE_FLAG_BULK => Err(GremlinError::GraphBinary(format!(
+            "Bulk flag set on type code 0x{:02x}",
+            type_code
+        ))),
+        _ => read_value(type_code, buf),
+    }
+}
+
+fn read_value(type_code: u8, buf: &mut &[u8]) -> GremlinResult<GValue> {
+    match type_code {
+        INT | LONG | STRING | DOUBLE | FLOAT | UUID | BIG_DECIMAL | BIG_INTEGER | BYTE | BINARY
+        | SHORT | BOOLEAN | CHAR | DURATION | DIRECTION | T | MERGE => {
+            v1::read_value(type_code, buf)
+        }
+        DATE_TIME => Ok(GValue::OffsetDateTime(read_offset_date_time(buf)?)),
+        // Sets are read as lists, the same way GraphSON V3 does
+        LIST | SET => Ok(read_list(buf)?.into()),
+        MAP => {
+            let len = read_len(buf)?;
+            let mut map = HashMap::with_capacity(capacity(len, buf));
+            for _ in 0..len {
+                let key = GKey::from_gvalue(read(buf)?)?;
+                let value = read(buf)?;
+                map.insert(key, value);
+            }
+            Ok(GValue::Map(map.into()))
+        }
+        VERTEX => {
+            let id = read_id(buf)?;
+            let label = read_label(buf)?;
+
+            let mut properties: HashMap<String, Vec<VertexProperty>> = HashMap::new();
+            if let GValue::List(list) = read(buf)? {
+                for property in list {
+                    let property = property.take::<VertexProperty>()?;
+                    properties
+                        .entry(property.label().clone())
+                        .or_default()
+                        .push(property);
+                }
+            }
+            Ok(Vertex::new(id, label, properties).into())
+        }
+        EDGE => {
+            let id = read_id(buf)?;
+            let label = read_label(buf)?;
+            let in_v_id = read_id(buf)?;
+            let in_v_label = read_label(buf)?;
+            let out_v_id = read_id(buf)?;
+            let out_v_label = read_label(buf)?;
+            // parent
+            read(buf)?;
+
+            let mut properties = HashMap::new();
+            if let GValue::List(list) = read(buf)? {
+                for property in list {
+                    let property = property.take::<Property>()?;
+                    properties.insert(property.label().clone(), property);
+                }
+            }
+            Ok(Edge::new(
+                id,
+                label,
+                in_v_id,
+                in_v_label,
+                out_v_id,
+                out_v_label,
+                properties,
+            )
+            .into())
+        }
+        VERTEX_PROPERTY => {
+            let id = read_id(buf)?;
+            let label = read_label(buf)?;
+            let value = read(buf)?;
+            // parent
+            read(buf)?;
+            // properties
+            read(buf)?;
+            Ok(VertexProperty::new(id, label, value).into())
+        }
+        PROPERTY => {
+            let key = read_string(buf)?;
+            let value = read(buf)?;
+            // parent
+            read(buf)?;
+            Ok(Property::new(key, value).into())
+        }
+        PATH => {
+            let labels = read(buf)?;
+            let objects = read(buf)?.take::<List>()?;
+            Ok(Path::new(labels, objects).into())
+        }
+        TREE => Ok(GValue::Tree(read_tree(buf)?)),
+        UNSPECIFIED_NULL => Ok(GValue::Null),
+        other => Err(GremlinError::GraphBinary(format!(
+            "Type code 0x{:02x} not supported",
+            other
+        ))),
+    }
+}
+
+fn read_tree(buf: &mut &[u8]) -> GremlinResult<Tree> {
+    let len = read_len(buf)?;
+    let mut branches = Vec::with_capacity(capacity(len, buf));
+    for _ in 0..len {
+        let key = read(buf)?;
+        branches.push((key, read_tree(buf)?));
+    }
+    Ok(Tree::new(branches))
+}
+
+fn read_id(buf: &mut &[u8]) -> GremlinResult<GID> {
+    match read(buf)? {
+        GValue::String(s) => Ok(GID::String(s)),
+        GValue::Int32(i) => Ok(GID::Int32(i)),
+        GValue::Int64(i) => Ok(GID::Int64(i)),
+        GValue::Uuid(uuid) => Ok(uuid.into()),
+        other => Err(GremlinError::GraphBinary(format!(
+            "{:?} cannot be an id",
+            other
+        ))),
+    }
+}
+
+// Elements may carry several labels, only the first one is kept
+fn read_label(buf: &mut &[u8]) -> GremlinResult<String> {
+    read_list(buf)?
+        .into_iter()
+        .next()
+        .map(|label| label.take::<String>())
+        .unwrap_or_else(|| Ok(String::default()))
+}
+
+fn read_list(buf: &mut &[u8]) -> GremlinResult<Vec<GValue>> {
+    let len = read_len(buf)?;
+    let mut elements = Vec::with_capacity(capacity(len, buf));
+    for _ in 0..len {
+        elements.push(read(buf)?);
+    }
+    Ok(elements)
+}
+
+fn read_value_flag(buf: &mut &[u8]) -> GremlinResult<u8> {
+    match read_u8(buf)? {
+        flag @ VALUE_FLAG_NONE | flag @ VALUE_FLAG_NULL | flag @ VALUE_FLAG_BULK => Ok(flag),
+        flag => Err(GremlinError::GraphBinary(format!(
+            "Unexpected value flag 0x{:02x}",
+            flag
+        ))),
+    }
+}
+
+// TESTS
+#[cfg(test)]
+mod tests {
+
+    use super::{read, read_len, read_response, write, write_request, ResponseReader};
+    use crate::process::traversal::traversal;
+    use crate::structure::{BulkSet, GValue, Traverser};
+    use crate::{edge, vertex, GremlinError};
+    use chrono::offset::TimeZone;
+    use std::collections::HashMap;
+
+    fn round_trip(value: GValue) -> GValue {
+        let mut buf = vec![];
+        write(&value, &mut buf).expect("Failed to serialize");
+        let mut slice = &buf[..];
+        let result = read(&mut slice).expect("Failed to deserialize");
+        assert!(slice.is_empty(), "Trailing bytes left after read");
+        result
+    }
+
+    fn response_body(bulked: bool, values: &[(GValue, i64)], code: i32) -> Vec<u8> {
+        let mut buf = vec![0x81, bulked as u8];
+        for (value, bulk) in values {
+            write(value, &mut buf).unwrap();
+            if bulked {
+                buf.extend_from_slice(&bulk.to_be_bytes());
+            }
+        }
+        buf.extend_from_slice(&[0xfd, 0x00, 0x00]);
+        buf.extend_from_slice(&code.to_be_bytes());
+        // message
+        buf.push(0x00);
+        super::write_string("done", &mut buf).unwrap();
+        // exception
+        buf.push(0x01);
+        buf
+    }
+
+    #[test]
+    fn test_huge_lengths() {
+        let len = [0x7f, 0xff, 0xff, 0xff];
+        let frames = [
+            [&[0x09, 0x00][..], &len].concat(),
+            [&[0x09, 0x02][..], &len].concat(),
+            [&[0x0a, 0x00][..], &len].concat(),
+            [&[0x2b, 0x00][..], &len].concat(),
+        ];
+
+        for frame in &frames {
+            assert!(read(&mut &frame[..]).is_err(), "{:?}", frame);
+        }
+    }
+
+    #[test]
+    fn test_elements() {
+        let vertex = GValue::from(vertex!({ id => 1_i64, label => "person", properties => {} }));
+        assert_eq!(round_trip(vertex.clone()), vertex);
+
+        let edge = GValue::from(edge!({
+            id => 13_i64,
+            label => "develops",
+            inV => { id => 10_i64, label => "software" },
+            outV => { id => 1_i64, label => "person" },
+            properties => {}
+        }));
+        assert_eq!(round_trip(edge.clone()), edge);
+
+        let date = GValue::OffsetDateTime(
+            chrono::FixedOffset::east_opt(3600)
+                .unwrap()
+                .with_ymd_and_hms(2024, 2, 29, 12, 30, 0)
+                .unwrap(),
+        );
+        assert_eq!(round_trip(date.clone()), date);
+
+        let list = GValue::List(vec![GValue::from(1), GValue::from("a"), GValue::Null].into());
+        assert_eq!(round_trip(list.clone()), list);
+
+        let mut map = HashMap::new();
+        map.insert(String::from("name"), GValue::from("marko"));
+        let map = GValue::from(map);
+        assert_eq!(round_trip(map.clone()), map);
+    }
+
+    #[test]
+    fn test_bulked_list() {
+        let buf = [
+            0x09, 0x02, 0x00, 0x00, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, b'a', 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+        ];
+        assert_eq!(
+            read(&mut &buf[..]).unwrap(),
+            GValue::BulkSet(BulkSet::new(vec![(GValue::from("a"), 3)]))
+        );
+    }
+
+    #[test]
+    fn test_request() {
+        let g = traversal().empty();
+        let mut args = HashMap::new();
+        args.insert(
+            String::from("gremlin"),
+            GValue::Bytecode(g.v(()).has_label("person").bytecode().clone()),
+        );
+
+        let request = write_request("bytecode", &args).unwrap();
+        let script = b"g.V().hasLabel('person')";
+        assert_eq!(0x81, request[0]);
+        assert!(request.ends_with(script));
+        assert_eq!(
+            &(script.len() as i32).to_be_bytes()[..],
+            &request[request.len() - script.len() - 4..request.len() - script.len()]
+        );
+
+        args.insert(String::from("evaluationTimeout"), GValue::Int64(500));
+        let request = write_request("bytecode", &args).unwrap();
+        let buf = &mut &request[1..];
+        let mut fields = HashMap::new();
+        for _ in 0..read_len(buf).unwrap() {
+            let key = read(buf).unwrap().take::<String>().unwrap();
+            fields.insert(key, read(buf).unwrap());
+        }
+        assert_eq!(Some(&GValue::Int64(500)), fields.get("timeoutMs"));
+        assert_eq!(Some(&GValue::Bool(true)), fields.get("bulkResults"));
+        assert!(!fields.contains_key("evaluationTimeout"));
+
+        args.insert(String::from("session"), GValue::from("session"));
+        assert!(write_request("bytecode", &args).is_err());
+    }
+
+    #[test]
+    fn test_streamed_response() {
+        let marko = GValue::from(vertex!({ id => 1_i64, label => "person", properties => {} }));
+        let body = response_body(true, &[(marko.clone(), 1), (GValue::from("josh"), 2)], 200);
+
+        let mut reader = ResponseReader::default();
+        let mut buffer = vec![];
+        let mut responses = vec![];
+        for byte in body {
+            buffer.push(byte);
+            if let Some(response) = reader.read(&mut buffer, false).unwrap() {
+                responses.push(response);
+            }
+        }
+        assert!(reader.is_finished());
+        assert!(buffer.is_empty());
+
+        let codes: Vec<i16> = responses.iter().map(|r| r.status.code).collect();
+        assert_eq!(vec![206, 206, 200], codes);
+        assert_eq!(
+            Some(GValue::List(vec![Traverser::new(1, marko).into()].into())),
+            responses[0].result.data
+        );
+        assert_eq!("done", responses[2].status.message);
+        assert!(responses[2].result.data.is_none());
+
+        let response =
+            read_response(&response_body(false, &[(GValue::from(1_i64), 1)], 500)).unwrap();
+        assert_eq!(500, response.status.code);
+        assert_eq!(
+            Some(GValue::List(vec![GValue::Int64(1)].into())),
+            response.result.data
+        );
+
+        let empty = read_response(&response_body(false, &[], 200)).unwrap();
+        assert!(empty.result.data.is_none());
+    }
+
+    #[test]
+    fn test_malformed_response() {
+        // An unknown type code fails right away instead of waiting for more bytes
+        let mut reader = ResponseReader::default();
+        let mut buffer = vec![0x81, 0x00, 0x7f, 0x00];
+        assert!(reader.read(&mut buffer, false).is_err());
+
+        // A value cut by the end of the body reports the missing bytes
+        let body = response_body(false, &[(GValue::from("marko"), 1)], 200);
+        let mut reader = ResponseReader::default();
+        let mut buffer = body[..6].to_vec();
+        assert!(reader.read(&mut buffer, false).unwrap().is_none());
+        match reader.read(&mut buffer, true) {
+            Err(GremlinError::EndOfBuffer { .. }) => {}
+            other => panic!("Unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,1067 @@
+//! Streaming reader of GraphSON response frames.
+//!
+//! Frames are deserialized straight into [GValue](crate::GValue)s instead of going through a
+//! `serde_json::Value` first. Collections, traversers, vertices, edges, properties, paths and trees
+//! are built while they are read. Scalar typed values, element ids and the less common types such as
+//! metrics still have their `@value` buffered into a `serde_json::Value` and handed to the
+//! deserializers in `serializer_v2` and `serializer_v3`, which only ever holds one small value.
+//!
+//! GraphSON V1 carries no types: its arrays are streamed, but each object is buffered whole since
+//! whether it is a vertex, an edge or a map is only known from its `type` field.
+
+use super::{serializer_v1, serializer_v2, serializer_v3, GraphSON};
+use crate::conversion::FromGValue;
+use crate::message::{ReponseStatus, Response, ResponseResult};
+use crate::structure::{
+    BulkSet, Edge, GKey, GValue, List, Map, Path, Property, Traverser, Tree, Vertex,
+    VertexProperty, GID,
+};
+use crate::{GremlinError, GremlinResult};
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::Value;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+
+// With serde_json's arbitrary_precision feature, enabled by big_numbers or by any other crate in
+// the build, numbers are handed to visitors as a map with this single key and the literal as value.
+const ARBITRARY_PRECISION_NUMBER: &str = "$serde_json::private::Number";
+
+/// Reads a GraphSON response frame.
+pub(crate) fn read_response(graphson: &GraphSON, response: &[u8]) -> GremlinResult<Response> {
+    let error = Cell::new(None);
+    let reader = Reader {
+        graphson,
+        error: &error,
+    };
+    let mut deserializer = serde_json::Deserializer::from_slice(response);
+    let response = ResponseSeed(reader)
+        .deserialize(&mut deserializer)
+        .and_then(|response| deserializer.end().map(|_| response));
+
+    // Errors raised by the GValue deserializers are kept aside, serde_json only gets their message
+    response.map_err(|e| error.take().unwrap_or_else(|| e.into()))
+}
+
+#[derive(Clone, Copy)]
+struct Reader<'a> {
+    graphson: &'a GraphSON,
+    error: &'a Cell<Option<GremlinError>>,
+}
+
+impl<'a> Reader<'a> {
+    fn fail<E: de::Error>(&self, error: GremlinError) -> E {
+        let e = E::custom(&error);
+        self.error.set(Some(error));
+        e
+    }
+
+    fn check<T, E: de::Error>(&self, result: GremlinResult<T>) -> Result<T, E> {
+        result.map_err(|e| self.fail(e))
+    }
+
+    // Falls back to the deserializers working on a serde_json::Value
+    fn read_value<E: de::Error>(&self, value: &Value) -> Result<GValue, E> {
+        self.check(self.deserialize(value))
+    }
+
+    fn deserialize(&self, value: &Value) -> GremlinResult<GValue> {
+        match self.graphson {
+            GraphSON::V1 => serializer_v1::deserializer_v1(value),
+            GraphSON::V2 => serializer_v2::deserializer_v2(value),
+            GraphSON::V3 => serializer_v3::deserializer_v3(value),
+        }
+    }
+
+    fn read_lenient(&self, value: &Value) -> GremlinResult<GValue> {
+        self.deserialize(value)
+            .or_else(|_| serializer_v1::deserializer_v1(value))
+    }
+
+    fn read_id<E: de::Error>(&self, value: &Value) -> Result<GID, E> {
+        let result = match self.graphson {
+            GraphSON::V3 => serializer_v3::deserialize_id(&serializer_v3::deserializer_v3, value),
+            _ => serializer_v2::deserialize_id(&serializer_v2::deserializer_v2, value),
+        };
+        self.check(result)
+    }
+
+    fn read_typed<E: de::Error>(&self, value_type: String, value: Value) -> Result<GValue, E> {
+        let mut typed = serde_json::Map::with_capacity(2);
+        typed.insert(String::from("@type"), Value::String(value_type));
+        typed.insert(String::from("@value"), value);
+        self.read_value(&Value::Object(typed))
+    }
+
+    fn shaped(self, shape: Shape) -> Seed<'a> {
+        Seed {
+            reader: self,
+            shape,
+        }
+    }
+}
+
+struct ResponseSeed<'a>(Reader<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for ResponseSeed<'a> {
+    type Value = Response;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Response, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for ResponseSeed<'a> {
+    type Value = Response;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a GraphSON response")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Response, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut request_id = None;
+        let mut result = None;
+        let mut status = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "requestId" => request_id = Some(map.next_value::<Uuid>()?),
+                "status" => status = Some(map.next_value_seed(StatusSeed(self.0))?),
+                "result" => result = Some(map.next_value_seed(ResultSeed(self.0))?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Response {
+            request_id: request_id.ok_or_else(|| de::Error::missing_field("requestId"))?,
+            result: result.ok_or_else(|| de::Error::missing_field("result"))?,
+            status: status.ok_or_else(|| de::Error::missing_field("status"))?,
+        })
+    }
+}
+
+struct ResultSeed<'a>(Reader<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for ResultSeed<'a> {
+    type Value = ResponseResult;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<ResponseResult, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for ResultSeed<'a> {
+    type Value = ResponseResult;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a GraphSON response result")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<ResponseResult, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut data = None;
+        let mut meta = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "data" => data = map.next_value_seed(DataSeed(self.0))?,
+                "meta" => meta = Some(map.next_value_seed(MapSeed(self.0))?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(ResponseResult {
+            data,
+            meta: meta.unwrap_or_default(),
+        })
+    }
+}
+
+struct StatusSeed<'a>(Reader<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for StatusSeed<'a> {
+    type Value = ReponseStatus;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<ReponseStatus, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for StatusSeed<'a> {
+    type Value = ReponseStatus;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a GraphSON response status")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<ReponseStatus, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut code = None;
+        let mut message = None;
+        let mut attributes = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "code" => code = Some(map.next_value::<i16>()?),
+                "message" => message = map.next_value::<Option<String>>()?,
+                "attributes" => attributes = Some(map.next_value_seed(MapSeed(self.0))?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(ReponseStatus {
+            code: code.ok_or_else(|| de::Error::missing_field("code"))?,
+            //Sometimes the message is omitted, default to empty string rather than panic
+            message: message.unwrap_or_default(),
+            attributes: attributes.unwrap_or_default(),
+        })
+    }
+}
+
+/// The status attributes or the result meta of a frame.
+///
+/// Some servers write them as plain JSON objects whatever the GraphSON version, so untyped
+/// objects are read entry by entry. Values of types unknown to this crate are kept as untyped
+/// JSON rather than failing the response, which needs each of them buffered on its own.
+struct MapSeed<'a>(Reader<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for MapSeed<'a> {
+    type Value = Map;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Map, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for MapSeed<'a> {
+    type Value = Map;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map or null")
+    }
+
+    fn visit_unit<E>(self) -> Result<Map, E> {
+        Ok(Map::default())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Map, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.0;
+        let mut entries = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(key) = map.next_key::<String>()? {
+            if (key == "@type" || key == "@value") && entries.is_empty() {
+                let mut typed = serde_json::Map::new();
+                typed.insert(key, map.next_value::<Value>()?);
+                while let Some((key, value)) = map.next_entry::<String, Value>()? {
+                    typed.insert(key, value);
+                }
+                let value = reader.check(reader.read_lenient(&Value::Object(typed)))?;
+                return Ok(value.take::<Map>().unwrap_or_default());
+            }
+            let value = map.next_value::<Value>()?;
+            entries.insert(
+                GKey::String(key),
+                reader.check(reader.read_lenient(&value))?,
+            );
+        }
+        Ok(entries.into())
+    }
+}
+
+struct DataSeed<'a>(Reader<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for DataSeed<'a> {
+    type Value = Option<GValue>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Option<GValue>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for DataSeed<'a> {
+    type Value = Option<GValue>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("GraphSON data")
+    }
+
+    fn visit_none<E>(self) -> Result<Option<GValue>, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Option<GValue>, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Option<GValue>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0
+            .shaped(Shape::Any)
+            .deserialize(deserializer)
+            .map(Some)
+    }
+}
+
+/// What the next JSON value is expected to be.
+#[derive(Clone, Copy)]
+enum Shape {
+    /// A value of any type
+    Any,
+    /// The @value of a g:List or g:Set
+    List,
+    /// The @value of a g:Map in GraphSON V3, keys and values alternate in an array
+    Map,
+    /// The @value of a g:BulkSet, values and bulks alternate in an array
+    BulkSet,
+    /// The @value of a g:Traverser
+    Traverser,
+    /// The @value of a g:Vertex
+    Vertex,
+    /// The @value of a g:Edge
+    Edge,
+    /// The @value of a g:VertexProperty
+    VertexProperty,
+    /// The @value of a g:Property
+    Property,
+    /// The @value of a g:Path
+    Path,
+    /// The @value of a g:Tree, an array of key and value branches
+    Tree,
+}
+
+struct Seed<'a> {
+    reader: Reader<'a>,
+    shape: Shape,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for Seed<'a> {
+    type Value = GValue;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<GValue, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self.shape {
+            Shape::Any => deserializer.deserialize_any(self),
+            Shape::List | Shape::Map | Shape::BulkSet | Shape::Tree => {
+                deserializer.deserialize_seq(self)
+            }
+            Shape::Traverser
+            | Shape::Vertex
+            | Shape::Edge
+            | Shape::VertexProperty
+            | Shape::Property
+            | Shape::Path => deserializer.deserialize_map(self),
+        }
+    }
+}
+
+impl<'a> Seed<'a> {
+    fn visit_typed<'de, A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let value_type = map.next_value::<String>()?;
+        let value = match map.next_key::<String>()? {
+            Some(key) if key == "@value" => {
+                let shape = match (reader.graphson, value_type.as_str()) {
+                    (_, "g:List") => Some(Shape::List),
+                    (GraphSON::V3, "g:Set") => Some(Shape::List),
+                    (GraphSON::V3, "g:Map") => Some(Shape::Map),
+                    (GraphSON::V3, "g:BulkSet") => Some(Shape::BulkSet),
+                    (_, "g:Traverser") => Some(Shape::Traverser),
+                    (_, "g:Vertex") => Some(Shape::Vertex),
+                    (_, "g:Edge") => Some(Shape::Edge),
+                    (_, "g:VertexProperty") => Some(Shape::VertexProperty),
+                    (_, "g:Property") => Some(Shape::Property),
+                    (_, "g:Path") => Some(Shape::Path),
+                    (_, "g:Tree") => Some(Shape::Tree),
+                    _ => None,
+                };
+                match shape {
+                    Some(shape) => map.next_value_seed(reader.shaped(shape))?,
+                    None => reader.read_typed(value_type, map.next_value::<Value>()?)?,
+                }
+            }
+            Some(key) => {
+                let mut object = serde_json::Map::new();
+                object.insert(String::from("@type"), Value::String(value_type));
+                object.insert(key, map.next_value::<Value>()?);
+                while let Some((key, value)) = map.next_entry::<String, Value>()? {
+                    object.insert(key, value);
+                }
+                return reader.read_value(&Value::Object(object));
+            }
+            None => reader.read_typed(value_type, Value::Null)?,
+        };
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+        Ok(value)
+    }
+
+    // GraphSON V2 maps are plain JSON objects
+    fn visit_untyped_map<'de, A>(self, first_key: String, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let mut entries = HashMap::new();
+        let value = map.next_value_seed(reader.shaped(Shape::Any))?;
+        entries.insert(GKey::String(first_key), value);
+        while let Some(key) = map.next_key::<String>()? {
+            let value = map.next_value_seed(reader.shaped(Shape::Any))?;
+            entries.insert(GKey::String(key), value);
+        }
+        Ok(entries.into())
+    }
+
+    fn visit_traverser<'de, A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let mut bulk = None;
+        let mut value = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "bulk" => {
+                    let b = map.next_value_seed(reader.shaped(Shape::Any))?;
+                    bulk = Some(reader.check(b.take::<i64>())?);
+                }
+                "value" => value = Some(map.next_value_seed(reader.shaped(Shape::Any))?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let bulk = bulk.ok_or_else(|| de::Error::missing_field("bulk"))?;
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        Ok(Traverser::new(bulk, value).into())
+    }
+
+    fn visit_vertex<'de, A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let mut id = None;
+        let mut label = None;
+        let mut properties = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "id" => id = Some(map.next_value::<Value>()?),
+                "label" => label = Some(map.next_value::<String>()?),
+                "properties" => properties = Some(map.next_value_seed(PropertiesSeed(reader))?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Vertex::new(
+            reader.read_id(&id.unwrap_or(Value::Null))?,
+            label.unwrap_or_else(|| String::from("vertex")),
+            properties.unwrap_or_default(),
+        )
+        .into())
+    }
+
+    // Edge properties are not read, the same as in serializer_v2 and serializer_v3
+    fn visit_edge<'de, A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let mut id = None;
+        let mut label = None;
+        let mut in_v = None;
+        let mut in_v_label = None;
+        let mut out_v = None;
+        let mut out_v_label = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "id" => id = Some(map.next_value::<Value>()?),
+                "label" => label = Some(map.next_value::<String>()?),
+                "inV" => in_v = Some(map.next_value::<Value>()?),
+                "inVLabel" => in_v_label = Some(map.next_value::<String>()?),
+                "outV" => out_v = Some(map.next_value::<Value>()?),
+                "outVLabel" => out_v_label = Some(map.next_value::<String>()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Edge::new(
+            reader.read_id(&id.unwrap_or(Value::Null))?,
+            label.unwrap_or_else(|| String::from("edge")),
+            reader.read_id(&in_v.unwrap_or(Value::Null))?,
+            in_v_label.ok_or_else(|| de::Error::missing_field("inVLabel"))?,
+            reader.read_id(&out_v.unwrap_or(Value::Null))?,
+            out_v_label.ok_or_else(|| de::Error::missing_field("outVLabel"))?,
+            HashMap::new(),
+        )
+        .into())
+    }
+
+    fn visit_vertex_property<'de, A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let mut id = None;
+        let mut label = None;
+        let mut value = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "id" => id = Some(map.next_value::<Value>()?),
+                "label" => label = Some(map.next_value::<String>()?),
+                "value" => value = Some(map.next_value_seed(reader.shaped(Shape::Any))?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(VertexProperty::new(
+            reader.read_id(&id.unwrap_or(Value::Null))?,
+            label.unwrap_or_else(|| String::from("vertex_property")),
+            value.ok_or_else(|| de::Error::missing_field("value"))?,
+        )
+        .into())
+    }
+
+    fn visit_property<'de, A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let mut key = None;
+        let mut value = None;
+        while let Some(field) = map.next_key::<String>()? {
+            match field.as_str() {
+                "key" => key = Some(map.next_value::<String>()?),
+                "value" => value = Some(map.next_value_seed(reader.shaped(Shape::Any))?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Property::new(
+            key.unwrap_or_else(|| String::from("property")),
+            value.ok_or_else(|| de::Error::missing_field("value"))?,
+        )
+        .into())
+    }
+
+    fn visit_path<'de, A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        let mut labels = None;
+        let mut objects = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "labels" => labels = Some(map.next_value_seed(reader.shaped(Shape::Any))?),
+                "objects" => {
+                    let o = map.next_value_seed(reader.shaped(Shape::Any))?;
+                    objects = Some(reader.check(o.take::<List>())?);
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let labels = labels.ok_or_else(|| de::Error::missing_field("labels"))?;
+        let objects = objects.ok_or_else(|| de::Error::missing_field("objects"))?;
+        Ok(Path::new(labels, objects).into())
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for Seed<'a> {
+    type Value = GValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a GraphSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<GValue, E> {
+        Ok(GValue::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<GValue, E> {
+        self.reader.read_value(&Value::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<GValue, E> {
+        self.reader.read_value(&Value::from(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<GValue, E> {
+        self.reader.read_value(&Value::from(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<GValue, E> {
+        Ok(GValue::String(v.to_owned()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<GValue, E> {
+        Ok(GValue::String(v))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<GValue, E> {
+        self.reader.read_value(&Value::Null)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<GValue, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let reader = self.reader;
+        let any = || reader.shaped(Shape::Any);
+        match self.shape {
+            Shape::Map => {
+                let mut entries = HashMap::with_capacity(seq.size_hint().unwrap_or(0) / 2);
+                while let Some(key) = seq.next_element_seed(any())? {
+                    let key = reader.check(GKey::from_gvalue(key))?;
+                    let value = seq.next_element_seed(any())?.ok_or_else(|| {
+                        reader.fail(GremlinError::Json(String::from(
+                            "Map must contain key and value pairs",
+                        )))
+                    })?;
+                    entries.insert(key, value);
+                }
+                Ok(entries.into())
+            }
+            Shape::BulkSet => {
+                let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0) / 2);
+                while let Some(value) = seq.next_element_seed(any())? {
+                    let bulk = seq.next_element_seed(any())?.ok_or_else(|| {
+                        reader.fail(GremlinError::Json(String::from(
+                            "BulkSet must contain value and bulk pairs",
+                        )))
+                    })?;
+                    items.push((value, reader.check(bulk.take::<i64>())?));
+                }
+                Ok(BulkSet::new(items).into())
+            }
+            Shape::Tree => {
+                let mut branches = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(branch) = seq.next_element_seed(BranchSeed(reader))? {
+                    branches.push(branch);
+                }
+                Ok(Tree::new(branches).into())
+            }
+            // Untyped arrays are not lists in GraphSON V3, skip them to report the same error
+            Shape::Any if matches!(reader.graphson, GraphSON::V3) => {
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+                reader.read_value(&Value::Array(Vec::new()))
+            }
+            _ => {
+                let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(element) = seq.next_element_seed(any())? {
+                    elements.push(element);
+                }
+                Ok(elements.into())
+            }
+        }
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<GValue, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.reader;
+        match self.shape {
+            Shape::Traverser => return self.visit_traverser(map),
+            Shape::Vertex => return self.visit_vertex(map),
+            Shape::Edge => return self.visit_edge(map),
+            Shape::VertexProperty => return self.visit_vertex_property(map),
+            Shape::Property => return self.visit_property(map),
+            Shape::Path => return self.visit_path(map),
+            _ => {}
+        }
+        match map.next_key::<String>()? {
+            Some(key) if key == "@type" && !matches!(reader.graphson, GraphSON::V1) => {
+                self.visit_typed(map)
+            }
+            Some(key) if key == ARBITRARY_PRECISION_NUMBER => {
+                let literal = map.next_value::<String>()?;
+                let number = literal
+                    .parse::<serde_json::Number>()
+                    .map_err(|e| reader.fail(e.into()))?;
+                reader.read_value(&Value::Number(number))
+            }
+            Some(key) if matches!(reader.graphson, GraphSON::V2) && key != "@value" => {
+                self.visit_untyped_map(key, map)
+            }
+            Some(key) => {
+                let mut object = serde_json::Map::new();
+                object.insert(key, map.next_value::<Value>()?);
+                while let Some((key, value)) = map.next_entry::<String, Value>()? {
+                    object.insert(key, value);
+                }
+                reader.read_value(&Value::Object(object))
+            }
+            None => reader.read_value(&Value::Object(serde_json::Map::new())),
+        }
+    }
+}
+
+/// The properties of a vertex, keyed by name.
+struct PropertiesSeed<'a>(Reader<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for PropertiesSeed<'a> {
+    type Value = HashMap<String, Vec<VertexProperty>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for PropertiesSeed<'a> {
+    type Value = HashMap<String, Vec<VertexProperty>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("object or null for properties")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(HashMap::new())
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(HashMap::new())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.0;
+        let mut properties = HashMap::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(key) = map.next_key::<String>()? {
+            let values = map.next_value_seed(reader.shaped(Shape::List))?;
+            let values = reader.check(values.take::<List>())?;
+            let values = values
+                .into_iter()
+                .map(|v| reader.check(v.take::<VertexProperty>()))
+                .collect::<Result<Vec<_>, _>>()?;
+            properties.insert(key, values);
+        }
+        Ok(properties)
+    }
+}
+
+/// A branch of a g:Tree, its key and the subtree below it.
+struct BranchSeed<'a>(Reader<'a>);
+
+impl<'de, 'a> DeserializeSeed<'de> for BranchSeed<'a> {
+    type Value = (GValue, Tree);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for BranchSeed<'a> {
+    type Value = (GValue, Tree);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a g:Tree branch")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let reader = self.0;
+        let mut key = None;
+        let mut tree = None;
+        while let Some(field) = map.next_key::<String>()? {
+            match field.as_str() {
+                "key" => key = Some(map.next_value_seed(reader.shaped(Shape::Any))?),
+                "value" => {
+                    let t = map.next_value_seed(reader.shaped(Shape::Any))?;
+                    tree = Some(reader.check(t.take::<Tree>())?);
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let key = key.ok_or_else(|| de::Error::missing_field("key"))?;
+        let tree = tree.ok_or_else(|| de::Error::missing_field("value"))?;
+        Ok((key, tree))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::read_response;
+    use crate::io::GraphSON;
+    use crate::structure::{GValue, List};
+    use crate::GremlinError;
+    use serde_json::{json, Value};
+
+    fn frame(data: Value) -> Vec<u8> {
+        serde_json::to_vec(&json!({
+            "requestId": "41d2e28a-20a4-4ab0-b379-d810dede3786",
+            "status": {"message": "", "code": 200, "attributes": {"@type": "g:Map", "@value": []}},
+            "result": {"data": data, "meta": {"@type": "g:Map", "@value": []}}
+        }))
+        .unwrap()
+    }
+
+    // The streaming reader must agree with the deserializers working on a serde_json::Value
+    fn assert_same(graphson: GraphSON, data: Value) -> Option<GValue> {
+        let response =
+            read_response(&graphson, &frame(data.clone())).expect("Failed to read the response");
+        assert_eq!(response.result.data, graphson.read(&data).unwrap());
+        assert_eq!(response.status.code, 200);
+        response.result.data
+    }
+
+    #[test]
+    fn test_v3_frames() {
+        let vertex = json!({"@type": "g:Vertex", "@value": {
+            "id": {"@type": "g:Int32", "@value": 1},
+            "label": "person",
+            "properties": {"name": [{"@type": "g:VertexProperty", "@value": {
+                "id": {"@type": "g:Int64", "@value": 0}, "value": "marko", "label": "name"}}]}
+        }});
+        let data = json!({"@type": "g:List", "@value": [
+            {"@type": "g:Traverser", "@value": {"bulk": {"@type": "g:Int64", "@value": 2}, "value": vertex}},
+            {"@type": "g:Map", "@value": [
+                {"@type": "g:T", "@value": "id"}, {"@type": "g:Int32", "@value": 1},
+                "names", {"@type": "g:Set", "@value": ["marko", "vadas"]},
+                "bulk", {"@type": "g:BulkSet", "@value": ["a", {"@type": "g:Int64", "@value": 3}]}
+            ]},
+            {"@type": "g:UUID", "@value": "41d2e28a-20a4-4ab0-b379-d810dede3786"},
+            true,
+            "string"
+        ]});
+        let result = assert_same(GraphSON::V3, data)
+            .unwrap()
+            .take::<List>()
+            .unwrap();
+        assert_eq!(result.len(), 5);
+        match &result[0] {
+            GValue::Traverser(traverser) => assert_eq!(traverser.bulk(), 2),
+            other => panic!("Unexpected value {:?}", other),
+        }
+
+        assert_same(GraphSON::V3, json!({"@type": "g:List", "@value": []}));
+
+        // json! sorts the keys, @type comes first
+        let response = br#"{"requestId": "41d2e28a-20a4-4ab0-b379-d810dede3786",
+            "status": {"message": "", "code": 200},
+            "result": {"data": {"@value": [{"@value": 1, "@type": "g:Int32"}], "@type": "g:List"}}}"#;
+        let response = read_response(&GraphSON::V3, response).unwrap();
+        assert_eq!(response.result.data, Some(vec![GValue::Int32(1)].into()));
+        assert_eq!(assert_same(GraphSON::V3, Value::Null), None);
+    }
+
+    #[test]
+    fn test_v3_elements() {
+        let int32 = |v: i32| json!({"@type": "g:Int32", "@value": v});
+        let vertex = |id: i32, label: &str| json!({"@type": "g:Vertex", "@value": {"id": int32(id), "label": label}});
+        let edge = json!({"@type": "g:Edge", "@value": {
+            "id": int32(13), "label": "develops", "inVLabel": "software", "outVLabel": "person",
+            "inV": int32(10), "outV": int32(1),
+            "properties": {"since": {"@type": "g:Property", "@value": {"key": "since", "value": int32(2009)}}}
+        }});
+        let path = json!({"@type": "g:Path", "@value": {
+            "labels": {"@type": "g:List", "@value": [{"@type": "g:Set", "@value": ["a"]}, {"@type": "g:Set", "@value": []}]},
+            "objects": {"@type": "g:List", "@value": [vertex(1, "person"), edge.clone()]}
+        }});
+        let leaf = json!({"@type": "g:Tree", "@value": []});
+        let tree = json!({"@type": "g:Tree", "@value": [
+            {"key": vertex(1, "person"), "value": {"@type": "g:Tree", "@value": [
+                {"key": vertex(2, "person"), "value": leaf},
+                {"key": "name", "value": leaf}
+            ]}}
+        ]});
+        let data = json!({"@type": "g:List", "@value": [
+            vertex(1, "person"),
+            {"@type": "g:Vertex", "@value": {"id": {"@type": "g:Unknown", "@value": "1"}, "properties": null}},
+            edge,
+            path,
+            tree,
+            {"@type": "g:Property", "@value": {"key": "since", "value": int32(2009)}},
+            {"@type": "g:VertexProperty", "@value": {"id": {"@type": "g:Int64", "@value": 0}, "value": "marko", "label": "name"}}
+        ]});
+        let result = assert_same(GraphSON::V3, data)
+            .unwrap()
+            .take::<List>()
+            .unwrap();
+        assert_eq!(result.len(), 7);
+        match &result[4] {
+            GValue::Tree(tree) => assert_eq!(tree.len(), 1),
+            other => panic!("Unexpected value {:?}", other),
+        }
+
+        // Untyped arrays are not lists in GraphSON V3
+        let data = json!({"@type": "g:List", "@value": [[1, 2]]});
+        assert!(read_response(&GraphSON::V3, &frame(data)).is_err());
+    }
+
+    #[test]
+    fn test_v2_frames() {
+        let data = json!([
+            {"name": ["marko"], "age": [{"@type": "g:Int32", "@value": 29}]},
+            {"@type": "g:Traverser", "@value": {"bulk": {"@type": "g:Int64", "@value": 1}, "value": "a"}},
+            {"@type": "g:Double", "@value": 1.5},
+            {"@type": "g:Path", "@value": {"labels": [["a"], []], "objects": [
+                {"@type": "g:Vertex", "@value": {"id": {"@type": "g:Int32", "@value": 1}, "label": "person",
+                    "properties": {"name": [{"@type": "g:VertexProperty", "@value": {
+                        "id": {"@type": "g:Int64", "@value": 0}, "value": "marko", "label": "name"}}]}}},
+                {"@type": "g:Edge", "@value": {"id": {"@type": "g:Int32", "@value": 13}, "label": "develops",
+                    "inVLabel": "software", "outVLabel": "person",
+                    "inV": {"@type": "g:Int32", "@value": 10}, "outV": {"@type": "g:Int32", "@value": 1}}}
+            ]}},
+            {}
+        ]);
+        assert_same(GraphSON::V2, data);
+    }
+
+    #[test]
+    fn test_v1_frames() {
+        assert_same(
+            GraphSON::V1,
+            json!([
+                {"name": ["marko"], "age": [29]},
+                [1.5, [null, "a"]],
+                {"id": 1, "label": "person", "type": "vertex", "properties": {"name": [{"id": 0, "value": "marko"}]}},
+                {"@type": "g:Int32", "@value": 1}
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_attributes_and_meta() {
+        let response = br#"{"requestId": "41d2e28a-20a4-4ab0-b379-d810dede3786",
+            "status": {"message": null, "code": 206, "attributes": {"@type": "g:Map", "@value": [
+                "host", "/127.0.0.1:8182",
+                "warnings", {"@type": "g:List", "@value": ["slow"]}
+            ]}},
+            "result": {"data": null, "meta": {"@type": "g:Map", "@value": [
+                "count", {"@type": "g:Int64", "@value": 2}
+            ]}}}"#;
+        let response = read_response(&GraphSON::V3, response).unwrap();
+        assert_eq!(response.status.code, 206);
+        assert_eq!(response.status.message, "");
+        assert_eq!(
+            response.status.attributes.get("host"),
+            Some(&GValue::from("/127.0.0.1:8182"))
+        );
+        assert_eq!(
+            response.status.attributes.get("warnings"),
+            Some(&GValue::List(vec![GValue::from("slow")].into()))
+        );
+        assert_eq!(response.result.meta.get("count"), Some(&GValue::Int64(2)));
+
+        // Some providers write plain objects whatever the GraphSON version
+        let response = br#"{"requestId": "41d2e28a-20a4-4ab0-b379-d810dede3786",
+            "status": {"code": 200, "attributes": {
+                "x-ms-total-request-charge": {"@type": "g:Double", "@value": 2.5}
+            }},
+            "result": {"data": null, "meta": {}}}"#;
+        for graphson in [GraphSON::V2, GraphSON::V3] {
+            let response = read_response(&graphson, response).unwrap();
+            assert_eq!(
+                response.status.attributes.get("x-ms-total-request-charge"),
+                Some(&GValue::Double(2.5))
+            );
+            assert!(response.result.meta.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let data = json!({"@type": "g:List", "@value": [{"@type": "g:Unknown", "@value": 1}]});
+        match read_response(&GraphSON::V3, &frame(data)) {
+            Err(GremlinError::Json(msg)) => assert_eq!(msg, "Type g:Unknown not supported"),
+            other => panic!("Unexpected result {:?}", other),
+        }
+
+        let data = json!({"@type": "g:Map", "@value": ["a"]});
+        assert!(read_response(&GraphSON::V3, &frame(data)).is_err());
+
+        match read_response(&GraphSON::V3, b"{\"requestId\": 1") {
+            Err(GremlinError::Serde(_)) => {}
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "big_numbers")]
+    #[test]
+    fn test_arbitrary_precision() {
+        let response = br#"{"requestId": "41d2e28a-20a4-4ab0-b379-d810dede3786",
+            "status": {"message": "", "code": 200},
+            "result": {"data": {"@type": "g:List", "@value": [
+                {"@type": "gx:BigInteger", "@value": 123456789012345678901234567890},
+                {"@type": "gx:BigDecimal", "@value": 1.00000000000000000000000000001}
+            ]}}}"#;
+        let data = read_response(&GraphSON::V3, response)
+            .unwrap()
+            .result
+            .data
+            .unwrap()
+            .take::<List>()
+            .unwrap();
+        assert_eq!(
+            data[0],
+            GValue::BigInteger("123456789012345678901234567890".parse().unwrap())
+        );
+        assert_eq!(
+            data[1],
+            GValue::BigDecimal("1.00000000000000000000000000001".parse().unwrap())
+        );
+
+        // Untyped numbers reach the visitor as arbitrary precision maps
+        let response = br#"{"requestId": "41d2e28a-20a4-4ab0-b379-d810dede3786",
+            "status": {"message": "", "code": 200},
+            "result": {"data": [1]}}"#;
+        assert!(read_response(&GraphSON::V2, response).is_err());
+    }
+}
@@ -5,6 +5,8 @@ macro_rules! g_serializer {
                 Ok(s.clone().into())
             } else if let Value::Bool(b) = val {
                 Ok((*b).into())
+            } else if let Value::Null = val {
+                Ok($crate::GValue::Null)
             } else {
                 let _type = &val["@type"];
                 let _type = get_value!(_type,serde_json::Value::String)?.as_str();
@@ -27,6 +29,9 @@ macro_rules! g_serializer_2 {
             if let Value::String(ref s) = val {
                 return Ok(s.clone().into())
             }
+            if let Value::Null = val {
+                return Ok($crate::GValue::Null)
+            }
             if let Value::Array(_) = val {
                 let _type = "g:List";
                 let _value = &val;
@@ -107,3 +112,14 @@ macro_rules! expect_double {
         }?
     };
 }
+
+#[cfg(feature = "big_numbers")]
+macro_rules! expect_number_literal {
+    ($value:expr) => {
+        match $value {
+            serde_json::Value::Number(n) => Ok(n.to_string()),
+            serde_json::Value::String(s) => Ok(s.clone()),
+            _ => Err($crate::GremlinError::Json(String::from("Expected number"))),
+        }?
+    };
+}
@@ -1,18 +1,102 @@
 #[macro_use]
 mod macros;
+mod graph_binary_v1;
+pub(crate) mod graph_binary_v4;
+mod graphson_de;
+mod serializer_v1;
 mod serializer_v2;
 mod serializer_v3;
+pub(crate) mod temporal;
 
 use crate::conversion::ToGValue;
+use crate::message::{message_with_args_and_uuid, message_with_args_and_uuid_v2, Response};
 use crate::process::traversal::{Order, Scope};
-use crate::structure::{Cardinality, Direction, GValue, Merge, T};
+use crate::structure::{
+    Cardinality, Direction, GValue, IntermediateRepr, Merge, Metric, Property, Tree,
+    VertexProperty, T,
+};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use serde_json::{json, Map, Value};
+use std::collections::HashMap;
 use std::string::ToString;
+use uuid::Uuid;
 
 use crate::{GremlinError, GremlinResult};
 
+/// The wire format used to encode requests and decode responses.
+///
+/// Existing [GraphSON](GraphSON) values convert into it, so they can still be handed to
+/// [ConnectionOptionsBuilder::serializer](crate::ConnectionOptionsBuilder::serializer) directly.
+#[derive(Debug, Clone)]
+pub enum IoProtocol {
+    GraphSON(GraphSON),
+    /// GraphBinary V1 [docs](https://tinkerpop.apache.org/docs/current/dev/io/#graphbinary)
+    GraphBinaryV1,
+    /// GraphBinary V4, only spoken over the TinkerPop 4 HTTP protocol, see
+    /// [Protocol](crate::Protocol).
+    GraphBinaryV4,
+}
+
+impl From<GraphSON> for IoProtocol {
+    fn from(graphson: GraphSON) -> Self {
+        IoProtocol::GraphSON(graphson)
+    }
+}
+
+impl IoProtocol {
+    pub fn content_type(&self) -> &str {
+        match self {
+            IoProtocol::GraphSON(graphson) => graphson.content_type(),
+            IoProtocol::GraphBinaryV1 => graph_binary_v1::CONTENT_TYPE,
+            IoProtocol::GraphBinaryV4 => graph_binary_v4::CONTENT_TYPE,
+        }
+    }
+
+    /// Encode a request message, prefixed with its mime type as expected by the Gremlin Server.
+    ///
+    /// A new request id is generated when `request_id` is `None`.
+    pub(crate) fn build_message(
+        &self,
+        op: &str,
+        processor: &str,
+        args: HashMap<String, GValue>,
+        request_id: Option<Uuid>,
+    ) -> GremlinResult<(Uuid, Vec<u8>)> {
+        let request_id = request_id.unwrap_or_else(Uuid::new_v4);
+        let message = match self {
+            IoProtocol::GraphSON(graphson) => {
+                graphson.write_request(op, processor, args, request_id)?
+            }
+            IoProtocol::GraphBinaryV1 => {
+                graph_binary_v1::write_request(op, processor, &args, request_id)?
+            }
+            IoProtocol::GraphBinaryV4 => graph_binary_v4::write_request(op, &args)?,
+        };
+
+        let content_type = self.content_type();
+        let mut payload = Vec::with_capacity(1 + content_type.len() + message.len());
+        payload.push(content_type.len() as u8);
+        payload.extend_from_slice(content_type.as_bytes());
+        payload.extend(message);
+
+        Ok((request_id, payload))
+    }
+
+    /// Decode a response frame, including its result data.
+    pub(crate) fn read_response(&self, response: &[u8]) -> GremlinResult<Response> {
+        match self {
+            IoProtocol::GraphSON(graphson) => graphson.read_response(response),
+            IoProtocol::GraphBinaryV1 => graph_binary_v1::read_response(response),
+            IoProtocol::GraphBinaryV4 => graph_binary_v4::read_response(response),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum GraphSON {
+    /// Untyped GraphSON, only suitable for script evaluation
+    V1,
     V2,
     V3,
 }
@@ -23,6 +107,7 @@ impl GraphSON {
             return Ok(None);
         }
         match self {
+            GraphSON::V1 => serializer_v1::deserializer_v1(value).map(Some),
             GraphSON::V2 => serializer_v2::deserializer_v2(value).map(Some),
             GraphSON::V3 => serializer_v3::deserializer_v3(value).map(Some),
         }
@@ -30,6 +115,41 @@ impl GraphSON {
 
     pub fn write(&self, value: &GValue) -> GremlinResult<Value> {
         match (self, value) {
+            (GraphSON::V1, GValue::Null) => Ok(Value::Null),
+            (GraphSON::V1, GValue::Double(d)) => Ok(json!(d)),
+            (GraphSON::V1, GValue::Float(f)) => Ok(json!(f)),
+            (GraphSON::V1, GValue::Int32(i)) => Ok(json!(i)),
+            (GraphSON::V1, GValue::Int64(i)) => Ok(json!(i)),
+            (GraphSON::V1, GValue::Short(i)) => Ok(json!(i)),
+            (GraphSON::V1, GValue::Byte(i)) => Ok(json!(i)),
+            #[cfg(feature = "big_numbers")]
+            (GraphSON::V1, GValue::BigInteger(i)) => {
+                Ok(Value::Number(i.to_string().parse::<serde_json::Number>()?))
+            }
+            #[cfg(feature = "big_numbers")]
+            (GraphSON::V1, GValue::BigDecimal(d)) => {
+                Ok(Value::Number(d.to_string().parse::<serde_json::Number>()?))
+            }
+            (GraphSON::V1, GValue::Bool(b)) => Ok(json!(b)),
+            (GraphSON::V1, GValue::Uuid(u)) => Ok(json!(u.to_string())),
+            (GraphSON::V1, GValue::Date(d)) => Ok(json!(d.timestamp_millis())),
+            (GraphSON::V1, GValue::Duration(d)) => Ok(json!(temporal::format_duration(d))),
+            (GraphSON::V1, GValue::Instant(d)) => Ok(json!(temporal::format_instant(d))),
+            (GraphSON::V1, GValue::LocalDate(d)) => Ok(json!(d.to_string())),
+            (GraphSON::V1, GValue::LocalDateTime(d)) => {
+                Ok(json!(temporal::format_local_date_time(d)))
+            }
+            (GraphSON::V1, GValue::OffsetDateTime(d))
+            | (GraphSON::V1, GValue::ZonedDateTime(d)) => {
+                Ok(json!(temporal::format_offset_date_time(d)))
+            }
+            (GraphSON::V1, GValue::Year(y)) => Ok(json!(y.to_string())),
+            (GraphSON::V1, GValue::Char(c)) => Ok(json!(c.to_string())),
+            (GraphSON::V1, GValue::Bytes(b)) => Ok(json!(STANDARD.encode(b))),
+            (GraphSON::V1, GValue::Set(s)) => {
+                let elements: GremlinResult<Vec<Value>> = s.iter().map(|e| self.write(e)).collect();
+                Ok(json!(elements?))
+            }
             (_, GValue::Double(d)) => Ok(json!({
                 "@type" : "g:Double",
                 "@value" : d
@@ -46,6 +166,24 @@ impl GraphSON {
                 "@type" : "g:Int64",
                 "@value" : i
             })),
+            (_, GValue::Short(i)) => Ok(json!({
+                "@type" : "gx:Int16",
+                "@value" : i
+            })),
+            (_, GValue::Byte(i)) => Ok(json!({
+                "@type" : "gx:Byte",
+                "@value" : i
+            })),
+            #[cfg(feature = "big_numbers")]
+            (_, GValue::BigInteger(i)) => Ok(json!({
+                "@type" : "gx:BigInteger",
+                "@value" : Value::Number(i.to_string().parse::<serde_json::Number>()?)
+            })),
+            #[cfg(feature = "big_numbers")]
+            (_, GValue::BigDecimal(d)) => Ok(json!({
+                "@type" : "gx:BigDecimal",
+                "@value" : Value::Number(d.to_string().parse::<serde_json::Number>()?)
+            })),
             (_, GValue::String(s)) => Ok(Value::String(s.clone())),
             (_, GValue::Uuid(s)) => Ok(json!({
                 "@type" : "g:UUID",
@@ -55,7 +193,44 @@ impl GraphSON {
                 "@type" : "g:Date",
                 "@value" : d.timestamp_millis()
             })),
-            (GraphSON::V2, GValue::List(d)) => {
+            (_, GValue::Duration(d)) => Ok(json!({
+                "@type" : "gx:Duration",
+                "@value" : temporal::format_duration(d)
+            })),
+            (_, GValue::Instant(d)) => Ok(json!({
+                "@type" : "gx:Instant",
+                "@value" : temporal::format_instant(d)
+            })),
+            (_, GValue::LocalDate(d)) => Ok(json!({
+                "@type" : "gx:LocalDate",
+                "@value" : d.to_string()
+            })),
+            (_, GValue::LocalDateTime(d)) => Ok(json!({
+                "@type" : "gx:LocalDateTime",
+                "@value" : temporal::format_local_date_time(d)
+            })),
+            (_, GValue::OffsetDateTime(d)) => Ok(json!({
+                "@type" : "gx:OffsetDateTime",
+                "@value" : temporal::format_offset_date_time(d)
+            })),
+            // Without a region id the offset is used as the zone
+            (_, GValue::ZonedDateTime(d)) => Ok(json!({
+                "@type" : "gx:ZonedDateTime",
+                "@value" : temporal::format_offset_date_time(d)
+            })),
+            (_, GValue::Year(y)) => Ok(json!({
+                "@type" : "gx:Year",
+                "@value" : y.to_string()
+            })),
+            (_, GValue::Char(c)) => Ok(json!({
+                "@type" : "gx:Char",
+                "@value" : c.to_string()
+            })),
+            (_, GValue::Bytes(b)) => Ok(json!({
+                "@type" : "gx:ByteBuffer",
+                "@value" : STANDARD.encode(b)
+            })),
+            (GraphSON::V1, GValue::List(d)) | (GraphSON::V2, GValue::List(d)) => {
                 let elements: GremlinResult<Vec<Value>> = d.iter().map(|e| self.write(e)).collect();
                 Ok(json!(elements?))
             }
@@ -66,6 +241,42 @@ impl GraphSON {
                     "@value" : elements?
                 }))
             }
+            (GraphSON::V1, GValue::Map(map)) | (GraphSON::V2, GValue::Map(map)) => {
+                let mut params = Map::new();
+
+                for (k, v) in map.iter() {
+                    params.insert(
+                        self.write(&k.clone().into())?
+                            .as_str()
+                            .ok_or_else(|| {
+                                GremlinError::Generic("Non-string key value.".to_string())
+                            })?
+                            .to_string(),
+                        self.write(&v)?,
+                    );
+                }
+
+                Ok(json!(params))
+            }
+            (GraphSON::V1, _) => Err(GremlinError::Generic(format!(
+                "Type {:?} not supported in GraphSON V1.",
+                value
+            ))),
+            (_, GValue::Lambda(l)) => Ok(json!({
+                "@type" : "g:Lambda",
+                "@value" : {
+                    "script" : l.script(),
+                    "language" : l.language(),
+                    "arguments" : l.arguments_length()
+                }
+            })),
+            (_, GValue::Binding(b)) => Ok(json!({
+                "@type" : "g:Binding",
+                "@value" : {
+                    "key" : b.key(),
+                    "value" : self.write(b.value())?
+                }
+            })),
             (_, GValue::P(p)) => Ok(json!({
                 "@type" : "g:P",
                 "@value" : {
@@ -111,32 +322,113 @@ impl GraphSON {
                     }
                 }))
             }
+            (_, GValue::Null) => Ok(Value::Null),
             (_, GValue::Vertex(v)) => {
-                let id = self.write(&v.id().to_gvalue())?;
+                let mut properties = Map::new();
+                for (key, values) in v.iter() {
+                    let values: GremlinResult<Vec<Value>> = values
+                        .iter()
+                        .map(|p| self.write_vertex_property(p))
+                        .collect();
+                    properties.insert(key.clone(), Value::Array(values?));
+                }
                 Ok(json!({
                     "@type" : "g:Vertex",
                     "@value" : {
-                        "id" :  id,
+                        "id" : self.write(&v.id().to_gvalue())?,
+                        "label" : v.label(),
+                        "properties" : properties
                     }
                 }))
             }
-            (GraphSON::V2, GValue::Map(map)) => {
-                let mut params = Map::new();
-
-                for (k, v) in map.iter() {
-                    params.insert(
-                        self.write(&k.clone().into())?
-                            .as_str()
-                            .ok_or_else(|| {
-                                GremlinError::Generic("Non-string key value.".to_string())
-                            })?
-                            .to_string(),
-                        self.write(&v)?,
-                    );
+            (_, GValue::Edge(e)) => {
+                let mut properties = Map::new();
+                for (key, property) in e.iter() {
+                    properties.insert(key.clone(), self.write_property(property)?);
                 }
-
-                Ok(json!(params))
+                Ok(json!({
+                    "@type" : "g:Edge",
+                    "@value" : {
+                        "id" : self.write(&e.id().to_gvalue())?,
+                        "label" : e.label(),
+                        "inV" : self.write(&e.in_v().id().to_gvalue())?,
+                        "inVLabel" : e.in_v().label(),
+                        "outV" : self.write(&e.out_v().id().to_gvalue())?,
+                        "outVLabel" : e.out_v().label(),
+                        "properties" : properties
+                    }
+                }))
+            }
+            (_, GValue::VertexProperty(p)) => self.write_vertex_property(p),
+            (_, GValue::Property(p)) => self.write_property(p),
+            (_, GValue::Path(p)) => Ok(json!({
+                "@type" : "g:Path",
+                "@value" : {
+                    "labels" : self.write(p.labels())?,
+                    "objects" : self.write_list(p.objects().iter())?
+                }
+            })),
+            (_, GValue::Tree(t)) => self.write_tree(t),
+            (_, GValue::Traverser(t)) => Ok(json!({
+                "@type" : "g:Traverser",
+                "@value" : {
+                    "bulk" : self.write(&GValue::Int64(t.bulk()))?,
+                    "value" : self.write(t.value())?
+                }
+            })),
+            (GraphSON::V2, GValue::Set(s)) => self.write_list(s.iter()),
+            (_, GValue::Set(s)) => {
+                let elements: GremlinResult<Vec<Value>> = s.iter().map(|e| self.write(e)).collect();
+                Ok(json!({
+                    "@type" : "g:Set",
+                    "@value" : elements?
+                }))
+            }
+            // BulkSets were introduced with GraphSON V3
+            (GraphSON::V2, GValue::BulkSet(s)) => self.write_list(s.iter_expanded()),
+            (_, GValue::BulkSet(s)) => {
+                let mut elements = Vec::with_capacity(s.len() * 2);
+                for (value, bulk) in s.iter() {
+                    elements.push(self.write(value)?);
+                    elements.push(self.write(&GValue::Int64(bulk))?);
+                }
+                Ok(json!({
+                    "@type" : "g:BulkSet",
+                    "@value" : elements
+                }))
+            }
+            (_, GValue::Token(t)) => Ok(json!({
+                "@type" : "g:T",
+                "@value" : t.value()
+            })),
+            (_, GValue::TraversalMetrics(m)) => {
+                let metrics: GremlinResult<Vec<Value>> =
+                    m.metrics().iter().map(|m| self.write_metric(m)).collect();
+                Ok(json!({
+                    "@type" : "g:TraversalMetrics",
+                    "@value" : self.write_map(vec![
+                        ("dur", self.write(&GValue::Double(*m.duration()))?),
+                        ("metrics", self.write_values(metrics?)),
+                    ])
+                }))
+            }
+            (_, GValue::Metric(m)) => self.write_metric(m),
+            (_, GValue::TraversalExplanation(e)) => {
+                let intermediate: GremlinResult<Vec<Value>> = e
+                    .intermediate()
+                    .iter()
+                    .map(|i| self.write_intermediate(i))
+                    .collect();
+                Ok(json!({
+                    "@type" : "g:TraversalExplanation",
+                    "@value" : self.write_map(vec![
+                        ("original", self.write_strings(e.original())),
+                        ("final", self.write_strings(e.final_t())),
+                        ("intermediate", self.write_values(intermediate?)),
+                    ])
+                }))
             }
+            (_, GValue::IntermediateRepr(i)) => self.write_intermediate(i),
             (GraphSON::V3, GValue::Map(map)) => {
                 let mut params = vec![];
 
@@ -248,14 +540,304 @@ impl GraphSON {
                     "@value" : column,
                 }))
             }
-            (_, _) => panic!("Type {:?} not supported.", value),
         }
     }
 
+    // A g:List in GraphSON V3, a plain array otherwise
+    fn write_list<'a, I>(&self, elements: I) -> GremlinResult<Value>
+    where
+        I: Iterator<Item = &'a GValue>,
+    {
+        let elements: GremlinResult<Vec<Value>> = elements.map(|e| self.write(e)).collect();
+        Ok(self.write_values(elements?))
+    }
+
+    fn write_values(&self, values: Vec<Value>) -> Value {
+        match self {
+            GraphSON::V3 => json!({
+                "@type" : "g:List",
+                "@value" : values
+            }),
+            _ => Value::Array(values),
+        }
+    }
+
+    fn write_strings(&self, values: &[String]) -> Value {
+        self.write_values(values.iter().map(|v| json!(v)).collect())
+    }
+
+    // A g:Map with string keys in GraphSON V3, a plain object otherwise
+    fn write_map(&self, entries: Vec<(&str, Value)>) -> Value {
+        match self {
+            GraphSON::V3 => {
+                let mut values = Vec::with_capacity(entries.len() * 2);
+                for (key, value) in entries {
+                    values.push(json!(key));
+                    values.push(value);
+                }
+                json!({
+                    "@type" : "g:Map",
+                    "@value" : values
+                })
+            }
+            _ => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (String::from(key), value))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn write_vertex_property(&self, property: &VertexProperty) -> GremlinResult<Value> {
+        Ok(json!({
+            "@type" : "g:VertexProperty",
+            "@value" : {
+                "id" : self.write(&property.id().to_gvalue())?,
+                "label" : property.label(),
+                "value" : self.write(property.value())?
+            }
+        }))
+    }
+
+    fn write_property(&self, property: &Property) -> GremlinResult<Value> {
+        Ok(json!({
+            "@type" : "g:Property",
+            "@value" : {
+                "key" : property.label(),
+                "value" : self.write(property.value())?
+            }
+        }))
+    }
+
+    fn write_tree(&self, tree: &Tree) -> GremlinResult<Value> {
+        let branches: GremlinResult<Vec<Value>> = tree
+            .iter()
+            .map(|(key, subtree)| {
+                Ok(json!({
+                    "key" : self.write(key)?,
+                    "value" : self.write_tree(subtree)?
+                }))
+            })
+            .collect();
+        Ok(json!({
+            "@type" : "g:Tree",
+            "@value" : branches?
+        }))
+    }
+
+    fn write_metric(&self, metric: &Metric) -> GremlinResult<Value> {
+        let nested: GremlinResult<Vec<Value>> = metric
+            .nested()
+            .iter()
+            .map(|m| self.write_metric(m))
+            .collect();
+        Ok(json!({
+            "@type" : "g:Metrics",
+            "@value" : self.write_map(vec![
+                ("id", json!(metric.id())),
+                ("name", json!(metric.name())),
+                ("dur", self.write(&GValue::Double(*metric.duration()))?),
+                (
+                    "counts",
+                    self.write_map(vec![
+                        ("traverserCount", self.write(&GValue::Int64(*metric.traversers()))?),
+                        ("elementCount", self.write(&GValue::Int64(*metric.count()))?),
+                    ]),
+                ),
+                (
+                    "annotations",
+                    self.write_map(vec![(
+                        "percentDur",
+                        self.write(&GValue::Double(*metric.perc_duration()))?,
+                    )]),
+                ),
+                ("metrics", self.write_values(nested?)),
+            ])
+        }))
+    }
+
+    fn write_intermediate(&self, intermediate: &IntermediateRepr) -> GremlinResult<Value> {
+        Ok(self.write_map(vec![
+            ("traversal", self.write_strings(intermediate.traversal())),
+            ("strategy", json!(intermediate.strategy())),
+            ("category", json!(intermediate.category())),
+        ]))
+    }
+
     pub fn content_type(&self) -> &str {
         match self {
+            GraphSON::V1 => "application/vnd.gremlin-v1.0+json",
             GraphSON::V2 => "application/vnd.gremlin-v2.0+json",
             GraphSON::V3 => "application/vnd.gremlin-v3.0+json",
         }
     }
+
+    fn write_request(
+        &self,
+        op: &str,
+        processor: &str,
+        args: HashMap<String, GValue>,
+        request_id: Uuid,
+    ) -> GremlinResult<Vec<u8>> {
+        let args = self.write(&GValue::from(args))?;
+        let (op, processor) = (String::from(op), String::from(processor));
+
+        let message = match self {
+            GraphSON::V1 | GraphSON::V3 => {
+                serde_json::to_vec(&message_with_args_and_uuid(op, processor, request_id, args))
+            }
+            GraphSON::V2 => serde_json::to_vec(&message_with_args_and_uuid_v2(
+                op, processor, request_id, args,
+            )),
+        };
+        message.map_err(GremlinError::from)
+    }
+
+    fn read_response(&self, response: &[u8]) -> GremlinResult<Response> {
+        graphson_de::read_response(self, response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::GraphSON;
+    use crate::structure::{
+        BulkSet, Edge, GValue, IntermediateRepr, List, Metric, Path, Property, Token,
+        TraversalExplanation, TraversalMetrics, Traverser, Tree, Vertex, VertexProperty, GID,
+    };
+    use std::collections::HashMap;
+
+    fn round_trip(graphson: GraphSON, value: GValue) -> GValue {
+        let written = graphson.write(&value).expect("Failed to write");
+        graphson
+            .read(&written)
+            .expect("Failed to read")
+            .unwrap_or(GValue::Null)
+    }
+
+    fn assert_round_trip(value: GValue) {
+        for graphson in [GraphSON::V2, GraphSON::V3].iter() {
+            assert_eq!(value, round_trip(graphson.clone(), value.clone()));
+        }
+    }
+
+    fn vertex() -> Vertex {
+        let mut properties = HashMap::new();
+        properties.insert(
+            String::from("name"),
+            vec![VertexProperty::new(
+                GID::Int64(0),
+                "name",
+                GValue::String(String::from("marko")),
+            )],
+        );
+        properties.insert(
+            String::from("age"),
+            vec![VertexProperty::new(GID::Int64(1), "age", GValue::Int32(29))],
+        );
+        Vertex::new(GID::Int32(1), String::from("person"), properties)
+    }
+
+    fn edge() -> Edge {
+        let mut properties = HashMap::new();
+        properties.insert(
+            String::from("weight"),
+            Property::new("weight", GValue::Double(0.5)),
+        );
+        Edge::new(
+            GID::Int32(13),
+            String::from("develops"),
+            GID::Int32(10),
+            String::from("software"),
+            GID::Int32(1),
+            String::from("person"),
+            properties,
+        )
+    }
+
+    #[test]
+    fn test_round_trip_elements() {
+        assert_round_trip(vertex().into());
+        assert_round_trip(edge().into());
+        assert_round_trip(VertexProperty::new(GID::Int64(0), "name", "marko").into());
+        assert_round_trip(Property::new("weight", GValue::Double(0.5)).into());
+    }
+
+    #[test]
+    fn test_round_trip_traversal_results() {
+        let path = Path::new(
+            GValue::List(List::new(vec![
+                GValue::List(List::new(vec!["a".into()])),
+                GValue::List(List::new(vec![])),
+            ])),
+            List::new(vec![vertex().into(), edge().into()]),
+        );
+        assert_round_trip(path.into());
+
+        let tree = Tree::new(vec![(
+            vertex().into(),
+            Tree::new(vec![(
+                GValue::String(String::from("leaf")),
+                Tree::default(),
+            )]),
+        )]);
+        assert_round_trip(tree.into());
+
+        assert_round_trip(Traverser::new(3, vertex().into()).into());
+        assert_round_trip(Token::new("label").into());
+        assert_round_trip(GValue::Null);
+    }
+
+    #[test]
+    fn test_round_trip_collections() {
+        let set = GValue::Set(vec![GValue::Int32(1), GValue::Int32(2)].into());
+        let list = GValue::List(List::new(vec![GValue::Int32(1), GValue::Int32(2)]));
+        assert_eq!(list, round_trip(GraphSON::V2, set.clone()));
+        assert_eq!(list, round_trip(GraphSON::V3, set));
+
+        let bulk_set = BulkSet::new(vec![(GValue::Int32(1), 2), (GValue::Int32(2), 1)]);
+        assert_eq!(
+            GValue::BulkSet(bulk_set.clone()),
+            round_trip(GraphSON::V3, bulk_set.clone().into())
+        );
+        assert_eq!(
+            GValue::List(List::new(vec![
+                GValue::Int32(1),
+                GValue::Int32(1),
+                GValue::Int32(2)
+            ])),
+            round_trip(GraphSON::V2, bulk_set.into())
+        );
+    }
+
+    #[test]
+    fn test_round_trip_profile_and_explain() {
+        let nested = Metric::new("7.0.0()", "VertexStep(OUT,vertex)", 0.5, 4, 4, 25.0, vec![]);
+        let metrics = TraversalMetrics::new(
+            2.0,
+            vec![Metric::new(
+                "7.0.0()",
+                "TinkerGraphStep(vertex,[])",
+                1.5,
+                6,
+                6,
+                75.0,
+                vec![nested],
+            )],
+        );
+        assert_round_trip(metrics.into());
+
+        let explanation = TraversalExplanation::new(
+            vec![String::from("GraphStep(vertex,[])")],
+            vec![String::from("TinkerGraphStep(vertex,[])")],
+            vec![IntermediateRepr::new(
+                vec![String::from("TinkerGraphStep(vertex,[])")],
+                String::from("TinkerGraphStepStrategy"),
+                String::from("provider"),
+            )],
+        );
+        assert_round_trip(explanation.into());
+    }
 }
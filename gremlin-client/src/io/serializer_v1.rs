@@ -0,0 +1,253 @@
+//! GraphSON V1 [docs](http://tinkerpop.apache.org/docs/current/dev/io/#graphson-1d0)
+//!
+//! GraphSON V1 carries no type information, so values are inferred from the plain JSON shape.
+
+use crate::structure::{Edge, GValue, Property, Vertex, VertexProperty, GID};
+use crate::GremlinError;
+use crate::GremlinResult;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+pub fn deserializer_v1(val: &Value) -> GremlinResult<GValue> {
+    match val {
+        Value::Null => Ok(GValue::Null),
+        Value::Bool(b) => Ok(GValue::Bool(*b)),
+        Value::Number(_) => deserialize_number(val),
+        Value::String(s) => Ok(GValue::String(s.clone())),
+        Value::Array(_) => deserialize_list(val),
+        Value::Object(o) => match o.get("type").and_then(Value::as_str) {
+            Some("vertex") => deserialize_vertex(o),
+            Some("edge") => deserialize_edge(o),
+            _ => deserialize_map(o),
+        },
+    }
+}
+
+// Integral numbers are read as Int64, everything else as Double
+fn deserialize_number(val: &Value) -> GremlinResult<GValue> {
+    match val.as_i64() {
+        Some(i) => Ok(GValue::Int64(i)),
+        None => Ok(GValue::Double(expect_double!(val))),
+    }
+}
+
+fn deserialize_list(val: &Value) -> GremlinResult<GValue> {
+    let val = get_value!(val, Value::Array)?;
+    let mut elements = Vec::with_capacity(val.len());
+    for item in val {
+        elements.push(deserializer_v1(item)?)
+    }
+    Ok(elements.into())
+}
+
+fn deserialize_map(val: &Map<String, Value>) -> GremlinResult<GValue> {
+    let mut map = HashMap::with_capacity(val.len());
+    for (k, v) in val {
+        map.insert(k.clone(), deserializer_v1(v)?);
+    }
+    Ok(map.into())
+}
+
+// Vertex shape {"id":1,"label":"person","type":"vertex","properties":{"name":[{"id":0,"value":"marko"}]}}
+fn deserialize_vertex(val: &Map<String, Value>) -> GremlinResult<GValue> {
+    let id = deserialize_id(field(val, "id", "Vertex")?)?;
+    let label = deserialize_label(val, "label", "vertex")?;
+
+    let mut properties = HashMap::new();
+    if let Some(Value::Object(o)) = val.get("properties") {
+        for (key, values) in o {
+            let values = get_value!(values, Value::Array)?;
+            let mut vertex_properties = Vec::with_capacity(values.len());
+            for value in values {
+                let value = get_value!(value, Value::Object)?;
+                vertex_properties.push(VertexProperty::new(
+                    deserialize_id(field(value, "id", "VertexProperty")?)?,
+                    key.clone(),
+                    deserializer_v1(field(value, "value", "VertexProperty")?)?,
+                ));
+            }
+            properties.insert(key.clone(), vertex_properties);
+        }
+    }
+
+    Ok(Vertex::new(id, label, properties).into())
+}
+
+// Edge shape {"id":13,"label":"develops","type":"edge","inVLabel":"software","outVLabel":"person","inV":10,"outV":1,"properties":{"since":2009}}
+fn deserialize_edge(val: &Map<String, Value>) -> GremlinResult<GValue> {
+    let id = deserialize_id(field(val, "id", "Edge")?)?;
+    let label = deserialize_label(val, "label", "edge")?;
+    let in_v_id = deserialize_id(field(val, "inV", "Edge")?)?;
+    let in_v_label = deserialize_label(val, "inVLabel", "vertex")?;
+    let out_v_id = deserialize_id(field(val, "outV", "Edge")?)?;
+    let out_v_label = deserialize_label(val, "outVLabel", "vertex")?;
+
+    let mut properties = HashMap::new();
+    if let Some(Value::Object(o)) = val.get("properties") {
+        for (key, value) in o {
+            properties.insert(
+                key.clone(),
+                Property::new(key.clone(), deserializer_v1(value)?),
+            );
+        }
+    }
+
+    Ok(Edge::new(
+        id,
+        label,
+        in_v_id,
+        in_v_label,
+        out_v_id,
+        out_v_label,
+        properties,
+    )
+    .into())
+}
+
+fn deserialize_id(val: &Value) -> GremlinResult<GID> {
+    match val {
+        Value::String(s) => Ok(GID::String(s.clone())),
+        Value::Number(_) => Ok(GID::Int64(expect_i64!(val))),
+        _ => Ok(GID::String(val.to_string())),
+    }
+}
+
+fn deserialize_label(val: &Map<String, Value>, key: &str, default: &str) -> GremlinResult<String> {
+    match val.get(key) {
+        Some(label) => Ok(get_value!(label, Value::String)?.clone()),
+        None => Ok(String::from(default)),
+    }
+}
+
+fn field<'a>(val: &'a Map<String, Value>, key: &str, owner: &str) -> GremlinResult<&'a Value> {
+    val.get(key)
+        .ok_or_else(|| GremlinError::Json(format!("Field {} not found in {}", key, owner)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::deserializer_v1;
+    use serde_json::json;
+
+    use crate::vertex;
+
+    use crate::structure::{Edge, GValue, GID, P};
+    use crate::GraphSON;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_number_input() {
+        let result = deserializer_v1(&json!(31)).expect("Failed to deserialize an Int64");
+        assert_eq!(result, GValue::Int64(31));
+
+        let result = deserializer_v1(&json!(-31)).expect("Failed to deserialize an Int64");
+        assert_eq!(result, GValue::Int64(-31));
+
+        let result = deserializer_v1(&json!(31.3)).expect("Failed to deserialize a Double");
+        assert_eq!(result, GValue::Double(31.3));
+
+        let result = deserializer_v1(&json!(true)).expect("Failed to deserialize a Bool");
+        assert_eq!(result, GValue::Bool(true));
+    }
+
+    #[test]
+    fn test_collections() {
+        let value = json!([1, 2, "3"]);
+
+        let result = deserializer_v1(&value).expect("Failed to deserialize a List");
+
+        assert_eq!(
+            result,
+            GValue::List(
+                vec![
+                    GValue::Int64(1),
+                    GValue::Int64(2),
+                    GValue::String(String::from("3")),
+                ]
+                .into()
+            )
+        );
+
+        let value = json!({"a": 1, "b": "marko", "c": {"d": [1.5]}});
+
+        let result = deserializer_v1(&value).expect("Failed to deserialize a Map");
+
+        let mut inner = HashMap::new();
+        inner.insert(
+            String::from("d"),
+            GValue::List(vec![GValue::Double(1.5)].into()),
+        );
+
+        let mut map = HashMap::new();
+        map.insert(String::from("a"), GValue::Int64(1));
+        map.insert(String::from("b"), GValue::String(String::from("marko")));
+        map.insert(String::from("c"), GValue::from(inner));
+        assert_eq!(result, GValue::from(map));
+    }
+
+    #[test]
+    fn test_vertex() {
+        let value = json!({"id":1,"label":"person","type":"vertex","properties":{"name":[{"id":0,"value":"marko"}],"location":[{"id":6,"value":"san diego","properties":{"startTime":1997}},{"id":7,"value":"santa cruz"}]}});
+
+        let result = deserializer_v1(&value).expect("Failed to deserialize a Vertex");
+
+        assert_eq!(
+            result,
+            vertex!({
+                id => 1_i64,
+                label => "person",
+                properties => {
+                    "name" => [ { id => 0_i64, value => "marko"}],
+                    "location" => [{ id => 6_i64, value => "san diego"},{ id => 7_i64, value => "santa cruz"}]
+                }
+            })
+            .into()
+        );
+    }
+
+    #[test]
+    fn test_edge() {
+        let value = json!({"id":13,"label":"develops","type":"edge","inVLabel":"software","outVLabel":"person","inV":10,"outV":1,"properties":{"since":2009}});
+
+        let result = deserializer_v1(&value).expect("Failed to deserialize an Edge");
+
+        let edge = result.take::<Edge>().expect("It should be an Edge");
+
+        assert_eq!(edge.id(), &GID::Int64(13));
+        assert_eq!(edge.label(), "develops");
+        assert_eq!(edge.in_v().id(), &GID::Int64(10));
+        assert_eq!(edge.in_v().label(), "software");
+        assert_eq!(edge.out_v().id(), &GID::Int64(1));
+        assert_eq!(edge.out_v().label(), "person");
+        assert_eq!(
+            edge.property("since").map(|p| p.value()),
+            Some(&GValue::Int64(2009))
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let mut map = HashMap::new();
+        map.insert(String::from("gremlin"), GValue::from("g.V(x)"));
+        map.insert(
+            String::from("bindings"),
+            GValue::from(vec![
+                GValue::Int32(1),
+                GValue::Int64(2),
+                GValue::Double(1.5),
+            ]),
+        );
+
+        let result = GraphSON::V1
+            .write(&GValue::from(map))
+            .expect("Failed to serialize a Map");
+
+        assert_eq!(
+            result,
+            json!({"gremlin": "g.V(x)", "bindings": [1, 2, 1.5]})
+        );
+
+        assert!(GraphSON::V1.write(&P::eq(1).into()).is_err());
+    }
+}
@@ -2,16 +2,21 @@
 //!
 
 use crate::conversion::FromGValue;
+use crate::io::temporal;
 use crate::structure::{
-    Edge, GKey, GValue, IntermediateRepr, List, Map, Metric, Path, Property, Token,
-    TraversalExplanation, TraversalMetrics, Traverser, Vertex, VertexProperty, GID,
+    Binding, BulkSet, Edge, GKey, GValue, IntermediateRepr, Lambda, List, Map, Metric, Path,
+    Property, Token, TraversalExplanation, TraversalMetrics, Traverser, Tree, Vertex,
+    VertexProperty, GID,
 };
 use crate::GremlinError;
 use crate::GremlinResult;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use chrono::offset::TimeZone;
 use chrono::Utc;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 static G_METRICS: &str = "g:Metrics";
 static G_TRAVERSAL_EXPLANATION: &str = "g:TraversalExplanation";
@@ -27,6 +32,7 @@ where
             GValue::String(d) => Ok(GID::String(d)),
             GValue::Int32(d) => Ok(GID::Int32(d)),
             GValue::Int64(d) => Ok(GID::Int64(d)),
+            GValue::Null => Ok(GID::String(val.to_string())),
             _ => Err(GremlinError::Json(format!("{} cannot be an id", val))),
         },
         Err(e) => match e {
@@ -45,6 +51,98 @@ where
     Ok(GValue::from(Utc.timestamp_millis(val)))
 }
 
+// Duration deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_duration_2)
+pub fn deserialize_duration<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    Ok(GValue::Duration(temporal::parse_duration(val)?))
+}
+
+// Instant deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_instant_2)
+pub fn deserialize_instant<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    Ok(GValue::Instant(temporal::parse_instant(val)?))
+}
+
+// LocalDate deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_localdate_2)
+pub fn deserialize_local_date<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    Ok(GValue::LocalDate(temporal::parse_local_date(val)?))
+}
+
+// LocalDateTime deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_localdatetime_2)
+pub fn deserialize_local_date_time<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    Ok(GValue::LocalDateTime(temporal::parse_local_date_time(val)?))
+}
+
+// OffsetDateTime deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_offsetdatetime_2)
+pub fn deserialize_offset_date_time<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    Ok(GValue::OffsetDateTime(temporal::parse_offset_date_time(
+        val,
+    )?))
+}
+
+// ZonedDateTime deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_zoneddatetime_2)
+pub fn deserialize_zoned_date_time<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    Ok(GValue::ZonedDateTime(temporal::parse_zoned_date_time(val)?))
+}
+
+// Year deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_year_2)
+pub fn deserialize_year<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    match val {
+        Value::String(s) => Ok(GValue::Year(temporal::parse_year(s)?)),
+        _ => Ok(GValue::Year(expect_i32!(val))),
+    }
+}
+
+// ByteBuffer deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_bytebuffer_2)
+pub fn deserialize_byte_buffer<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    let bytes = STANDARD
+        .decode(val)
+        .map_err(|e| GremlinError::Json(format!("{} is not valid base64: {}", val, e)))?;
+    Ok(GValue::Bytes(bytes))
+}
+
+// Char deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_char_2)
+pub fn deserialize_char<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::String)?;
+    let mut chars = val.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(GValue::Char(c)),
+        _ => Err(GremlinError::Json(format!("{} cannot be a Char", val))),
+    }
+}
+
 // Long deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_long_2)
 pub fn deserialize_g64<T>(_: &T, val: &Value) -> GremlinResult<GValue>
 where
@@ -90,6 +188,74 @@ where
     Ok(GValue::from(val))
 }
 
+// Byte deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_byte_2)
+pub fn deserialize_byte<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = expect_i64!(val);
+    let val =
+        i8::try_from(val).map_err(|_| GremlinError::Json(format!("{} cannot be a Byte", val)))?;
+    Ok(GValue::from(val))
+}
+
+// Int16 deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_int16_2)
+pub fn deserialize_g16<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = expect_i64!(val);
+    let val = i16::try_from(val)
+        .map_err(|_| GremlinError::Json(format!("{} cannot be an Int16", val)))?;
+    Ok(GValue::from(val))
+}
+
+// BigInteger deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_biginteger_2)
+#[cfg(feature = "big_numbers")]
+pub fn deserialize_big_integer<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = expect_number_literal!(val);
+    let val = val
+        .parse::<num_bigint::BigInt>()
+        .map_err(|e| GremlinError::Json(format!("{} cannot be a BigInteger: {}", val, e)))?;
+    Ok(GValue::from(val))
+}
+
+// Without the big_numbers feature BigIntegers are read as Int64 when they fit
+#[cfg(not(feature = "big_numbers"))]
+pub fn deserialize_big_integer<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = expect_i64!(val);
+    Ok(GValue::from(val))
+}
+
+// BigDecimal deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_bigdecimal_2)
+#[cfg(feature = "big_numbers")]
+pub fn deserialize_big_decimal<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = expect_number_literal!(val);
+    let val = val
+        .parse::<bigdecimal::BigDecimal>()
+        .map_err(|e| GremlinError::Json(format!("{} cannot be a BigDecimal: {}", val, e)))?;
+    Ok(GValue::from(val))
+}
+
+// Without the big_numbers feature BigDecimals are read as Double
+#[cfg(not(feature = "big_numbers"))]
+pub fn deserialize_big_decimal<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = expect_double!(val);
+    Ok(GValue::from(val))
+}
+
 // List deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_list)
 pub fn deserialize_list<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
 where
@@ -103,6 +269,26 @@ where
     Ok(elements.into())
 }
 
+// BulkSet deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_bulkset)
+pub fn deserialize_bulk_set<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::Array)?;
+    let mut items = Vec::with_capacity(val.len() / 2);
+    for pair in val.chunks(2) {
+        match pair {
+            [value, bulk] => items.push((reader(value)?, reader(bulk)?.take::<i64>()?)),
+            _ => {
+                return Err(GremlinError::Json(String::from(
+                    "BulkSet must contain value and bulk pairs",
+                )))
+            }
+        }
+    }
+    Ok(BulkSet::new(items).into())
+}
+
 // Map deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_map)
 pub fn deserialize_map<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
 where
@@ -177,7 +363,7 @@ where
         in_v_label,
         out_v_id,
         out_v_label,
-        HashMap::new(),
+        deserialize_edge_properties(reader, &val["properties"])?,
     )
     .into())
 }
@@ -194,6 +380,47 @@ where
     Ok(Path::new(labels, objects).into())
 }
 
+// Tree deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_tree_3)
+pub fn deserialize_tree<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::Array)?;
+    let mut branches = Vec::with_capacity(val.len());
+    for branch in val {
+        let key = reader(&branch["key"])?;
+        let tree = reader(&branch["value"])?.take::<Tree>()?;
+        branches.push((key, tree));
+    }
+    Ok(Tree::new(branches).into())
+}
+
+// Lambda deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_lambda_2)
+pub fn deserialize_lambda<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let script = get_value!(&val["script"], Value::String)?;
+    let language = get_value!(&val["language"], Value::String)?;
+    let arguments = match &val["arguments"] {
+        Value::Null => -1,
+        arguments => expect_i32!(arguments),
+    };
+    Ok(Lambda::with_language(script.clone(), language.clone())
+        .arguments(arguments)
+        .into())
+}
+
+// Binding deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_binding_3)
+pub fn deserialize_binding<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let key = get_value!(&val["key"], Value::String)?;
+    let value = reader(&val["value"])?;
+    Ok(Binding::new(key.clone(), value).into())
+}
+
 // Traversal Metrics deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_traversalmetrics)
 pub fn deserialize_metrics<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
 where
@@ -368,10 +595,24 @@ g_serializer!(deserializer_v3, {
     "g:Int64" => deserialize_g64,
     "g:Float" => deserialize_f32,
     "g:Double" => deserialize_f64,
+    "gx:Byte" => deserialize_byte,
+    "gx:Int16" => deserialize_g16,
+    "gx:BigInteger" => deserialize_big_integer,
+    "gx:BigDecimal" => deserialize_big_decimal,
     "g:Date" => deserialize_date,
+    "gx:Duration" => deserialize_duration,
+    "gx:Instant" => deserialize_instant,
+    "gx:LocalDate" => deserialize_local_date,
+    "gx:LocalDateTime" => deserialize_local_date_time,
+    "gx:OffsetDateTime" => deserialize_offset_date_time,
+    "gx:ZonedDateTime" => deserialize_zoned_date_time,
+    "gx:Year" => deserialize_year,
+    "gx:ByteBuffer" => deserialize_byte_buffer,
+    "gx:Char" => deserialize_char,
     "g:UUID" => deserialize_uuid,
     "g:List" => deserialize_list,
     "g:Set" => deserialize_list,
+    "g:BulkSet" => deserialize_bulk_set,
     "g:Map" => deserialize_map,
     "g:T" => deserialize_token,
     "g:Vertex" => deserialize_vertex,
@@ -379,6 +620,9 @@ g_serializer!(deserializer_v3, {
     "g:Property" => deserialize_property,
     "g:Edge" => deserialize_edge,
     "g:Path" => deserialize_path,
+    "g:Tree" => deserialize_tree,
+    "g:Lambda" => deserialize_lambda,
+    "g:Binding" => deserialize_binding,
     "g:TraversalMetrics" => deserialize_metrics,
     "g:Metrics" => deserialize_metric,
     "g:TraversalExplanation" => deserialize_explain,
@@ -424,6 +668,30 @@ where
     }
 }
 
+fn deserialize_edge_properties<T>(
+    reader: &T,
+    properties: &Value,
+) -> GremlinResult<HashMap<String, Property>>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    match properties {
+        Value::Object(o) => {
+            let mut p = HashMap::new();
+            for (k, v) in o {
+                p.insert(k.clone(), reader(v)?.take()?);
+            }
+            Ok(p)
+        }
+
+        Value::Null => Ok(HashMap::new()),
+        _ => Err(GremlinError::Json(format!(
+            "Expected object or null for properties. Found {}",
+            properties
+        ))),
+    }
+}
+
 fn remove_or_else(map: &mut Map, field: &str, owner: &str) -> GremlinResult<GValue> {
     remove(map, field, owner)
         .ok_or_else(|| GremlinError::Json(format!("Field {} not found in {}", field, owner)))
@@ -442,7 +710,8 @@ mod tests {
     use crate::{edge, vertex};
 
     use crate::structure::{
-        GValue, Map, Metric, Path, Property, Token, TraversalMetrics, Vertex, VertexProperty, GID,
+        Binding, BulkSet, GValue, Lambda, Map, Metric, Path, Property, Token, TraversalMetrics,
+        Tree, Vertex, VertexProperty, GID,
     };
     use chrono::offset::TimeZone;
     use std::collections::HashMap;
@@ -502,6 +771,200 @@ mod tests {
         assert_eq!(result, GValue::from(map));
     }
 
+    #[test]
+    #[cfg(feature = "big_numbers")]
+    fn test_big_numbers() {
+        let value: serde_json::Value = serde_json::from_str(
+            r#"{"@type":"gx:BigInteger","@value":123456789012345678901234567890}"#,
+        )
+        .unwrap();
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a BigInteger");
+        assert_eq!(
+            result,
+            GValue::BigInteger("123456789012345678901234567890".parse().unwrap())
+        );
+
+        let value: serde_json::Value =
+            serde_json::from_str(r#"{"@type":"gx:BigDecimal","@value":1234567890.0123456789}"#)
+                .unwrap();
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a BigDecimal");
+        assert_eq!(
+            result,
+            GValue::BigDecimal("1234567890.0123456789".parse().unwrap())
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "big_numbers"))]
+    fn test_big_numbers_fallback() {
+        let value = json!({
+            "@type": "gx:BigInteger",
+            "@value": 31
+        });
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a BigInteger");
+        assert_eq!(result, GValue::Int64(31));
+
+        let value = json!({
+            "@type": "gx:BigDecimal",
+            "@value": 31.3
+        });
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a BigDecimal");
+        assert_eq!(result, GValue::Double(31.3));
+    }
+
+    #[test]
+    fn test_temporal() {
+        let value = json!({"@type": "gx:Duration", "@value": "PT1H2M3.004S"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Duration");
+        assert_eq!(
+            result,
+            GValue::Duration(chrono::Duration::milliseconds(3_723_004))
+        );
+
+        let value = json!({"@type": "gx:Instant", "@value": "2016-12-14T16:39:19.349Z"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize an Instant");
+        assert_eq!(
+            result,
+            GValue::Instant(chrono::Utc.timestamp_millis_opt(1481733559349).unwrap())
+        );
+
+        let value = json!({"@type": "gx:LocalDate", "@value": "2016-01-01"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize a LocalDate");
+        let date = chrono::NaiveDate::from_ymd_opt(2016, 1, 1).unwrap();
+        assert_eq!(result, GValue::LocalDate(date));
+
+        let value = json!({"@type": "gx:LocalDateTime", "@value": "2016-01-01T12:30"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize a LocalDateTime");
+        let date_time = date.and_hms_opt(12, 30, 0).unwrap();
+        assert_eq!(result, GValue::LocalDateTime(date_time));
+
+        let value = json!({"@type": "gx:OffsetDateTime", "@value": "2016-01-01T12:30+01:00"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize an OffsetDateTime");
+        let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+        let offset_date_time = offset.from_local_datetime(&date_time).unwrap();
+        assert_eq!(result, GValue::OffsetDateTime(offset_date_time));
+
+        let value =
+            json!({"@type": "gx:ZonedDateTime", "@value": "2016-01-01T12:30+01:00[Europe/Paris]"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize a ZonedDateTime");
+        assert_eq!(result, GValue::ZonedDateTime(offset_date_time));
+
+        let value = json!({"@type": "gx:Year", "@value": "2016"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Year");
+        assert_eq!(result, GValue::Year(2016));
+
+        for value in vec![
+            json!({"@type": "gx:Duration", "@value": "PT3723.004S"}),
+            json!({"@type": "gx:Instant", "@value": "2016-12-14T16:39:19.349Z"}),
+            json!({"@type": "gx:LocalDateTime", "@value": "2016-01-01T12:30:00"}),
+            json!({"@type": "gx:OffsetDateTime", "@value": "2016-01-01T12:30:00+01:00"}),
+            json!({"@type": "gx:Year", "@value": "2016"}),
+        ] {
+            let result = deserializer_v3(&value).expect("Failed to deserialize");
+            assert_eq!(crate::GraphSON::V3.write(&result).unwrap(), value);
+        }
+
+        let value = json!({"@type": "gx:Duration", "@value": "1 hour"});
+        assert!(deserializer_v3(&value).is_err());
+    }
+
+    #[test]
+    fn test_char_and_bytes() {
+        let value = json!({"@type": "gx:Char", "@value": "x"});
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Char");
+        assert_eq!(result, GValue::Char('x'));
+
+        let value = json!({"@type": "gx:Char", "@value": "xy"});
+        assert!(deserializer_v3(&value).is_err());
+
+        let value = json!({"@type": "gx:ByteBuffer", "@value": "c29tZSBieXRlcw=="});
+        let result = deserializer_v3(&value).expect("Failed to deserialize a ByteBuffer");
+        assert_eq!(result, GValue::Bytes(b"some bytes".to_vec()));
+        assert_eq!(crate::GraphSON::V3.write(&result).unwrap(), value);
+    }
+
+    #[test]
+    fn test_tree() {
+        let value = json!({"@type":"g:Tree","@value":[{"key":{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":1},"label":"person"}},"value":{"@type":"g:Tree","@value":[{"key":{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":2},"label":"person"}},"value":{"@type":"g:Tree","@value":[]}},{"key":{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":3},"label":"software"}},"value":{"@type":"g:Tree","@value":[{"key":{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":4},"label":"person"}},"value":{"@type":"g:Tree","@value":[]}}]}}]}}]});
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Tree");
+        let tree = result.take::<Tree>().expect("It should be a Tree");
+
+        assert_eq!(1, tree.len());
+
+        let marko: GValue = vertex!({ id => 1, label => "person", properties => {} }).into();
+        let subtree = tree.get(&marko).expect("It should contain the root");
+        assert_eq!(2, subtree.len());
+
+        let walked: Vec<(usize, GID)> = tree
+            .walk()
+            .map(|(depth, key, _)| (depth, key.get::<Vertex>().unwrap().id().clone()))
+            .collect();
+        assert_eq!(
+            vec![
+                (0, GID::Int32(1)),
+                (1, GID::Int32(2)),
+                (1, GID::Int32(3)),
+                (2, GID::Int32(4))
+            ],
+            walked
+        );
+
+        let (software, software_tree) = tree
+            .get_by_id(&GID::Int32(3))
+            .expect("It should find the software");
+        assert_eq!("software", software.get::<Vertex>().unwrap().label());
+        assert_eq!(1, software_tree.len());
+        assert!(tree.get_by_id(&GID::Int32(5)).is_none());
+    }
+
+    #[test]
+    fn test_bulk_set() {
+        let value = json!({"@type":"g:BulkSet","@value":["marko",{"@type":"g:Int64","@value":2},"josh",{"@type":"g:Int64","@value":1}]});
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a BulkSet");
+        let set = result.get::<BulkSet>().expect("It should be a BulkSet");
+
+        assert_eq!(2, set.len());
+        assert_eq!(3, set.size());
+        assert_eq!(2, set.bulk(&GValue::from("marko")));
+        assert_eq!(
+            vec![(&GValue::from("marko"), 2), (&GValue::from("josh"), 1)],
+            set.iter().collect::<Vec<_>>()
+        );
+
+        let names = result.take::<Vec<String>>().unwrap();
+        assert_eq!(vec!["marko", "marko", "josh"], names);
+
+        let value = json!({"@type":"g:BulkSet","@value":["marko"]});
+        assert!(deserializer_v3(&value).is_err());
+    }
+
+    #[test]
+    fn test_lambda() {
+        let value = json!({"@type":"g:Lambda","@value":{"script":"{ it.get() }","language":"gremlin-groovy","arguments":1}});
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Lambda");
+        assert_eq!(
+            result,
+            GValue::Lambda(Lambda::new("{ it.get() }").arguments(1))
+        );
+        assert_eq!(crate::GraphSON::V3.write(&result).unwrap(), value);
+    }
+
+    #[test]
+    fn test_binding() {
+        let value = json!({"@type":"g:Binding","@value":{"key":"x","value":{"@type":"g:Int32","@value":1}}});
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Binding");
+        assert_eq!(result, GValue::Binding(Binding::new("x", 1)));
+        assert_eq!(crate::GraphSON::V3.write(&result).unwrap(), value);
+    }
+
     #[test]
     fn test_number_input() {
         // I32
@@ -541,6 +1004,36 @@ mod tests {
         let result = deserializer_v3(&value).expect("Failed to deserialize Double");
         assert_eq!(result, GValue::Double(31.3));
 
+        // I8
+        let value = json!({
+            "@type": "gx:Byte",
+            "@value": -31
+        });
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Byte");
+        assert_eq!(result, GValue::Byte(-31));
+
+        let value = json!({
+            "@type": "gx:Byte",
+            "@value": 310
+        });
+        assert!(deserializer_v3(&value).is_err());
+
+        // I16
+        let value = json!({
+            "@type": "gx:Int16",
+            "@value": 3100
+        });
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize an Int16");
+        assert_eq!(result, GValue::Short(3100));
+
+        // Smaller numeric types widen into i64 and f64
+        assert_eq!(result.clone().take::<i64>().unwrap(), 3100);
+        assert_eq!(result.take::<f64>().unwrap(), 3100.0);
+        assert_eq!(GValue::Int32(31).take::<i64>().unwrap(), 31);
+        assert_eq!(GValue::Float(0.5).take::<f64>().unwrap(), 0.5);
+
         // Date
         let value = json!({
             "@type": "g:Date",
@@ -0,0 +1,230 @@
+//! Textual forms of the `java.time` types used by GraphSON [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_duration_2)
+//!
+//! Java prints these values with `toString`, which omits zero seconds and fractions, so the parsers
+//! accept both the short and the full forms.
+
+use crate::{GremlinError, GremlinResult};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+// ISO-8601 duration as printed by java.time.Duration, e.g. PT1H2M3.004S or PT-0.5S
+pub(crate) fn parse_duration(val: &str) -> GremlinResult<Duration> {
+    let invalid = || GremlinError::Json(format!("{} is not a valid Duration", val));
+
+    let (negated, rest) = match val.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, val.strip_prefix('+').unwrap_or(val)),
+    };
+    let rest = rest
+        .strip_prefix('P')
+        .or_else(|| rest.strip_prefix('p'))
+        .ok_or_else(invalid)?;
+
+    let (days, time) = match rest.find(['T', 't']) {
+        Some(idx) => (&rest[..idx], Some(&rest[idx + 1..])),
+        None => (rest, None),
+    };
+
+    let mut duration = Duration::zero();
+    if !days.is_empty() {
+        let days = days
+            .strip_suffix('D')
+            .or_else(|| days.strip_suffix('d'))
+            .and_then(|d| d.parse::<i64>().ok())
+            .ok_or_else(invalid)?;
+        duration += Duration::try_days(days).ok_or_else(invalid)?;
+    }
+
+    if let Some(mut time) = time {
+        if time.is_empty() {
+            return Err(invalid());
+        }
+        while !time.is_empty() {
+            let idx = time.find(char::is_alphabetic).ok_or_else(invalid)?;
+            let (amount, unit) = (&time[..idx], &time[idx..idx + 1]);
+            let component = match unit {
+                "H" | "h" => amount.parse::<i64>().ok().and_then(Duration::try_hours),
+                "M" | "m" => amount.parse::<i64>().ok().and_then(Duration::try_minutes),
+                "S" | "s" => parse_seconds(amount),
+                _ => None,
+            }
+            .ok_or_else(invalid)?;
+            duration = duration.checked_add(&component).ok_or_else(invalid)?;
+            time = &time[idx + 1..];
+        }
+    }
+
+    Ok(if negated { -duration } else { duration })
+}
+
+// Seconds with an optional fraction of up to nine digits, e.g. -3.004
+fn parse_seconds(val: &str) -> Option<Duration> {
+    let (negative, unsigned) = match val.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, val),
+    };
+    let (seconds, fraction) = match unsigned.split_once('.') {
+        Some((seconds, fraction)) => (seconds, fraction),
+        None => (unsigned, ""),
+    };
+    if seconds.is_empty() || fraction.len() > 9 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let seconds = seconds.parse::<i64>().ok()?;
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", fraction).parse::<i64>().ok()?
+    };
+    let duration = Duration::try_seconds(seconds)?.checked_add(&Duration::nanoseconds(nanos))?;
+    Some(if negative { -duration } else { duration })
+}
+
+pub(crate) fn format_duration(val: &Duration) -> String {
+    let sign = if *val < Duration::zero() { "-" } else { "" };
+    let seconds = val.num_seconds().unsigned_abs();
+    let nanos = val.subsec_nanos().unsigned_abs();
+    if nanos == 0 {
+        format!("PT{}{}S", sign, seconds)
+    } else {
+        let fraction = format!("{:09}", nanos);
+        format!("PT{}{}.{}S", sign, seconds, fraction.trim_end_matches('0'))
+    }
+}
+
+pub(crate) fn split_duration(val: &Duration) -> (i64, i32) {
+    let mut seconds = val.num_seconds();
+    let mut nanos = val.subsec_nanos();
+    // java.time.Duration keeps the nanosecond adjustment positive
+    if nanos < 0 {
+        seconds -= 1;
+        nanos += NANOS_PER_SECOND as i32;
+    }
+    (seconds, nanos)
+}
+
+pub(crate) fn parse_local_date(val: &str) -> GremlinResult<NaiveDate> {
+    val.parse::<NaiveDate>()
+        .map_err(|e| GremlinError::Json(format!("{} is not a valid LocalDate: {}", val, e)))
+}
+
+pub(crate) fn parse_local_date_time(val: &str) -> GremlinResult<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M"))
+        .map_err(|e| GremlinError::Json(format!("{} is not a valid LocalDateTime: {}", val, e)))
+}
+
+pub(crate) fn format_local_date_time(val: &NaiveDateTime) -> String {
+    val.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+}
+
+pub(crate) fn parse_instant(val: &str) -> GremlinResult<DateTime<Utc>> {
+    parse_offset_date_time(val).map(|d| d.with_timezone(&Utc))
+}
+
+pub(crate) fn format_instant(val: &DateTime<Utc>) -> String {
+    val.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+pub(crate) fn parse_offset_date_time(val: &str) -> GremlinResult<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(val, "%Y-%m-%dT%H:%M:%S%.f%:z")
+        .or_else(|_| DateTime::parse_from_str(val, "%Y-%m-%dT%H:%M%:z"))
+        .or_else(|_| DateTime::parse_from_rfc3339(val))
+        .or_else(|_| {
+            // Zulu times without seconds, e.g. 2016-12-14T16:39Z
+            let local = val.strip_suffix('Z').unwrap_or(val);
+            NaiveDateTime::parse_from_str(local, "%Y-%m-%dT%H:%M")
+                .map(|d| d.and_utc().fixed_offset())
+        })
+        .map_err(|e| GremlinError::Json(format!("{} is not a valid date time: {}", val, e)))
+}
+
+pub(crate) fn format_offset_date_time(val: &DateTime<FixedOffset>) -> String {
+    val.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+// ZonedDateTime carries a trailing region id, e.g. 2016-12-23T12:12:24.166-05:00[America/New_York].
+// Only the offset is kept.
+pub(crate) fn parse_zoned_date_time(val: &str) -> GremlinResult<DateTime<FixedOffset>> {
+    let offset_date_time = match val.find('[') {
+        Some(idx) => &val[..idx],
+        None => val,
+    };
+    parse_offset_date_time(offset_date_time)
+}
+
+pub(crate) fn parse_year(val: &str) -> GremlinResult<i32> {
+    val.parse::<i32>()
+        .map_err(|e| GremlinError::Json(format!("{} is not a valid Year: {}", val, e)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{
+        format_duration, format_instant, format_local_date_time, parse_duration,
+        parse_local_date_time, parse_offset_date_time, parse_zoned_date_time, split_duration,
+    };
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(parse_duration("PT120H").unwrap(), Duration::hours(120));
+        assert_eq!(
+            parse_duration("PT1H2M3.004S").unwrap(),
+            Duration::seconds(3723) + Duration::milliseconds(4)
+        );
+        assert_eq!(
+            parse_duration("P2DT3H").unwrap(),
+            Duration::days(2) + Duration::hours(3)
+        );
+        assert_eq!(
+            parse_duration("PT-0.5S").unwrap(),
+            Duration::milliseconds(-500)
+        );
+        assert_eq!(parse_duration("-PT6H3M").unwrap(), -Duration::minutes(363));
+        assert!(parse_duration("PT").is_err());
+        assert!(parse_duration("1H").is_err());
+        assert!(parse_duration("PT1.0000000001S").is_err());
+
+        assert_eq!(format_duration(&Duration::hours(120)), "PT432000S");
+        assert_eq!(format_duration(&Duration::milliseconds(-500)), "PT-0.5S");
+        let duration = Duration::seconds(3723) + Duration::nanoseconds(4);
+        assert_eq!(
+            parse_duration(&format_duration(&duration)).unwrap(),
+            duration
+        );
+
+        assert_eq!(
+            split_duration(&Duration::milliseconds(-500)),
+            (-1, 500_000_000)
+        );
+    }
+
+    #[test]
+    fn test_date_times() {
+        let local = NaiveDate::from_ymd_opt(2016, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 30, 0)
+            .unwrap();
+        assert_eq!(parse_local_date_time("2016-01-01T12:30").unwrap(), local);
+        assert_eq!(parse_local_date_time("2016-01-01T12:30:00").unwrap(), local);
+        assert_eq!(format_local_date_time(&local), "2016-01-01T12:30:00");
+
+        let offset = parse_offset_date_time("2007-12-03T10:15:30+01:00").unwrap();
+        assert_eq!(offset.offset().local_minus_utc(), 3600);
+        assert_eq!(
+            offset,
+            parse_offset_date_time("2007-12-03T09:15:30Z").unwrap()
+        );
+
+        let zoned =
+            parse_zoned_date_time("2016-12-23T12:12:24.166-05:00[America/New_York]").unwrap();
+        assert_eq!(zoned.offset().local_minus_utc(), -5 * 3600);
+        assert_eq!(zoned.timestamp_subsec_millis(), 166);
+
+        let instant = Utc.timestamp_millis_opt(1481733559349).unwrap();
+        assert_eq!(format_instant(&instant), "2016-12-14T16:39:19.349Z");
+    }
+}
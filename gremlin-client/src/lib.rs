@@ -113,29 +113,41 @@
 #[macro_use]
 extern crate lazy_static;
 
+mod authenticator;
 mod client;
 mod connection;
 mod conversion;
 mod error;
+mod http;
 mod io;
 mod message;
 mod pool;
+mod request_options;
+#[cfg(feature = "aws-sigv4")]
+mod sigv4;
+#[cfg(test)]
+mod test_support;
 
+pub use authenticator::{Authenticator, Challenge, PlainAuthenticator};
 pub use client::GremlinClient;
 pub use connection::{
-    ConnectionOptions, ConnectionOptionsBuilder, TlsOptions, WebSocketOptions,
-    WebSocketOptionsBuilder,
+    ConnectionOptions, ConnectionOptionsBuilder, Protocol, TlsIdentity, TlsOptions,
+    TlsOptionsBuilder, TlsVersion, Transport, WebSocketOptions, WebSocketOptionsBuilder,
 };
 pub use conversion::{BorrowFromGValue, FromGValue, ToGValue};
 pub use error::GremlinError;
-pub use io::GraphSON;
-pub use message::Message;
+pub use io::{GraphSON, IoProtocol};
+pub use message::{Message, ResponseStatusCode};
+pub use request_options::{MaterializeProperties, RequestOptions, RequestOptionsBuilder};
+#[cfg(feature = "aws-sigv4")]
+pub use sigv4::{AwsCredentials, SigV4Signer};
 
 pub type GremlinResult<T> = Result<T, error::GremlinError>;
 
 pub use structure::{
     Cardinality, Edge, GKey, GResultSet, GValue, IntermediateRepr, List, Map, Metric, Path,
-    Property, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty, GID,
+    Property, ResponseFrame, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty,
+    GID,
 };
 
 #[cfg(feature = "async_gremlin")]
@@ -1,6 +1,7 @@
+use crate::structure::Map;
+use crate::{GValue, GremlinError};
 use serde::{Deserialize as SerdeDeserialize, Deserializer};
 use serde_derive::{Deserialize, Serialize};
-use serde_json::Value;
 use uuid::Uuid;
 
 #[derive(Serialize)]
@@ -16,13 +17,6 @@ pub struct RequestIdV2 {
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum Message<T> {
-    #[serde(rename_all = "camelCase")]
-    V1 {
-        request_id: Uuid,
-        op: String,
-        processor: String,
-        args: T,
-    },
     #[serde(rename_all = "camelCase")]
     V2 {
         request_id: RequestIdV2,
@@ -43,31 +37,159 @@ impl<T> Message<T> {
     #[allow(dead_code)]
     pub fn id(&self) -> &Uuid {
         match self {
-            Message::V1 { request_id, .. } => request_id,
             Message::V2 { request_id, .. } => &request_id.value,
             Message::V3 { request_id, .. } => request_id,
         }
     }
 }
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
+/// A response frame decoded by the configured deserializer.
+#[derive(Debug)]
 pub struct Response {
     pub request_id: Uuid,
     pub result: ResponseResult,
     pub status: ReponseStatus,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 pub struct ResponseResult {
-    pub data: Value,
+    pub data: Option<GValue>,
+    pub meta: Map,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ReponseStatus {
     pub code: i16,
     //Sometimes the message is omitted, default to empty string rather than panic
     #[serde(default, deserialize_with = "map_null_to_default")]
     pub message: String,
+    // Typed GraphSON values, read along with the rest of the frame
+    #[serde(skip)]
+    pub attributes: Map,
+}
+
+impl ReponseStatus {
+    /// The error reported by a non-success status.
+    ///
+    /// Gremlin Server puts the exception hierarchy and the stack trace of the failure in the
+    /// `exceptions` and `stackTrace` attributes.
+    pub(crate) fn into_error(self) -> GremlinError {
+        let exceptions = match self.attributes.get("exceptions") {
+            Some(GValue::List(list)) => list
+                .iter()
+                .filter_map(|e| e.get::<String>().ok().cloned())
+                .collect(),
+            _ => vec![],
+        };
+        let stack_trace = match self.attributes.get("stackTrace") {
+            Some(GValue::String(trace)) => Some(trace.clone()),
+            _ => None,
+        };
+        GremlinError::Server {
+            code: ResponseStatusCode::from(self.code),
+            message: self.message,
+            exceptions,
+            stack_trace,
+        }
+    }
+}
+
+/// Status code of a response [docs](https://tinkerpop.apache.org/docs/current/dev/provider/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseStatusCode {
+    Success,
+    NoContent,
+    PartialContent,
+    /// Wrong credentials
+    Unauthorized,
+    Forbidden,
+    /// The server asks for a SASL authentication
+    Authenticate,
+    TooManyRequests,
+    RequestErrorSerialization,
+    RequestErrorMalformedRequest,
+    RequestErrorInvalidRequestArguments,
+    ServerError,
+    /// A `fail()` step was reached
+    ServerErrorFailStep,
+    /// A transient failure, e.g. a conflicting concurrent transaction
+    ServerErrorTemporary,
+    ServerErrorEvaluation,
+    ServerErrorTimeout,
+    ServerErrorSerialization,
+    /// A code outside of the ones defined by TinkerPop, e.g. from a provider
+    Other(i16),
+}
+
+impl ResponseStatusCode {
+    pub fn code(&self) -> i16 {
+        match self {
+            ResponseStatusCode::Success => 200,
+            ResponseStatusCode::NoContent => 204,
+            ResponseStatusCode::PartialContent => 206,
+            ResponseStatusCode::Unauthorized => 401,
+            ResponseStatusCode::Forbidden => 403,
+            ResponseStatusCode::Authenticate => 407,
+            ResponseStatusCode::TooManyRequests => 429,
+            ResponseStatusCode::RequestErrorSerialization => 497,
+            ResponseStatusCode::RequestErrorMalformedRequest => 498,
+            ResponseStatusCode::RequestErrorInvalidRequestArguments => 499,
+            ResponseStatusCode::ServerError => 500,
+            ResponseStatusCode::ServerErrorFailStep => 595,
+            ResponseStatusCode::ServerErrorTemporary => 596,
+            ResponseStatusCode::ServerErrorEvaluation => 597,
+            ResponseStatusCode::ServerErrorTimeout => 598,
+            ResponseStatusCode::ServerErrorSerialization => 599,
+            ResponseStatusCode::Other(code) => *code,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.code())
+    }
+
+    /// Whether sending the same request again may succeed, the server being busy or the
+    /// failure being transient.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ResponseStatusCode::TooManyRequests | ResponseStatusCode::ServerErrorTemporary
+        )
+    }
+
+    /// Whether the evaluation of the request exceeded its timeout.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, ResponseStatusCode::ServerErrorTimeout)
+    }
+}
+
+impl From<i16> for ResponseStatusCode {
+    fn from(code: i16) -> ResponseStatusCode {
+        match code {
+            200 => ResponseStatusCode::Success,
+            204 => ResponseStatusCode::NoContent,
+            206 => ResponseStatusCode::PartialContent,
+            401 => ResponseStatusCode::Unauthorized,
+            403 => ResponseStatusCode::Forbidden,
+            407 => ResponseStatusCode::Authenticate,
+            429 => ResponseStatusCode::TooManyRequests,
+            497 => ResponseStatusCode::RequestErrorSerialization,
+            498 => ResponseStatusCode::RequestErrorMalformedRequest,
+            499 => ResponseStatusCode::RequestErrorInvalidRequestArguments,
+            500 => ResponseStatusCode::ServerError,
+            595 => ResponseStatusCode::ServerErrorFailStep,
+            596 => ResponseStatusCode::ServerErrorTemporary,
+            597 => ResponseStatusCode::ServerErrorEvaluation,
+            598 => ResponseStatusCode::ServerErrorTimeout,
+            599 => ResponseStatusCode::ServerErrorSerialization,
+            code => ResponseStatusCode::Other(code),
+        }
+    }
+}
+
+impl std::fmt::Display for ResponseStatusCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
 }
 
 fn map_null_to_default<'de, D, T>(de: D) -> Result<T, D::Error>
@@ -78,10 +200,6 @@ where
     Option::<T>::deserialize(de).map(Option::unwrap_or_default)
 }
 
-pub fn message_with_args_v2<T>(op: String, processor: String, args: T) -> Message<T> {
-    message_with_args_and_uuid_v2(op, processor, Uuid::new_v4(), args)
-}
-
 pub fn message_with_args_and_uuid_v2<T>(
     op: String,
     processor: String,
@@ -119,7 +237,56 @@ pub fn message_with_args_and_uuid<T>(
 
 #[cfg(test)]
 mod tests {
-    use crate::message::ReponseStatus;
+    use crate::message::{ReponseStatus, ResponseStatusCode};
+    use crate::structure::List;
+    use crate::{GValue, GremlinError};
+    use std::collections::HashMap;
+
+    #[test]
+    fn it_should_classify_status_codes() {
+        assert_eq!(ResponseStatusCode::ServerErrorTimeout, 598.into());
+        assert_eq!(ResponseStatusCode::Other(530), 530.into());
+        assert_eq!(530, ResponseStatusCode::Other(530).code());
+        assert!(ResponseStatusCode::from(598).is_timeout());
+        assert!(ResponseStatusCode::from(596).is_retryable());
+        assert!(!ResponseStatusCode::from(597).is_retryable());
+        assert!(ResponseStatusCode::from(206).is_success());
+    }
+
+    #[test]
+    fn it_should_read_errors_from_status_attributes() {
+        let mut attributes = HashMap::new();
+        attributes.insert(
+            String::from("exceptions"),
+            GValue::List(List::new(vec![GValue::from(
+                "java.util.ConcurrentModificationException",
+            )])),
+        );
+        attributes.insert(String::from("stackTrace"), GValue::from("at ..."));
+        let status = ReponseStatus {
+            code: 596,
+            message: String::from("Conflict"),
+            attributes: attributes.into(),
+        };
+
+        match status.into_error() {
+            GremlinError::Server {
+                code,
+                message,
+                exceptions,
+                stack_trace,
+            } => {
+                assert_eq!(ResponseStatusCode::ServerErrorTemporary, code);
+                assert_eq!("Conflict", message);
+                assert_eq!(
+                    vec![String::from("java.util.ConcurrentModificationException")],
+                    exceptions
+                );
+                assert_eq!(Some(String::from("at ...")), stack_trace);
+            }
+            other => panic!("Unexpected {:?}", other),
+        }
+    }
 
     #[test]
     fn handle_no_response_status_message() {
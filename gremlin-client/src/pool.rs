@@ -1,13 +1,10 @@
 use r2d2::ManageConnection;
 
+use crate::authenticator::authenticate;
 use crate::connection::Connection;
 use crate::connection::ConnectionOptions;
 use crate::error::GremlinError;
-use crate::message::{
-    message_with_args, message_with_args_and_uuid, message_with_args_v2, Response,
-};
-use crate::{GValue, GraphSON, GremlinResult};
-use base64::encode;
+use crate::{GValue, GremlinResult};
 use std::collections::HashMap;
 
 #[derive(Debug)]
@@ -40,84 +37,25 @@ impl ManageConnection for GremlinConnectionManager {
             String::from("language"),
             GValue::String(String::from("gremlin-groovy")),
         );
-        let args = self.options.serializer.write(&GValue::from(args))?;
 
-        let message = match self.options.serializer {
-            GraphSON::V2 => message_with_args_v2(String::from("eval"), String::default(), args),
-            GraphSON::V3 => message_with_args(String::from("eval"), String::default(), args),
-        };
-
-        let msg = serde_json::to_string(&message).map_err(GremlinError::from)?;
-
-        let content_type = match self.options.serializer {
-            GraphSON::V2 => "application/vnd.gremlin-v2.0+json",
-            GraphSON::V3 => "application/vnd.gremlin-v3.0+json",
-        };
-        let payload = String::from("") + content_type + &msg;
-
-        let mut binary = payload.into_bytes();
-        binary.insert(0, content_type.len() as u8);
+        let (_, binary) = self
+            .options
+            .serializer
+            .build_message("eval", "", args, None)?;
 
         conn.send(binary)?;
 
-        let result = conn.recv()?;
-        let response: Response = serde_json::from_slice(&result)?;
-
+        let response = conn.recv()?;
+        let challenged = response.status.code == 407;
+        let response = authenticate(&self.options, conn, response)?;
         match response.status.code {
             200 | 206 => Ok(()),
             204 => Ok(()),
-            407 => match &self.options.credentials {
-                Some(c) => {
-                    let mut args = HashMap::new();
-
-                    args.insert(
-                        String::from("sasl"),
-                        GValue::String(encode(&format!("\0{}\0{}", c.username, c.password))),
-                    );
-
-                    let args = self.options.serializer.write(&GValue::from(args))?;
-                    let message = message_with_args_and_uuid(
-                        String::from("authentication"),
-                        String::from("traversal"),
-                        response.request_id,
-                        args,
-                    );
-
-                    let msg = serde_json::to_string(&message).map_err(GremlinError::from)?;
-
-                    let content_type = self.options.serializer.content_type();
-                    let payload = String::from("") + content_type + &msg;
-
-                    let mut binary = payload.into_bytes();
-                    binary.insert(0, content_type.len() as u8);
-
-                    conn.send(binary)?;
-
-                    let result = conn.recv()?;
-                    let response: Response = serde_json::from_slice(&result)?;
-
-                    match response.status.code {
-                        200 | 206 => Ok(()),
-                        204 => Ok(()),
-                        401 => Ok(()),
-                        // 401 is actually a username/password incorrect error, but if not
-                        // not returned as okay, the pool loops infinitely trying
-                        // to authenticate.
-                        _ => Err(GremlinError::Request((
-                            response.status.code,
-                            response.status.message,
-                        ))),
-                    }
-                }
-                None => Err(GremlinError::Request((
-                    response.status.code,
-                    response.status.message,
-                ))),
-            },
-            _ => Err(GremlinError::Request((
-                response.status.code,
-                response.status.message,
-            ))),
+            // 401 is actually a username/password incorrect error, but if not
+            // not returned as okay, the pool loops infinitely trying
+            // to authenticate.
+            401 if challenged => Ok(()),
+            _ => Err(response.status.into_error()),
         }
     }
 
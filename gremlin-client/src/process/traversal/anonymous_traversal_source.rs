@@ -1,3 +1,6 @@
+use crate::process::traversal::step::by::ByStep;
+use crate::process::traversal::step::filter::FilterStep;
+use crate::process::traversal::step::flat_map::FlatMapStep;
 use crate::process::traversal::step::has::HasStep;
 use crate::process::traversal::step::loops::LoopsStep;
 use crate::process::traversal::step::not::NotStep;
@@ -176,6 +179,27 @@ impl AnonymousTraversalSource {
         self.traversal.clone().has_many(steps)
     }
 
+    pub fn map<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<ByStep>,
+    {
+        self.traversal.clone().map(step)
+    }
+
+    pub fn flat_map<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<FlatMapStep>,
+    {
+        self.traversal.clone().flat_map(step)
+    }
+
+    pub fn filter<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<FilterStep>,
+    {
+        self.traversal.clone().filter(step)
+    }
+
     pub fn not<A>(&self, step: A) -> TraversalBuilder
     where
         A: Into<NotStep>,
@@ -1,8 +1,10 @@
-use crate::conversion::{FromGValue, ToGValue};
+use crate::conversion::FromGValue;
 use crate::process::traversal::step::by::ByStep;
 use crate::process::traversal::step::choose::IntoChooseStep;
 use crate::process::traversal::step::coalesce::CoalesceStep;
 use crate::process::traversal::step::dedup::DedupStep;
+use crate::process::traversal::step::filter::FilterStep;
+use crate::process::traversal::step::flat_map::FlatMapStep;
 use crate::process::traversal::step::from::FromStep;
 use crate::process::traversal::step::has::HasStep;
 use crate::process::traversal::step::limit::LimitStep;
@@ -51,10 +53,7 @@ impl TraversalBuilder {
     where
         T: Into<GIDs>,
     {
-        self.bytecode.add_step(
-            String::from("V"),
-            ids.into().0.iter().map(|id| id.to_gvalue()).collect(),
-        );
+        self.bytecode.add_step(String::from("V"), ids.into().0);
         self
     }
 
@@ -62,10 +61,7 @@ impl TraversalBuilder {
     where
         T: Into<GIDs>,
     {
-        self.bytecode.add_step(
-            String::from("E"),
-            ids.into().0.iter().map(|id| id.to_gvalue()).collect(),
-        );
+        self.bytecode.add_step(String::from("E"), ids.into().0);
         self
     }
 
@@ -443,6 +439,11 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn tree(mut self) -> Self {
+        self.bytecode.add_step(String::from("tree"), vec![]);
+        self
+    }
+
     pub fn limit<A>(mut self, limit: A) -> Self
     where
         A: Into<LimitStep>,
@@ -582,6 +583,24 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn flat_map<A>(mut self, step: A) -> Self
+    where
+        A: Into<FlatMapStep>,
+    {
+        self.bytecode
+            .add_step(String::from("flatMap"), step.into().into());
+        self
+    }
+
+    pub fn filter<A>(mut self, step: A) -> Self
+    where
+        A: Into<FilterStep>,
+    {
+        self.bytecode
+            .add_step(String::from("filter"), step.into().into());
+        self
+    }
+
     pub fn repeat<A>(mut self, step: A) -> Self
     where
         A: Into<RepeatStep>,
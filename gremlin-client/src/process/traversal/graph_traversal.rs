@@ -3,6 +3,8 @@ use crate::process::traversal::step::by::ByStep;
 use crate::process::traversal::step::choose::IntoChooseStep;
 use crate::process::traversal::step::coalesce::CoalesceStep;
 use crate::process::traversal::step::dedup::DedupStep;
+use crate::process::traversal::step::filter::FilterStep;
+use crate::process::traversal::step::flat_map::FlatMapStep;
 use crate::process::traversal::step::from::FromStep;
 use crate::process::traversal::step::has::HasStep;
 use crate::process::traversal::step::limit::LimitStep;
@@ -22,7 +24,7 @@ use crate::process::traversal::strategies::{
     RemoteStrategy, TraversalStrategies, TraversalStrategy,
 };
 use crate::process::traversal::{Bytecode, Scope, TraversalBuilder, WRITE_OPERATORS};
-use crate::structure::{Cardinality, Labels, Null};
+use crate::structure::{Cardinality, Labels, Null, Tree};
 use crate::{
     structure::GIDs, structure::GProperty, structure::IntoPredicate, Edge, GValue, GremlinClient,
     List, Map, Path, Vertex,
@@ -478,6 +480,14 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         GraphTraversal::new(self.terminator, self.builder)
     }
 
+    pub fn tree(mut self) -> GraphTraversal<S, Tree, T>
+    where
+        T: Terminator<Tree>,
+    {
+        self.builder = self.builder.tree();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
     pub fn limit<A>(mut self, limit: A) -> Self
     where
         A: Into<LimitStep>,
@@ -600,6 +610,22 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self
     }
 
+    pub fn flat_map<A>(mut self, step: A) -> Self
+    where
+        A: Into<FlatMapStep>,
+    {
+        self.builder = self.builder.flat_map(step);
+        self
+    }
+
+    pub fn filter<A>(mut self, step: A) -> Self
+    where
+        A: Into<FilterStep>,
+    {
+        self.builder = self.builder.filter(step);
+        self
+    }
+
     pub fn project<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
     where
         A: Into<SelectStep>,
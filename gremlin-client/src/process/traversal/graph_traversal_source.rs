@@ -1,4 +1,4 @@
-use crate::conversion::{FromGValue, ToGValue};
+use crate::conversion::FromGValue;
 use crate::process::traversal::strategies::{
     RemoteStrategy, TraversalStrategies, TraversalStrategy,
 };
@@ -13,7 +13,7 @@ use crate::process::traversal::{GraphTraversal, TraversalBuilder};
 use crate::structure::GIDs;
 use crate::structure::Labels;
 use crate::structure::{Edge, GValue, Vertex};
-use crate::GremlinClient;
+use crate::{GremlinClient, RequestOptions};
 
 use super::merge_edge::MergeEdgeStep;
 use super::merge_vertex::MergeVertexStep;
@@ -59,10 +59,7 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
     {
         let mut code = Bytecode::new();
 
-        code.add_step(
-            String::from("V"),
-            ids.into().0.iter().map(|id| id.to_gvalue()).collect(),
-        );
+        code.add_step(String::from("V"), ids.into().0);
 
         GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
     }
@@ -104,10 +101,7 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
     {
         let mut code = Bytecode::new();
 
-        code.add_step(
-            String::from("E"),
-            ids.into().0.iter().map(|id| id.to_gvalue()).collect(),
-        );
+        code.add_step(String::from("E"), ids.into().0);
 
         GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
     }
@@ -162,6 +156,27 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
     }
 }
 
+impl GraphTraversalSource<SyncTerminator> {
+    /// Return a traversal source whose traversals are submitted with the given
+    /// [RequestOptions](RequestOptions), such as an evaluation timeout.
+    pub fn with_options(&self, options: RequestOptions) -> GraphTraversalSource<SyncTerminator> {
+        GraphTraversalSource {
+            term: self.term.with_options(options),
+        }
+    }
+}
+
+#[cfg(feature = "async_gremlin")]
+impl GraphTraversalSource<AsyncTerminator> {
+    /// Return a traversal source whose traversals are submitted with the given
+    /// [RequestOptions](RequestOptions), such as an evaluation timeout.
+    pub fn with_options(&self, options: RequestOptions) -> GraphTraversalSource<AsyncTerminator> {
+        GraphTraversalSource {
+            term: self.term.with_options(options),
+        }
+    }
+}
+
 // TESTS
 #[cfg(test)]
 mod tests {
@@ -170,7 +185,7 @@ mod tests {
 
     use super::GraphTraversalSource;
     use crate::process::traversal::{Bytecode, Order, Scope, __};
-    use crate::structure::{GValue, P, T};
+    use crate::structure::{Binding, Bindings, GValue, Lambda, P, T};
 
     fn empty() -> GraphTraversalSource<MockTerminator> {
         GraphTraversalSource::new(MockTerminator {})
@@ -810,6 +825,109 @@ mod tests {
         assert_eq!(&code, g.v(()).path().bytecode());
     }
 
+    #[test]
+    fn binding_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![Binding::new("id", 1).into()]);
+        code.add_step(
+            String::from("has"),
+            vec![
+                String::from("name").into(),
+                P::new("eq", Binding::new("name", "marko").into()).into(),
+            ],
+        );
+        code.add_step(
+            String::from("property"),
+            vec![String::from("age").into(), Binding::new("age", 29).into()],
+        );
+        code.add_step(
+            String::from("limit"),
+            vec![Binding::new("limit", 1_i64).into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(Bindings::of("id", 1))
+                .has(("name", Bindings::of("name", "marko")))
+                .property("age", Bindings::of("age", 29))
+                .limit(Bindings::of("limit", 1_i64))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn lambda_test() {
+        let g = empty();
+
+        let length = Lambda::new("it.get().value('name').length()");
+        let adult = Lambda::new("it.get().value('age') >= 18");
+        let names = Lambda::new("it.get().values('name')");
+        let log = Lambda::new("println it");
+        let compare = Lambda::new("a, b -> a <=> b");
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("filter"), vec![adult.clone().into()]);
+        code.add_step(String::from("sideEffect"), vec![log.clone().into()]);
+        code.add_step(String::from("flatMap"), vec![names.clone().into()]);
+        code.add_step(String::from("order"), vec![Scope::Global.into()]);
+        code.add_step(String::from("by"), vec![compare.clone().into()]);
+        code.add_step(String::from("map"), vec![length.clone().into()]);
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .filter(adult)
+                .side_effect(log)
+                .flat_map(names)
+                .order(())
+                .by(compare)
+                .map(length)
+                .bytecode()
+        );
+
+        let mut code = Bytecode::new();
+        let length = Lambda::new("it.get().value('name').length()");
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("order"), vec![Scope::Global.into()]);
+        code.add_step(
+            String::from("by"),
+            vec![length.clone().into(), Order::Desc.into()],
+        );
+        code.add_step(
+            String::from("filter"),
+            vec![__.has_label("person").bytecode().clone().into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .order(())
+                .by((length, Order::Desc))
+                .filter(__.has_label("person"))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn tree_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("out"), vec![]);
+        code.add_step(String::from("tree"), vec![]);
+        code.add_step(String::from("by"), vec![String::from("name").into()]);
+
+        assert_eq!(&code, g.v(()).out(()).tree().by("name").bytecode());
+    }
+
     #[test]
     fn limit_test() {
         let g = empty();
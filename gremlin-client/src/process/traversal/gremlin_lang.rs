@@ -0,0 +1,312 @@
+//! Translation of [Bytecode](Bytecode) into a `gremlin-lang` script, the traversal language of
+//! TinkerPop 4 servers.
+
+use super::bytecode::Instruction;
+use crate::process::traversal::{Bytecode, Order, Scope};
+use crate::structure::{Cardinality, Column, Direction, GKey, Merge, GID, T};
+use crate::{GValue, GremlinError, GremlinResult};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// A `gremlin-lang` script with the bindings it refers to.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Script {
+    pub(crate) gremlin: String,
+    pub(crate) bindings: HashMap<String, GValue>,
+}
+
+/// Translate a traversal spawned from `g`.
+///
+/// [Binding](crate::structure::Binding)s become script variables. Values without a literal
+/// form, such as durations or byte arrays, are passed as generated bindings instead.
+pub(crate) fn translate(bytecode: &Bytecode) -> GremlinResult<Script> {
+    let mut translator = Translator {
+        script: String::from("g"),
+        bindings: HashMap::new(),
+        generated: 0,
+    };
+    translator.instructions(bytecode.sources())?;
+    translator.instructions(bytecode.steps())?;
+
+    Ok(Script {
+        gremlin: translator.script,
+        bindings: translator.bindings,
+    })
+}
+
+struct Translator {
+    script: String,
+    bindings: HashMap<String, GValue>,
+    generated: usize,
+}
+
+impl Translator {
+    fn instructions(&mut self, instructions: &[Instruction]) -> GremlinResult<()> {
+        for instruction in instructions {
+            self.script.push('.');
+            self.script.push_str(instruction.operator());
+            self.script.push('(');
+            self.arguments(instruction.args())?;
+            self.script.push(')');
+        }
+        Ok(())
+    }
+
+    fn arguments<'a, I>(&mut self, args: I) -> GremlinResult<()>
+    where
+        I: IntoIterator<Item = &'a GValue>,
+    {
+        for (i, arg) in args.into_iter().enumerate() {
+            if i > 0 {
+                self.script.push_str(", ");
+            }
+            self.value(arg)?;
+        }
+        Ok(())
+    }
+
+    fn value(&mut self, value: &GValue) -> GremlinResult<()> {
+        match value {
+            GValue::Null => self.script.push_str("null"),
+            GValue::Bool(b) => self.script.push_str(if *b { "true" } else { "false" }),
+            GValue::String(s) => self.string(s),
+            GValue::Int32(i) => self.write(format_args!("{}", i)),
+            GValue::Int64(i) => self.write(format_args!("{}L", i)),
+            GValue::Short(i) => self.write(format_args!("{}S", i)),
+            GValue::Byte(i) => self.write(format_args!("{}B", i)),
+            GValue::Float(f) => self.float(f64::from(*f), 'F'),
+            GValue::Double(d) => self.float(*d, 'D'),
+            #[cfg(feature = "big_numbers")]
+            GValue::BigInteger(i) => self.write(format_args!("{}N", i)),
+            #[cfg(feature = "big_numbers")]
+            GValue::BigDecimal(d) => self.write(format_args!("{}M", d)),
+            GValue::Uuid(uuid) => self.write(format_args!("UUID('{}')", uuid)),
+            GValue::Date(date) | GValue::Instant(date) => {
+                self.write(format_args!("datetime('{}')", date.to_rfc3339()))
+            }
+            GValue::OffsetDateTime(date) | GValue::ZonedDateTime(date) => {
+                self.write(format_args!("datetime('{}')", date.to_rfc3339()))
+            }
+            GValue::List(list) => {
+                self.script.push('[');
+                self.arguments(list.iter())?;
+                self.script.push(']');
+            }
+            GValue::Set(set) => {
+                self.script.push('{');
+                self.arguments(set.iter())?;
+                self.script.push('}');
+            }
+            GValue::Map(map) => {
+                if map.is_empty() {
+                    self.script.push_str("[:]");
+                    return Ok(());
+                }
+                self.script.push('[');
+                for (i, (key, value)) in map.iter().enumerate() {
+                    if i > 0 {
+                        self.script.push_str(", ");
+                    }
+                    self.key(key)?;
+                    self.script.push(':');
+                    self.value(value)?;
+                }
+                self.script.push(']');
+            }
+            GValue::Vertex(vertex) => self.id(vertex.id())?,
+            GValue::Edge(edge) => self.id(edge.id())?,
+            GValue::T(t) => self.script.push_str(t_literal(t)),
+            GValue::Token(token) => self.write(format_args!("T.{}", token.value())),
+            GValue::Direction(direction) => self.script.push_str(direction_literal(direction)),
+            GValue::Order(order) => self.script.push_str(match order {
+                Order::Asc => "Order.asc",
+                Order::Desc => "Order.desc",
+                Order::Shuffle => "Order.shuffle",
+            }),
+            GValue::Scope(scope) => self.script.push_str(match scope {
+                Scope::Global => "Scope.global",
+                Scope::Local => "Scope.local",
+            }),
+            GValue::Pop(pop) => self.write(format_args!("Pop.{}", pop)),
+            GValue::Column(column) => self.script.push_str(match column {
+                Column::Keys => "Column.keys",
+                Column::Values => "Column.values",
+            }),
+            GValue::Cardinality(cardinality) => self.script.push_str(match cardinality {
+                Cardinality::List => "Cardinality.list",
+                Cardinality::Set => "Cardinality.set",
+                Cardinality::Single => "Cardinality.single",
+            }),
+            GValue::Merge(merge) => self.script.push_str(match merge {
+                Merge::OnCreate => "Merge.onCreate",
+                Merge::OnMatch => "Merge.onMatch",
+                Merge::OutV => "Merge.outV",
+                Merge::InV => "Merge.inV",
+            }),
+            GValue::P(p) => self.predicate("P", p.operator(), p.value())?,
+            GValue::TextP(p) => self.predicate("TextP", p.operator(), p.value())?,
+            GValue::Bytecode(bytecode) => {
+                self.script.push_str("__");
+                if bytecode.steps().is_empty() {
+                    self.script.push_str(".identity()");
+                }
+                self.instructions(bytecode.steps())?;
+            }
+            GValue::Binding(binding) => {
+                self.script.push_str(binding.key());
+                self.bindings
+                    .insert(binding.key().to_string(), binding.value().clone());
+            }
+            GValue::Lambda(_) => {
+                return Err(GremlinError::Generic(String::from(
+                    "Lambdas are not supported by gremlin-lang",
+                )))
+            }
+            other => {
+                let key = format!("_{}", self.generated);
+                self.generated += 1;
+                self.script.push_str(&key);
+                self.bindings.insert(key, other.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn key(&mut self, key: &GKey) -> GremlinResult<()> {
+        match key {
+            GKey::String(s) => self.string(s),
+            GKey::T(t) => self.write(format_args!("({})", t_literal(t))),
+            GKey::Token(token) => self.write(format_args!("(T.{})", token.value())),
+            GKey::Direction(direction) => {
+                self.write(format_args!("({})", direction_literal(direction)))
+            }
+            GKey::Vertex(vertex) => self.id(vertex.id())?,
+            GKey::Edge(edge) => self.id(edge.id())?,
+        }
+        Ok(())
+    }
+
+    fn id(&mut self, id: &GID) -> GremlinResult<()> {
+        self.value(&id.into())
+    }
+
+    fn predicate(&mut self, kind: &str, operator: &str, value: &GValue) -> GremlinResult<()> {
+        self.write(format_args!("{}.{}(", kind, operator));
+        self.value(value)?;
+        self.script.push(')');
+        Ok(())
+    }
+
+    fn string(&mut self, s: &str) {
+        self.script.push('\'');
+        for c in s.chars() {
+            match c {
+                '\'' => self.script.push_str("\\'"),
+                '\\' => self.script.push_str("\\\\"),
+                '\n' => self.script.push_str("\\n"),
+                '\r' => self.script.push_str("\\r"),
+                '\t' => self.script.push_str("\\t"),
+                c if c.is_control() => self.write(format_args!("\\u{:04x}", c as u32)),
+                c => self.script.push(c),
+            }
+        }
+        self.script.push('\'');
+    }
+
+    fn float(&mut self, f: f64, suffix: char) {
+        if f.is_nan() {
+            self.script.push_str("NaN");
+        } else if f.is_infinite() {
+            self.script
+                .push_str(if f > 0.0 { "Infinity" } else { "-Infinity" });
+        } else {
+            self.write(format_args!("{:?}{}", f, suffix));
+        }
+    }
+
+    fn write(&mut self, args: std::fmt::Arguments) {
+        // Writing to a String cannot fail
+        let _ = self.script.write_fmt(args);
+    }
+}
+
+fn t_literal(t: &T) -> &'static str {
+    match t {
+        T::Id => "T.id",
+        T::Key => "T.key",
+        T::Label => "T.label",
+        T::Value => "T.value",
+    }
+}
+
+fn direction_literal(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Out => "Direction.OUT",
+        Direction::In => "Direction.IN",
+        Direction::From => "Direction.from",
+        Direction::To => "Direction.to",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::translate;
+    use crate::process::traversal::{traversal, Order, __};
+    use crate::structure::{Bindings, TextP, P, T};
+    use crate::GValue;
+
+    #[test]
+    fn it_should_translate_steps_and_literals() {
+        let g = traversal().empty();
+
+        let script = translate(
+            g.v(1)
+                .has_label("person")
+                .has(("name", "O'Brien"))
+                .has(("age", P::within((29_i64, 32_i64))))
+                .has(("city", TextP::containing("ork")))
+                .out_e("knows")
+                .has(("weight", P::gt(0.5)))
+                .order(())
+                .by(("since", Order::Desc))
+                .limit(2)
+                .bytecode(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            "g.V(1).hasLabel('person').has('name', P.eq('O\\'Brien'))\
+             .has('age', P.within([29L, 32L])).has('city', TextP.containing('ork')).outE('knows')\
+             .has('weight', P.gt(0.5D)).order(Scope.global).by('since', Order.desc).limit(2L)",
+            script.gremlin
+        );
+        assert!(script.bindings.is_empty());
+    }
+
+    #[test]
+    fn it_should_translate_anonymous_traversals_and_bindings() {
+        let g = traversal().empty();
+        let mut bytecode = g
+            .v(())
+            .has(("name", Bindings::of("name", "marko")))
+            .where_(__.out("created").count().is(P::gt(1)))
+            .values(T::Id)
+            .bytecode()
+            .clone();
+        bytecode.add_source(String::from("withSideEffect"), vec!["a".into(), 1.into()]);
+
+        let script = translate(&bytecode).unwrap();
+
+        assert_eq!(
+            "g.withSideEffect('a', 1).V().has('name', P.eq(name)).where(__.out('created').count()\
+             .is(P.gt(1))).values(T.id)",
+            script.gremlin
+        );
+        assert_eq!(
+            Some(&GValue::from("marko")),
+            script.bindings.get("name"),
+            "{:?}",
+            script.bindings
+        );
+    }
+}
@@ -9,6 +9,7 @@ mod builder;
 mod bytecode;
 mod graph_traversal;
 mod graph_traversal_source;
+pub(crate) mod gremlin_lang;
 mod order;
 pub(crate) mod remote;
 mod scope;
@@ -3,7 +3,7 @@ use crate::conversion::FromGValue;
 use crate::process::traversal::strategies::TraversalStrategies;
 use crate::process::traversal::RemoteTraversalIterator;
 use crate::process::traversal::{GraphTraversal, GraphTraversalSource};
-use crate::GremlinResult;
+use crate::{GremlinResult, RequestOptions};
 
 #[cfg(feature = "async_gremlin")]
 use crate::aio::GremlinClient as GremlinAsyncClient;
@@ -115,6 +115,13 @@ impl SyncTerminator {
     pub fn new(strategies: TraversalStrategies) -> SyncTerminator {
         SyncTerminator { strategies }
     }
+
+    /// Submit traversals with the given [RequestOptions](RequestOptions)
+    pub fn with_options(&self, options: RequestOptions) -> SyncTerminator {
+        SyncTerminator {
+            strategies: self.strategies.with_options(options),
+        }
+    }
 }
 
 impl<T: FromGValue> Terminator<T> for SyncTerminator {
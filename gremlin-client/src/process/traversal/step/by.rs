@@ -1,5 +1,5 @@
 use crate::process::traversal::{Order, TraversalBuilder};
-use crate::structure::{Column, GValue, T};
+use crate::structure::{Column, GValue, Lambda, T};
 
 pub struct ByStep {
     params: Vec<GValue>,
@@ -65,6 +65,18 @@ impl From<(TraversalBuilder, Order)> for ByStep {
     }
 }
 
+impl From<Lambda> for ByStep {
+    fn from(param: Lambda) -> Self {
+        ByStep::new(vec![param.into()])
+    }
+}
+
+impl From<(Lambda, Order)> for ByStep {
+    fn from(param: (Lambda, Order)) -> Self {
+        ByStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
+
 impl From<TraversalBuilder> for ByStep {
     fn from(param: TraversalBuilder) -> Self {
         ByStep::new(vec![param.bytecode.into()])
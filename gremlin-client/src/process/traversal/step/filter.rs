@@ -0,0 +1,30 @@
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::{GValue, Lambda};
+
+pub struct FilterStep {
+    params: Vec<GValue>,
+}
+
+impl FilterStep {
+    fn new(params: Vec<GValue>) -> Self {
+        FilterStep { params }
+    }
+}
+
+impl From<FilterStep> for Vec<GValue> {
+    fn from(step: FilterStep) -> Self {
+        step.params
+    }
+}
+
+impl From<TraversalBuilder> for FilterStep {
+    fn from(param: TraversalBuilder) -> Self {
+        FilterStep::new(vec![param.bytecode.into()])
+    }
+}
+
+impl From<Lambda> for FilterStep {
+    fn from(param: Lambda) -> Self {
+        FilterStep::new(vec![param.into()])
+    }
+}
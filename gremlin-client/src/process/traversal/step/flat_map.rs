@@ -0,0 +1,30 @@
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::{GValue, Lambda};
+
+pub struct FlatMapStep {
+    params: Vec<GValue>,
+}
+
+impl FlatMapStep {
+    fn new(params: Vec<GValue>) -> Self {
+        FlatMapStep { params }
+    }
+}
+
+impl From<FlatMapStep> for Vec<GValue> {
+    fn from(step: FlatMapStep) -> Self {
+        step.params
+    }
+}
+
+impl From<TraversalBuilder> for FlatMapStep {
+    fn from(param: TraversalBuilder) -> Self {
+        FlatMapStep::new(vec![param.bytecode.into()])
+    }
+}
+
+impl From<Lambda> for FlatMapStep {
+    fn from(param: Lambda) -> Self {
+        FlatMapStep::new(vec![param.into()])
+    }
+}
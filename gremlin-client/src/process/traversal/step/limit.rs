@@ -1,5 +1,5 @@
 use crate::process::traversal::Scope;
-use crate::structure::GValue;
+use crate::structure::{Binding, GValue};
 
 pub struct LimitStep {
     limit: GValue,
@@ -27,6 +27,12 @@ impl From<LimitStep> for Vec<GValue> {
     }
 }
 
+impl From<Binding> for LimitStep {
+    fn from(param: Binding) -> LimitStep {
+        LimitStep::new(param.into(), None)
+    }
+}
+
 impl From<i64> for LimitStep {
     fn from(param: i64) -> LimitStep {
         LimitStep::new(param.into(), None)
@@ -2,6 +2,8 @@ pub mod by;
 pub mod choose;
 pub mod coalesce;
 pub mod dedup;
+pub mod filter;
+pub mod flat_map;
 pub mod from;
 pub mod has;
 pub mod limit;
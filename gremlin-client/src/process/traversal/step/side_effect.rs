@@ -1,3 +1,4 @@
+use crate::structure::Lambda;
 use crate::{process::traversal::TraversalBuilder, GValue};
 
 pub struct SideEffectStep {
@@ -21,3 +22,9 @@ impl From<TraversalBuilder> for SideEffectStep {
         SideEffectStep::new(vec![param.bytecode.into()])
     }
 }
+
+impl From<Lambda> for SideEffectStep {
+    fn from(param: Lambda) -> Self {
+        SideEffectStep::new(vec![param.into()])
+    }
+}
@@ -3,7 +3,7 @@ use crate::conversion::FromGValue;
 use crate::process::traversal::remote::Terminator;
 use crate::process::traversal::GraphTraversal;
 use crate::process::traversal::RemoteTraversalIterator;
-use crate::{GremlinError, GremlinResult};
+use crate::{GremlinError, GremlinResult, RequestOptions};
 pub use remote::RemoteStrategy;
 
 #[derive(Clone)]
@@ -38,6 +38,19 @@ impl TraversalStrategies {
         self.strategies.push(strategy);
     }
 
+    pub(crate) fn with_options(&self, options: RequestOptions) -> TraversalStrategies {
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|strategy| match strategy {
+                TraversalStrategy::Remote(x) => {
+                    TraversalStrategy::Remote(x.with_options(options.clone()))
+                }
+            })
+            .collect();
+        TraversalStrategies { strategies }
+    }
+
     pub(crate) fn apply<S, E: FromGValue, A>(
         &self,
         traversal: &GraphTraversal<S, E, A>,
@@ -3,17 +3,29 @@ use crate::conversion::FromGValue;
 use crate::process::traversal::remote::Terminator;
 use crate::{
     process::traversal::GraphTraversal, process::traversal::RemoteTraversalIterator, GremlinClient,
-    GremlinResult,
+    GremlinResult, RequestOptions,
 };
 
 #[derive(Clone)]
 pub struct RemoteStrategy {
     client: GremlinClient,
+    options: RequestOptions,
 }
 
 impl RemoteStrategy {
     pub fn new(client: GremlinClient) -> RemoteStrategy {
-        RemoteStrategy { client }
+        RemoteStrategy {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Submit traversals with the given [RequestOptions](RequestOptions)
+    pub fn with_options(&self, options: RequestOptions) -> RemoteStrategy {
+        RemoteStrategy {
+            client: self.client.clone(),
+            options,
+        }
     }
 
     pub(crate) fn apply<S, E: FromGValue, A>(
@@ -23,7 +35,9 @@ impl RemoteStrategy {
     where
         A: Terminator<E>,
     {
-        let result = self.client.submit_traversal(traversal.bytecode())?;
+        let result = self
+            .client
+            .submit_traversal(traversal.bytecode(), &self.options)?;
 
         Ok(RemoteTraversalIterator::new(result))
     }
@@ -0,0 +1,156 @@
+use crate::GValue;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// How the server returns the properties of the elements in a result.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MaterializeProperties {
+    /// Elements come with all their properties
+    All,
+    /// Elements only come with their id and label
+    Tokens,
+}
+
+impl MaterializeProperties {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MaterializeProperties::All => "all",
+            MaterializeProperties::Tokens => "tokens",
+        }
+    }
+}
+
+/// Options of a single request, sent as arguments of its message.
+///
+/// They override the server configuration for that request only.
+///
+/// ```
+/// use gremlin_client::{MaterializeProperties, RequestOptions};
+/// use std::time::Duration;
+///
+/// let options = RequestOptions::builder()
+///     .evaluation_timeout(Duration::from_secs(120))
+///     .batch_size(256)
+///     .materialize_properties(MaterializeProperties::Tokens)
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    evaluation_timeout: Option<Duration>,
+    batch_size: Option<i32>,
+    user_agent: Option<String>,
+    materialize_properties: Option<MaterializeProperties>,
+    args: HashMap<String, GValue>,
+}
+
+impl RequestOptions {
+    pub fn builder() -> RequestOptionsBuilder {
+        RequestOptionsBuilder(RequestOptions::default())
+    }
+
+    /// Add the options to the arguments of a request message.
+    ///
+    /// Custom arguments never replace the ones built by the client, such as the script or
+    /// its bindings.
+    pub(crate) fn apply(&self, args: &mut HashMap<String, GValue>) {
+        if let Some(timeout) = self.evaluation_timeout {
+            args.insert(
+                String::from("evaluationTimeout"),
+                GValue::Int64(timeout.as_millis() as i64),
+            );
+        }
+        if let Some(batch_size) = self.batch_size {
+            args.insert(String::from("batchSize"), GValue::Int32(batch_size));
+        }
+        if let Some(user_agent) = &self.user_agent {
+            args.insert(String::from("userAgent"), GValue::from(user_agent.clone()));
+        }
+        if let Some(materialize) = &self.materialize_properties {
+            args.insert(
+                String::from("materializeProperties"),
+                GValue::from(materialize.as_str()),
+            );
+        }
+        for (key, value) in &self.args {
+            args.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+}
+
+pub struct RequestOptionsBuilder(RequestOptions);
+
+impl RequestOptionsBuilder {
+    /// Maximum time the server spends evaluating the request
+    pub fn evaluation_timeout(mut self, timeout: Duration) -> Self {
+        self.0.evaluation_timeout = Some(timeout);
+        self
+    }
+
+    /// Number of results the server sends in each partial response
+    pub fn batch_size(mut self, batch_size: i32) -> Self {
+        self.0.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Identifies the application in the server logs and metrics
+    pub fn user_agent<T>(mut self, user_agent: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.0.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn materialize_properties(mut self, materialize: MaterializeProperties) -> Self {
+        self.0.materialize_properties = Some(materialize);
+        self
+    }
+
+    /// Any other argument understood by the server
+    pub fn arg<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        self.0.args.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn build(self) -> RequestOptions {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MaterializeProperties, RequestOptions};
+    use crate::GValue;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    #[test]
+    fn it_should_add_request_args() {
+        let options = RequestOptions::builder()
+            .evaluation_timeout(Duration::from_secs(2))
+            .batch_size(10)
+            .user_agent("analytics")
+            .materialize_properties(MaterializeProperties::Tokens)
+            .arg("requestTag", "nightly")
+            .arg("gremlin", "g.V().drop()")
+            .build();
+
+        let mut args = HashMap::new();
+        args.insert(String::from("gremlin"), GValue::from("g.V()"));
+        options.apply(&mut args);
+
+        assert_eq!(Some(&GValue::Int64(2000)), args.get("evaluationTimeout"));
+        assert_eq!(Some(&GValue::Int32(10)), args.get("batchSize"));
+        assert_eq!(Some(&GValue::from("analytics")), args.get("userAgent"));
+        assert_eq!(
+            Some(&GValue::from("tokens")),
+            args.get("materializeProperties")
+        );
+        assert_eq!(Some(&GValue::from("nightly")), args.get("requestTag"));
+        assert_eq!(Some(&GValue::from("g.V()")), args.get("gremlin"));
+    }
+}
@@ -0,0 +1,372 @@
+//! AWS Signature Version 4 signing of the WebSocket handshake and of HTTP requests, required by
+//! Amazon Neptune databases with IAM authentication.
+
+use crate::{GremlinError, GremlinResult};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tungstenite::handshake::client::Request;
+use tungstenite::http::Uri;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// The AWS credentials signing the requests.
+#[derive(Clone)]
+pub struct AwsCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl AwsCredentials {
+    pub fn new(access_key_id: &str, secret_access_key: &str) -> AwsCredentials {
+        AwsCredentials {
+            access_key_id: String::from(access_key_id),
+            secret_access_key: String::from(secret_access_key),
+            session_token: None,
+        }
+    }
+
+    /// The token of temporary credentials, such as the ones of an assumed role
+    pub fn session_token(mut self, session_token: &str) -> AwsCredentials {
+        self.session_token = Some(String::from(session_token));
+        self
+    }
+}
+
+impl fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish()
+    }
+}
+
+/// Signs the WebSocket handshake of each new connection, or each request over HTTP, with AWS
+/// Signature Version 4.
+///
+/// ```
+/// use gremlin_client::{AwsCredentials, ConnectionOptions, SigV4Signer};
+///
+/// let signer = SigV4Signer::new(
+///     "us-east-1",
+///     AwsCredentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+/// );
+///
+/// let options = ConnectionOptions::builder()
+///     .host("my-cluster.cluster-abc.us-east-1.neptune.amazonaws.com")
+///     .ssl(true)
+///     .sigv4(signer)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct SigV4Signer {
+    region: String,
+    service: String,
+    credentials: AwsCredentials,
+    clock: Arc<dyn Fn() -> SystemTime + Send + Sync>,
+}
+
+impl SigV4Signer {
+    /// A signer for the `neptune-db` service of the given region.
+    pub fn new(region: &str, credentials: AwsCredentials) -> SigV4Signer {
+        SigV4Signer {
+            region: String::from(region),
+            service: String::from("neptune-db"),
+            credentials,
+            clock: Arc::new(SystemTime::now),
+        }
+    }
+
+    pub fn service(mut self, service: &str) -> SigV4Signer {
+        self.service = String::from(service);
+        self
+    }
+
+    /// The source of the signing time, the system clock by default
+    pub fn clock<F>(mut self, clock: F) -> SigV4Signer
+    where
+        F: Fn() -> SystemTime + Send + Sync + 'static,
+    {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// The headers to add to a request for `uri` with the given body.
+    ///
+    /// `host` and `x-amz-date`, and `x-amz-security-token` with temporary credentials, are
+    /// signed.
+    pub fn sign(
+        &self,
+        method: &str,
+        uri: &str,
+        payload: &[u8],
+    ) -> GremlinResult<Vec<(String, String)>> {
+        let uri: Uri = uri
+            .parse()
+            .map_err(|e: tungstenite::http::uri::InvalidUri| {
+                GremlinError::Generic(e.to_string())
+            })?;
+        let host = uri
+            .authority()
+            .ok_or_else(|| GremlinError::Generic("No Hostname".into()))?
+            .as_str();
+
+        Ok(self
+            .headers(method, host, &uri, payload)
+            .into_iter()
+            .map(|(name, value)| (String::from(name), value))
+            .collect())
+    }
+
+    pub(crate) fn sign_request(&self, mut request: Request) -> GremlinResult<Request> {
+        let host = match request.headers().get("Host") {
+            Some(host) => host
+                .to_str()
+                .map_err(|e| GremlinError::Generic(e.to_string()))?
+                .to_string(),
+            None => request
+                .uri()
+                .authority()
+                .ok_or_else(|| GremlinError::Generic("No Hostname".into()))?
+                .to_string(),
+        };
+        let headers = self.headers(request.method().as_str(), &host, request.uri(), &[]);
+
+        for (name, value) in headers {
+            let value =
+                value
+                    .parse()
+                    .map_err(|e: tungstenite::http::header::InvalidHeaderValue| {
+                        GremlinError::Generic(e.to_string())
+                    })?;
+            request.headers_mut().insert(name, value);
+        }
+        Ok(request)
+    }
+
+    fn headers(
+        &self,
+        method: &str,
+        host: &str,
+        uri: &Uri,
+        payload: &[u8],
+    ) -> Vec<(&'static str, String)> {
+        let secs = (self.clock)()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        let time: DateTime<Utc> = DateTime::from_timestamp(secs as i64, 0).unwrap_or_default();
+        let amz_date = time.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = &amz_date[..8];
+
+        let mut signed = vec![("host", host.to_string()), ("x-amz-date", amz_date.clone())];
+        if let Some(token) = &self.credentials.session_token {
+            signed.push(("x-amz-security-token", token.clone()));
+        }
+        let canonical_headers: String = signed
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let signed_headers = signed
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+
+        let canonical_request = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            method,
+            canonical_path(uri.path()),
+            canonical_query(uri.query().unwrap_or("")),
+            canonical_headers,
+            signed_headers,
+            hex(&Sha256::digest(payload))
+        );
+        let scope = format!("{}/{}/{}/aws4_request", date, self.region, self.service);
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            ALGORITHM,
+            amz_date,
+            scope,
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let key = format!("AWS4{}", self.credentials.secret_access_key);
+        let key = hmac(key.as_bytes(), date.as_bytes());
+        let key = hmac(&key, self.region.as_bytes());
+        let key = hmac(&key, self.service.as_bytes());
+        let key = hmac(&key, b"aws4_request");
+        let signature = hex(&hmac(&key, string_to_sign.as_bytes()));
+
+        let mut headers = vec![("X-Amz-Date", amz_date.clone())];
+        if let Some(token) = &self.credentials.session_token {
+            headers.push(("X-Amz-Security-Token", token.clone()));
+        }
+        headers.push((
+            "Authorization",
+            format!(
+                "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+                ALGORITHM, self.credentials.access_key_id, scope, signed_headers, signature
+            ),
+        ));
+        headers
+    }
+}
+
+impl fmt::Debug for SigV4Signer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SigV4Signer")
+            .field("region", &self.region)
+            .field("service", &self.service)
+            .field("credentials", &self.credentials)
+            .finish()
+    }
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// Each segment is encoded again, as every service but S3 expects
+fn canonical_path(path: &str) -> String {
+    if path.is_empty() {
+        return String::from("/");
+    }
+    let mut encoded = String::with_capacity(path.len());
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+fn canonical_query(query: &str) -> String {
+    let mut params: Vec<(&str, &str)> = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| match param.find('=') {
+            Some(i) => (&param[..i], &param[i + 1..]),
+            None => (param, ""),
+        })
+        .collect();
+    params.sort_unstable();
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AwsCredentials, SigV4Signer};
+    use crate::ConnectionOptions;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    // The credentials, region, service and time of the AWS Signature Version 4 test suite
+    fn signer() -> SigV4Signer {
+        SigV4Signer::new(
+            "us-east-1",
+            AwsCredentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+        )
+        .service("service")
+        .clock(|| UNIX_EPOCH + Duration::from_secs(1440938160))
+    }
+
+    fn authorization(method: &str, uri: &str) -> String {
+        let headers = signer().sign(method, uri, b"").unwrap();
+        assert_eq!(
+            (String::from("X-Amz-Date"), String::from("20150830T123600Z")),
+            headers[0]
+        );
+        headers[1].1.clone()
+    }
+
+    #[test]
+    fn it_should_match_the_signing_test_suite() {
+        assert_eq!(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31",
+            authorization("GET", "https://example.amazonaws.com/")
+        );
+        assert_eq!(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500",
+            authorization(
+                "GET",
+                "https://example.amazonaws.com/?Param2=value2&Param1=value1"
+            )
+        );
+        assert_eq!(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b",
+            authorization("POST", "https://example.amazonaws.com/")
+        );
+    }
+
+    #[test]
+    fn it_should_sign_the_session_token() {
+        let signer = SigV4Signer::new(
+            "us-east-1",
+            AwsCredentials::new("AKIDEXAMPLE", "secret").session_token("token"),
+        );
+
+        let headers = signer
+            .sign("GET", "wss://example.amazonaws.com:8182/gremlin", b"")
+            .unwrap();
+
+        assert_eq!(
+            (String::from("X-Amz-Security-Token"), String::from("token")),
+            headers[1]
+        );
+        assert!(
+            headers[2]
+                .1
+                .contains("SignedHeaders=host;x-amz-date;x-amz-security-token"),
+            "{:?}",
+            headers
+        );
+    }
+
+    #[test]
+    fn it_should_sign_each_handshake() {
+        let now = Arc::new(AtomicU64::new(1440938160));
+        let clock = now.clone();
+        let options = ConnectionOptions::builder()
+            .host("example.amazonaws.com")
+            .sigv4(signer().clock(move || {
+                SystemTime::UNIX_EPOCH + Duration::from_secs(clock.load(Ordering::SeqCst))
+            }))
+            .build();
+
+        let first = options.websocket_request().unwrap();
+        now.fetch_add(60, Ordering::SeqCst);
+        let second = options.websocket_request().unwrap();
+
+        assert_eq!("20150830T123600Z", first.headers()["X-Amz-Date"]);
+        assert_eq!("20150830T123700Z", second.headers()["X-Amz-Date"]);
+        assert_ne!(
+            first.headers()["Authorization"],
+            second.headers()["Authorization"]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::GValue;
+
+/// A named argument of a traversal, serialized as `g:Binding`.
+///
+/// The server caches compiled traversals by their bytecode. Passing the arguments that change
+/// between requests as bindings lets those requests share the cached traversal.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Binding {
+    key: String,
+    value: Box<GValue>,
+}
+
+impl Binding {
+    pub fn new<K, V>(key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        Binding {
+            key: key.into(),
+            value: Box::new(value.into()),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> &GValue {
+        &self.value
+    }
+}
+
+/// Creates [Binding](Binding)s for the arguments of a traversal.
+///
+/// ```
+/// use gremlin_client::process::traversal::traversal;
+/// use gremlin_client::structure::Bindings;
+/// # use gremlin_client::GremlinClient;
+///
+/// # fn build(client: GremlinClient) {
+/// let g = traversal().with_remote(client);
+///
+/// let person = g
+///     .v(())
+///     .has(("name", Bindings::of("name", "marko")))
+///     .limit(Bindings::of("limit", 1_i64));
+/// # }
+/// ```
+pub struct Bindings;
+
+impl Bindings {
+    pub fn of<K, V>(key: K, value: V) -> Binding
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        Binding::new(key, value)
+    }
+}
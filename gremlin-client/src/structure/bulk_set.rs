@@ -0,0 +1,65 @@
+use crate::GValue;
+use std::convert::TryFrom;
+
+/// A set that keeps how many times each element was added, as returned by `aggregate` or
+/// `store` side effects.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BulkSet {
+    items: Vec<(GValue, i64)>,
+}
+
+impl BulkSet {
+    pub fn new(items: Vec<(GValue, i64)>) -> Self {
+        BulkSet { items }
+    }
+
+    /// The number of distinct elements.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The number of elements counting their bulks.
+    pub fn size(&self) -> i64 {
+        self.items.iter().map(|(_, bulk)| bulk).sum()
+    }
+
+    /// Returns the bulk of an element, 0 when it's not in the set.
+    pub fn bulk(&self, value: &GValue) -> i64 {
+        self.items
+            .iter()
+            .find(|(v, _)| v == value)
+            .map_or(0, |(_, bulk)| *bulk)
+    }
+
+    /// Iterates over the distinct elements with their bulks.
+    pub fn iter(&self) -> impl Iterator<Item = (&GValue, i64)> {
+        self.items.iter().map(|(value, bulk)| (value, *bulk))
+    }
+
+    /// Iterates over the elements, each repeated as many times as its bulk.
+    pub fn iter_expanded(&self) -> impl Iterator<Item = &GValue> {
+        self.items.iter().flat_map(|(value, bulk)| {
+            std::iter::repeat_n(value, usize::try_from(*bulk).unwrap_or(0))
+        })
+    }
+
+    /// Consumes the set into its elements, each repeated as many times as its bulk.
+    pub fn into_expanded(self) -> impl Iterator<Item = GValue> {
+        self.items.into_iter().flat_map(|(value, bulk)| {
+            std::iter::repeat_n(value, usize::try_from(bulk).unwrap_or(0))
+        })
+    }
+}
+
+impl IntoIterator for BulkSet {
+    type Item = (GValue, i64);
+    type IntoIter = std::vec::IntoIter<(GValue, i64)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
@@ -0,0 +1,557 @@
+//! Deserialization of [GValue](GValue)s into types implementing `serde::Deserialize`.
+//!
+//! Graph elements are read as maps, with their properties flattened next to the `id` and the
+//! `label`. A property with many values is read as a sequence. A ByteBuffer is read as bytes, or
+//! as a sequence of `u8` by types such as `Vec<u8>` that ask for one.
+
+use crate::io::temporal;
+use crate::structure::{Direction, Edge, GKey, GValue, List, Vertex, VertexProperty, T};
+use crate::{GremlinError, ToGValue};
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, Unexpected, VariantAccess,
+    Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+fn token_name(t: &T) -> &'static str {
+    match t {
+        T::Id => "id",
+        T::Key => "key",
+        T::Label => "label",
+        T::Value => "value",
+    }
+}
+
+fn direction_name(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Out | Direction::From => "OUT",
+        Direction::In | Direction::To => "IN",
+    }
+}
+
+fn vertex_entries(vertex: Vertex) -> Vec<(GKey, GValue)> {
+    let mut entries = vec![
+        (GKey::from("id"), vertex.id().to_gvalue()),
+        (GKey::from("label"), GValue::from(vertex.label().clone())),
+    ];
+    for (key, mut properties) in vertex {
+        let value = if properties.len() == 1 {
+            vertex_property_value(properties.remove(0))
+        } else {
+            GValue::List(List::new(
+                properties.into_iter().map(vertex_property_value).collect(),
+            ))
+        };
+        entries.push((GKey::String(key), value));
+    }
+    entries
+}
+
+fn edge_entries(edge: Edge) -> Vec<(GKey, GValue)> {
+    let mut entries = vec![
+        (GKey::from("id"), edge.id().to_gvalue()),
+        (GKey::from("label"), GValue::from(edge.label().clone())),
+        (GKey::from("inV"), edge.in_v().id().to_gvalue()),
+        (GKey::from("outV"), edge.out_v().id().to_gvalue()),
+    ];
+    for (key, property) in edge {
+        entries.push((GKey::String(key), property.value().clone()));
+    }
+    entries
+}
+
+fn vertex_property_value(property: VertexProperty) -> GValue {
+    property.value().clone()
+}
+
+fn visit_map<'de, V, I>(entries: I, visitor: V) -> Result<V::Value, GremlinError>
+where
+    V: Visitor<'de>,
+    I: IntoIterator<Item = (GKey, GValue)>,
+{
+    let mut map = MapDeserializer::new(entries.into_iter());
+    let value = visitor.visit_map(&mut map)?;
+    map.end()?;
+    Ok(value)
+}
+
+fn visit_seq<'de, V, I>(values: I, visitor: V) -> Result<V::Value, GremlinError>
+where
+    V: Visitor<'de>,
+    I: IntoIterator<Item = GValue>,
+{
+    let mut seq = SeqDeserializer::new(values.into_iter());
+    let value = visitor.visit_seq(&mut seq)?;
+    seq.end()?;
+    Ok(value)
+}
+
+impl<'de> Deserializer<'de> for GValue {
+    type Error = GremlinError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            GValue::Null => visitor.visit_unit(),
+            GValue::Bool(v) => visitor.visit_bool(v),
+            GValue::Byte(v) => visitor.visit_i8(v),
+            GValue::Short(v) => visitor.visit_i16(v),
+            GValue::Int32(v) => visitor.visit_i32(v),
+            GValue::Int64(v) => visitor.visit_i64(v),
+            GValue::Float(v) => visitor.visit_f32(v),
+            GValue::Double(v) => visitor.visit_f64(v),
+            #[cfg(feature = "big_numbers")]
+            GValue::BigInteger(v) => {
+                use bigdecimal::ToPrimitive;
+                match (v.to_i64(), v.to_i128()) {
+                    (Some(v), _) => visitor.visit_i64(v),
+                    (None, Some(v)) => visitor.visit_i128(v),
+                    _ => visitor.visit_string(v.to_string()),
+                }
+            }
+            #[cfg(feature = "big_numbers")]
+            GValue::BigDecimal(v) => visitor.visit_string(v.to_string()),
+            GValue::String(v) => visitor.visit_string(v),
+            GValue::Char(v) => visitor.visit_char(v),
+            GValue::Bytes(v) => visitor.visit_byte_buf(v),
+            GValue::Uuid(v) => visitor.visit_string(v.to_string()),
+            GValue::Date(v) | GValue::Instant(v) => {
+                visitor.visit_string(temporal::format_instant(&v))
+            }
+            GValue::OffsetDateTime(v) | GValue::ZonedDateTime(v) => {
+                visitor.visit_string(temporal::format_offset_date_time(&v))
+            }
+            GValue::LocalDate(v) => visitor.visit_string(v.to_string()),
+            GValue::LocalDateTime(v) => visitor.visit_string(temporal::format_local_date_time(&v)),
+            GValue::Duration(v) => visitor.visit_string(temporal::format_duration(&v)),
+            GValue::Year(v) => visitor.visit_i32(v),
+            GValue::T(t) => visitor.visit_str(token_name(&t)),
+            GValue::Direction(direction) => visitor.visit_str(direction_name(&direction)),
+            GValue::Token(token) => visitor.visit_string(token.value().clone()),
+            GValue::List(list) => visit_seq(list, visitor),
+            GValue::Set(set) => visit_seq(set, visitor),
+            GValue::BulkSet(set) => visit_seq(set.into_expanded(), visitor),
+            GValue::Path(path) => visit_seq(path.objects().clone(), visitor),
+            GValue::Map(map) => visit_map(map, visitor),
+            GValue::Tree(tree) => visit_map(
+                tree.into_iter()
+                    .map(|(key, tree)| Ok((key.try_into_key()?, GValue::Tree(tree))))
+                    .collect::<Result<Vec<_>, GremlinError>>()?,
+                visitor,
+            ),
+            GValue::Vertex(vertex) => visit_map(vertex_entries(vertex), visitor),
+            GValue::Edge(edge) => visit_map(edge_entries(edge), visitor),
+            GValue::VertexProperty(property) => {
+                vertex_property_value(property).deserialize_any(visitor)
+            }
+            GValue::Property(property) => property.value().clone().deserialize_any(visitor),
+            other => Err(de::Error::custom(format!("Cannot deserialize {:?}", other))),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            GValue::Null => visitor.visit_none(),
+            other => visitor.visit_some(other),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            GValue::Map(map) => {
+                let mut entries = map.into_iter();
+                match (entries.next(), entries.next()) {
+                    (Some((variant, value)), None) => visitor.visit_enum(EnumDeserializer {
+                        variant,
+                        value: Some(value),
+                    }),
+                    _ => Err(de::Error::invalid_value(
+                        Unexpected::Map,
+                        &"a map with a single key",
+                    )),
+                }
+            }
+            GValue::VertexProperty(property) => {
+                vertex_property_value(property).deserialize_enum(name, variants, visitor)
+            }
+            GValue::Property(property) => property
+                .value()
+                .clone()
+                .deserialize_enum(name, variants, visitor),
+            other => {
+                let variant = match other {
+                    GValue::String(v) => GKey::String(v),
+                    GValue::T(t) => GKey::T(t),
+                    GValue::Direction(d) => GKey::Direction(d),
+                    GValue::Token(t) => GKey::Token(t),
+                    other => {
+                        return Err(de::Error::invalid_type(
+                            Unexpected::Other(&format!("{:?}", other)),
+                            &"a string or a map with a single key",
+                        ))
+                    }
+                };
+                visitor.visit_enum(EnumDeserializer {
+                    variant,
+                    value: None,
+                })
+            }
+        }
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            GValue::Bytes(v) => visit_seq(v.into_iter().map(|b| GValue::Short(b.into())), visitor),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, GremlinError> for GValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl GValue {
+    fn try_into_key(self) -> Result<GKey, GremlinError> {
+        match self {
+            GValue::String(v) => Ok(GKey::String(v)),
+            GValue::T(t) => Ok(GKey::T(t)),
+            GValue::Token(t) => Ok(GKey::Token(t)),
+            GValue::Vertex(v) => Ok(GKey::Vertex(v)),
+            GValue::Edge(e) => Ok(GKey::Edge(e)),
+            GValue::Direction(d) => Ok(GKey::Direction(d)),
+            other => Err(de::Error::custom(format!(
+                "Cannot use {:?} as a map key",
+                other
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for GKey {
+    type Error = GremlinError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            GKey::String(v) => visitor.visit_string(v),
+            GKey::T(t) => visitor.visit_str(token_name(&t)),
+            GKey::Token(token) => visitor.visit_string(token.value().clone()),
+            GKey::Direction(direction) => visitor.visit_str(direction_name(&direction)),
+            GKey::Vertex(vertex) => GValue::Vertex(vertex).deserialize_any(visitor),
+            GKey::Edge(edge) => GValue::Edge(edge).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(EnumDeserializer {
+            variant: self,
+            value: None,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, GremlinError> for GKey {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+struct EnumDeserializer {
+    variant: GKey,
+    value: Option<GValue>,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer {
+    type Error = GremlinError;
+    type Variant = VariantDeserializer;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.variant)?;
+        Ok((variant, VariantDeserializer { value: self.value }))
+    }
+}
+
+struct VariantDeserializer {
+    value: Option<GValue>,
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer {
+    type Error = GremlinError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        match self.value {
+            None | Some(GValue::Null) => Ok(()),
+            Some(value) => de::Deserialize::deserialize(value),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.value {
+            Some(value) => seed.deserialize(value),
+            None => Err(de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"newtype variant",
+            )),
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(value) => Deserializer::deserialize_seq(value, visitor),
+            None => Err(de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"tuple variant",
+            )),
+        }
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(value) => Deserializer::deserialize_map(value, visitor),
+            None => Err(de::Error::invalid_type(
+                Unexpected::UnitVariant,
+                &"struct variant",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::structure::{GKey, GValue, List, Map, Vertex, VertexProperty};
+    use crate::GID;
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Role {
+        Admin,
+        Guest(String),
+        Member { since: i32 },
+        Pair(i32, i32),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Person {
+        name: String,
+        age: u8,
+        nickname: Option<String>,
+        tags: Vec<String>,
+        scores: BTreeMap<String, f64>,
+        roles: Vec<Role>,
+    }
+
+    fn map(entries: Vec<(&str, GValue)>) -> GValue {
+        let entries: HashMap<GKey, GValue> = entries
+            .into_iter()
+            .map(|(k, v)| (GKey::from(k), v))
+            .collect();
+        GValue::Map(Map::from(entries))
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut scores = BTreeMap::new();
+        scores.insert(String::from("math"), 7.5);
+
+        let person = Person {
+            name: String::from("marko"),
+            age: 29,
+            nickname: None,
+            tags: vec![String::from("a"), String::from("b")],
+            scores,
+            roles: vec![
+                Role::Admin,
+                Role::Guest(String::from("lop")),
+                Role::Member { since: 2010 },
+                Role::Pair(1, 2),
+            ],
+        };
+
+        let value = GValue::from_serde(&person).expect("Failed to serialize");
+        assert_eq!(
+            value.clone().take::<Map>().unwrap()["age"],
+            GValue::Short(29)
+        );
+        assert_eq!(
+            value.clone().take::<Map>().unwrap()["nickname"],
+            GValue::Null
+        );
+        assert_eq!(value.take_serde::<Person>().unwrap(), person);
+    }
+
+    #[test]
+    fn test_server_values() {
+        let value = map(vec![
+            ("name", GValue::from("marko")),
+            ("age", GValue::Int32(29)),
+            (
+                "tags",
+                GValue::List(List::new(vec![GValue::from("a"), GValue::from("b")])),
+            ),
+            ("scores", map(vec![("math", GValue::Float(7.5))])),
+            (
+                "roles",
+                GValue::List(List::new(vec![
+                    GValue::from("Admin"),
+                    map(vec![("Guest", GValue::from("lop"))]),
+                ])),
+            ),
+        ]);
+
+        let person = value.take_serde::<Person>().expect("Failed to deserialize");
+        assert_eq!(person.age, 29);
+        assert_eq!(person.nickname, None);
+        assert_eq!(person.scores["math"], 7.5);
+        assert_eq!(
+            person.roles,
+            vec![Role::Admin, Role::Guest(String::from("lop"))]
+        );
+
+        let value = map(vec![("name", GValue::Int32(1))]);
+        assert!(value.take_serde::<Person>().is_err());
+
+        let value = GValue::Int32(-1);
+        assert!(value.take_serde::<u8>().is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        struct Buffer(Vec<u8>);
+
+        impl serde::Serialize for Buffer {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(&self.0)
+            }
+        }
+
+        let value = GValue::from_serde(&Buffer(vec![1, 2, 255])).expect("Failed to serialize");
+        assert_eq!(value, GValue::Bytes(vec![1, 2, 255]));
+        assert_eq!(value.take_serde::<Vec<u8>>().unwrap(), vec![1, 2, 255]);
+
+        let value = GValue::from_serde(&vec![1u8, 2, 255]).expect("Failed to serialize");
+        assert_eq!(
+            value,
+            GValue::List(List::new(vec![
+                GValue::Short(1),
+                GValue::Short(2),
+                GValue::Short(255)
+            ]))
+        );
+        assert_eq!(value.take_serde::<Vec<u8>>().unwrap(), vec![1, 2, 255]);
+    }
+
+    #[test]
+    fn test_vertex() {
+        #[derive(Debug, Deserialize)]
+        struct Software {
+            id: i64,
+            label: String,
+            name: String,
+            lang: Vec<String>,
+        }
+
+        let mut properties = HashMap::new();
+        properties.insert(
+            String::from("name"),
+            vec![VertexProperty::new(GID::Int64(1), "name", "lop")],
+        );
+        properties.insert(
+            String::from("lang"),
+            vec![
+                VertexProperty::new(GID::Int64(2), "lang", "java"),
+                VertexProperty::new(GID::Int64(3), "lang", "groovy"),
+            ],
+        );
+        let vertex = Vertex::new(GID::Int64(3), "software", properties);
+
+        let software = GValue::Vertex(vertex)
+            .take_serde::<Software>()
+            .expect("Failed to deserialize a Vertex");
+        assert_eq!(software.id, 3);
+        assert_eq!(software.label, "software");
+        assert_eq!(software.name, "lop");
+        assert_eq!(software.lang, vec!["java", "groovy"]);
+    }
+}
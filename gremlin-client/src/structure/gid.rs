@@ -1,18 +1,25 @@
-use crate::{GremlinError, GremlinResult};
+use crate::structure::Binding;
+use crate::{GValue, GremlinError, GremlinResult, ToGValue};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
-pub struct GIDs(pub(crate) Vec<GID>);
+pub struct GIDs(pub(crate) Vec<GValue>);
 
 impl<T: Into<GID>> From<T> for GIDs {
     fn from(val: T) -> GIDs {
-        GIDs(vec![val.into()])
+        GIDs(vec![val.into().to_gvalue()])
     }
 }
 
 impl<T: Into<GID>> From<Vec<T>> for GIDs {
     fn from(val: Vec<T>) -> GIDs {
-        GIDs(val.into_iter().map(|gid| gid.into()).collect())
+        GIDs(val.into_iter().map(|gid| gid.into().to_gvalue()).collect())
+    }
+}
+
+impl From<Binding> for GIDs {
+    fn from(val: Binding) -> GIDs {
+        GIDs(vec![val.into()])
     }
 }
 
@@ -0,0 +1,52 @@
+static GREMLIN_GROOVY: &str = "gremlin-groovy";
+
+/// A script evaluated by the server in place of a traversal argument, e.g. in `map` or `by`.
+///
+/// Lambdas must be enabled on the server, see the
+/// [reference](https://tinkerpop.apache.org/docs/current/reference/#a-note-on-lambdas).
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lambda {
+    script: String,
+    language: String,
+    arguments: i32,
+}
+
+impl Lambda {
+    /// A Groovy closure, e.g. `Lambda::new("it.get().value('name').length()")`.
+    pub fn new<S>(script: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Lambda::with_language(script, GREMLIN_GROOVY)
+    }
+
+    pub fn with_language<S, L>(script: S, language: L) -> Self
+    where
+        S: Into<String>,
+        L: Into<String>,
+    {
+        Lambda {
+            script: script.into(),
+            language: language.into(),
+            arguments: -1,
+        }
+    }
+
+    /// Sets the number of arguments the script takes, -1 (the default) lets the server decide.
+    pub fn arguments(mut self, arguments: i32) -> Self {
+        self.arguments = arguments;
+        self
+    }
+
+    pub fn script(&self) -> &str {
+        &self.script
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn arguments_length(&self) -> i32 {
+        self.arguments
+    }
+}
@@ -32,6 +32,18 @@ impl IntermediateRepr {
             category,
         }
     }
+
+    pub fn traversal(&self) -> &Vec<String> {
+        &self.traversal
+    }
+
+    pub fn strategy(&self) -> &String {
+        &self.strategy
+    }
+
+    pub fn category(&self) -> &String {
+        &self.category
+    }
 }
 impl TraversalExplanation {
     pub fn new(
@@ -100,6 +112,10 @@ impl Metric {
     pub fn traversers(&self) -> &i64 {
         &self.traversers
     }
+
+    pub fn nested(&self) -> &Vec<Metric> {
+        &self.nested
+    }
 }
 
 impl Metric {
@@ -1,10 +1,14 @@
+mod binding;
+mod bulk_set;
 mod cardinality;
 mod column;
+mod de;
 mod direction;
 mod edge;
 mod either;
 mod gid;
 mod label;
+mod lambda;
 mod list;
 mod macros;
 mod map;
@@ -16,15 +20,19 @@ mod path;
 mod pop;
 mod property;
 mod result;
+mod ser;
 mod set;
 mod t;
 mod text_p;
 mod token;
 mod traverser;
+mod tree;
 mod value;
 mod vertex;
 mod vertex_property;
 
+pub use self::binding::{Binding, Bindings};
+pub use self::bulk_set::BulkSet;
 pub use self::edge::Edge;
 pub use self::gid::{GIDs, GID};
 pub use self::list::List;
@@ -32,7 +40,7 @@ pub use self::metrics::{IntermediateRepr, Metric, TraversalExplanation, Traversa
 pub use self::null::Null;
 pub use self::path::Path;
 pub use self::property::Property;
-pub use self::result::GResultSet;
+pub use self::result::{GResultSet, ResponseFrame};
 pub use self::set::Set;
 pub use self::token::Token;
 pub use self::value::GValue;
@@ -43,6 +51,7 @@ pub use column::Column;
 pub use direction::Direction;
 pub use either::*;
 pub use label::Labels;
+pub use lambda::Lambda;
 pub use map::{GKey, Map};
 pub use merge::Merge;
 pub use p::{IntoPredicate, P};
@@ -50,3 +59,4 @@ pub use pop::Pop;
 pub use t::T;
 pub use text_p::TextP;
 pub use traverser::Traverser;
+pub use tree::{Tree, Walk};
@@ -14,6 +14,10 @@ impl Path {
         }
     }
 
+    pub fn labels(&self) -> &GValue {
+        &self.labels
+    }
+
     pub fn objects(&self) -> &List {
         &self.objects
     }
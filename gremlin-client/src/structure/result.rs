@@ -1,15 +1,45 @@
 use crate::message::Response;
 use crate::pool::GremlinConnectionManager;
-use crate::structure::GValue;
+use crate::structure::{GValue, Map};
 use crate::{GremlinClient, GremlinResult};
 use r2d2::PooledConnection;
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The metadata of a response message.
+///
+/// A result streamed in partial (206) responses is read one frame per message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseFrame {
+    status_attributes: Map,
+    result_meta: Map,
+}
+
+impl ResponseFrame {
+    pub(crate) fn new(response: &Response) -> ResponseFrame {
+        ResponseFrame {
+            status_attributes: response.status.attributes.clone(),
+            result_meta: response.result.meta.clone(),
+        }
+    }
+
+    /// Status attributes of the frame, such as warnings or the host that served the request.
+    pub fn status_attributes(&self) -> &Map {
+        &self.status_attributes
+    }
+
+    /// Result meta of the frame, such as side-effect keys or provider specific fields.
+    pub fn result_meta(&self) -> &Map {
+        &self.result_meta
+    }
+}
 
 #[derive(Debug)]
 pub struct GResultSet {
     client: GremlinClient,
     results: VecDeque<GValue>,
     response: Response,
+    frames: Vec<ResponseFrame>,
     conn: PooledConnection<GremlinConnectionManager>,
 }
 
@@ -23,14 +53,36 @@ impl GResultSet {
         GResultSet {
             client,
             results,
+            frames: vec![ResponseFrame::new(&response)],
             response,
             conn,
         }
     }
 
+    /// Status attributes of the last response frame read, see
+    /// [ResponseFrame::status_attributes](ResponseFrame::status_attributes).
+    pub fn status_attributes(&self) -> &Map {
+        &self.response.status.attributes
+    }
+
+    /// Result meta of the last response frame read, see
+    /// [ResponseFrame::result_meta](ResponseFrame::result_meta).
+    pub fn result_meta(&self) -> &Map {
+        &self.response.result.meta
+    }
+
+    /// Every response frame read so far, in order.
+    ///
+    /// The next partial (206) frame is only read once the results of the current one are
+    /// consumed.
+    pub fn frames(&self) -> &[ResponseFrame] {
+        &self.frames
+    }
+
     fn fetch(&mut self) -> GremlinResult<()> {
         if self.results.is_empty() && self.response.status.code == 206 {
             let (response, resuts) = self.client.read_response(&mut self.conn)?;
+            self.frames.push(ResponseFrame::new(&response));
             self.response = response;
             self.results = resuts;
         }
@@ -38,6 +90,45 @@ impl GResultSet {
     }
 }
 
+// Frames read when a result set is dropped before its last response, and the time the server has
+// to send them. Past either limit the connection is discarded by the pool instead, rather than
+// blocking the caller.
+const DRAIN_BUDGET: usize = 8;
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl Drop for GResultSet {
+    // The remaining frames would otherwise be read by the next request on this connection
+    fn drop(&mut self) {
+        if self.response.status.code != 206 {
+            return;
+        }
+        let deadline = Instant::now() + DRAIN_TIMEOUT;
+        let mut budget = DRAIN_BUDGET;
+        while self.response.status.code == 206 {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if budget == 0 || remaining.is_zero() {
+                self.conn.mark_broken();
+                return;
+            }
+            budget -= 1;
+            let response = self
+                .conn
+                .set_read_timeout(Some(remaining))
+                .and_then(|_| self.conn.recv());
+            match response {
+                Ok(response) => self.response = response,
+                Err(_) => {
+                    self.conn.mark_broken();
+                    return;
+                }
+            }
+        }
+        if self.conn.set_read_timeout(None).is_err() {
+            self.conn.mark_broken();
+        }
+    }
+}
+
 impl Iterator for GResultSet {
     type Item = GremlinResult<GValue>;
 
@@ -48,3 +139,127 @@ impl Iterator for GResultSet {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{DRAIN_BUDGET, DRAIN_TIMEOUT};
+    use crate::test_support::{response, serve};
+    use crate::{ConnectionOptions, GValue, GremlinClient};
+    use serde_json::json;
+    use std::time::Instant;
+    use tungstenite::Message;
+
+    // A server streaming `partial` partial responses to the scripts mentioning "first"
+    fn serve_partial(partial: usize) -> u16 {
+        serve(move |request| {
+            let id = request["requestId"].as_str().unwrap();
+            let script = request["args"].to_string();
+            let frame = |code, value: &str, frame: usize| -> Message {
+                let attributes = json!({ "frame": frame.to_string() });
+                response(
+                    id,
+                    code,
+                    json!([value]),
+                    attributes,
+                    json!({ "value": value }),
+                )
+            };
+
+            if script.contains("first") {
+                let mut frames: Vec<_> = (0..partial).map(|i| frame(206, "first", i)).collect();
+                frames.push(frame(200, "first", partial));
+                frames
+            } else if script.contains("stall") {
+                // The rest of the response never comes
+                vec![frame(206, "stall", 0)]
+            } else if script.contains("second") {
+                vec![frame(200, "second", 0)]
+            } else {
+                vec![frame(200, "0", 0)]
+            }
+        })
+    }
+
+    fn client(port: u16) -> GremlinClient {
+        GremlinClient::connect(
+            ConnectionOptions::builder()
+                .host("127.0.0.1")
+                .port(port)
+                .pool_size(1)
+                .build(),
+        )
+        .unwrap()
+    }
+
+    fn first_then_second(client: &GremlinClient) -> Vec<GValue> {
+        let mut results = client.execute("g.inject('first')", &[]).unwrap();
+        assert_eq!(GValue::from("first"), results.next().unwrap().unwrap());
+        drop(results);
+
+        client
+            .execute("g.inject('second')", &[])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_keep_the_metadata_of_every_frame() {
+        let client = client(serve_partial(2));
+
+        let mut results = client.execute("g.inject('first')", &[]).unwrap();
+        assert_eq!(3, results.by_ref().count());
+
+        let frames: Vec<_> = results
+            .frames()
+            .iter()
+            .map(|frame| {
+                (
+                    frame.status_attributes().get("frame").cloned(),
+                    frame.result_meta().get("value").cloned(),
+                )
+            })
+            .collect();
+        let expected: Vec<_> = ["0", "1", "2"]
+            .iter()
+            .map(|frame| (Some(GValue::from(*frame)), Some(GValue::from("first"))))
+            .collect();
+        assert_eq!(expected, frames);
+        assert_eq!(
+            Some(&GValue::from("2")),
+            results.status_attributes().get("frame")
+        );
+    }
+
+    #[test]
+    fn it_should_drain_unread_responses_on_drop() {
+        let client = client(serve_partial(2));
+
+        assert_eq!(vec![GValue::from("second")], first_then_second(&client));
+    }
+
+    #[test]
+    fn it_should_not_block_on_drop_when_the_server_stalls() {
+        let client = client(serve_partial(0));
+
+        let mut results = client.execute("g.inject('stall')", &[]).unwrap();
+        assert_eq!(GValue::from("stall"), results.next().unwrap().unwrap());
+        let start = Instant::now();
+        drop(results);
+        assert!(start.elapsed() < DRAIN_TIMEOUT * 2);
+
+        let second: Vec<_> = client
+            .execute("g.inject('second')", &[])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(vec![GValue::from("second")], second);
+    }
+
+    #[test]
+    fn it_should_discard_connections_past_the_drain_budget() {
+        let client = client(serve_partial(DRAIN_BUDGET * 4));
+
+        assert_eq!(vec![GValue::from("second")], first_then_second(&client));
+    }
+}
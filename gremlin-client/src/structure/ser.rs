@@ -0,0 +1,525 @@
+//! Serialization of types implementing `serde::Serialize` into [GValue](GValue)s.
+//!
+//! Structs and maps become [Map](Map)s, sequences and tuples become [List](List)s and enums are
+//! externally tagged, the same way `serde_json` lays them out. Unsigned integers are widened to
+//! the smallest signed type that holds them, since Java has no unsigned types.
+//!
+//! Serde writes a `Vec<u8>` as a sequence, so it becomes a List of Shorts. Use a type that calls
+//! `serialize_bytes`, such as `serde_bytes::ByteBuf`, to send a ByteBuffer instead. Either form
+//! deserializes back into a `Vec<u8>`.
+
+use crate::structure::{GKey, GValue, List, Map};
+use crate::GremlinError;
+use serde::ser::{self, Impossible, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+pub(crate) struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeVariant<SerializeList>;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeVariant<SerializeMap>;
+
+    fn serialize_bool(self, v: bool) -> Result<GValue, GremlinError> {
+        Ok(GValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<GValue, GremlinError> {
+        Ok(GValue::Byte(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<GValue, GremlinError> {
+        Ok(GValue::Short(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<GValue, GremlinError> {
+        Ok(GValue::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<GValue, GremlinError> {
+        Ok(GValue::Int64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<GValue, GremlinError> {
+        Ok(GValue::Short(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<GValue, GremlinError> {
+        Ok(GValue::Int32(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<GValue, GremlinError> {
+        Ok(GValue::Int64(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<GValue, GremlinError> {
+        match i64::try_from(v) {
+            Ok(v) => Ok(GValue::Int64(v)),
+            #[cfg(feature = "big_numbers")]
+            Err(_) => Ok(GValue::BigInteger(v.into())),
+            #[cfg(not(feature = "big_numbers"))]
+            Err(_) => Err(ser::Error::custom(format!(
+                "{} is out of range for a Long",
+                v
+            ))),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<GValue, GremlinError> {
+        Ok(GValue::Float(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<GValue, GremlinError> {
+        Ok(GValue::Double(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<GValue, GremlinError> {
+        Ok(GValue::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<GValue, GremlinError> {
+        Ok(GValue::String(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<GValue, GremlinError> {
+        Ok(GValue::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<GValue, GremlinError> {
+        Ok(GValue::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<GValue, GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<GValue, GremlinError> {
+        Ok(GValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<GValue, GremlinError> {
+        Ok(GValue::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<GValue, GremlinError> {
+        Ok(GValue::String(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<GValue, GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<GValue, GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(tagged(variant, value.serialize(Serializer)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList, GremlinError> {
+        Ok(SerializeList(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList, GremlinError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, GremlinError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeList>, GremlinError> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, GremlinError> {
+        Ok(SerializeMap {
+            entries: HashMap::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeMap, GremlinError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeMap>, GremlinError> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+fn tagged(variant: &str, value: GValue) -> GValue {
+    let mut entries = HashMap::with_capacity(1);
+    entries.insert(GKey::from(variant), value);
+    GValue::Map(Map::from(entries))
+}
+
+pub(crate) struct SerializeList(Vec<GValue>);
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.0.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<GValue, GremlinError> {
+        Ok(GValue::List(List::new(self.0)))
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<GValue, GremlinError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<GValue, GremlinError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+pub(crate) struct SerializeMap {
+    entries: HashMap<GKey, GValue>,
+    key: Option<GKey>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = self.key.take().ok_or_else(|| {
+            <GremlinError as ser::Error>::custom("serialize_value called before serialize_key")
+        })?;
+        self.entries.insert(key, value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<GValue, GremlinError> {
+        Ok(GValue::Map(Map::from(self.entries)))
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.entries
+            .insert(GKey::from(key), value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<GValue, GremlinError> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+pub(crate) struct SerializeVariant<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeList> {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeSeq::serialize_element(&mut self.inner, value)
+    }
+
+    fn end(self) -> Result<GValue, GremlinError> {
+        Ok(tagged(self.variant, ser::SerializeSeq::end(self.inner)?))
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeMap> {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<GValue, GremlinError> {
+        Ok(tagged(self.variant, ser::SerializeMap::end(self.inner)?))
+    }
+}
+
+/// Map keys must be strings, numbers and chars are written as strings like `serde_json` does.
+struct KeySerializer;
+
+fn key_must_be_a_string() -> GremlinError {
+    ser::Error::custom("map keys must be strings")
+}
+
+impl ser::Serializer for KeySerializer {
+    type Ok = GKey;
+    type Error = GremlinError;
+
+    type SerializeSeq = Impossible<GKey, GremlinError>;
+    type SerializeTuple = Impossible<GKey, GremlinError>;
+    type SerializeTupleStruct = Impossible<GKey, GremlinError>;
+    type SerializeTupleVariant = Impossible<GKey, GremlinError>;
+    type SerializeMap = Impossible<GKey, GremlinError>;
+    type SerializeStruct = Impossible<GKey, GremlinError>;
+    type SerializeStructVariant = Impossible<GKey, GremlinError>;
+
+    fn serialize_bool(self, v: bool) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<GKey, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<GKey, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<GKey, GremlinError> {
+        Ok(GKey::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<GKey, GremlinError> {
+        Ok(GKey::from(v))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<GKey, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_none(self) -> Result<GKey, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<GKey, GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit(self) -> Result<GKey, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<GKey, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<GKey, GremlinError> {
+        Ok(GKey::from(variant))
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<GKey, GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<GKey, GremlinError>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, GremlinError> {
+        Err(key_must_be_a_string())
+    }
+}
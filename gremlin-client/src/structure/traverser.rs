@@ -15,6 +15,14 @@ impl Traverser {
         }
     }
 
+    pub fn bulk(&self) -> i64 {
+        self.bulk
+    }
+
+    pub fn value(&self) -> &GValue {
+        &self.value
+    }
+
     pub fn take<T>(self) -> GremlinResult<T>
     where
         T: FromGValue,
@@ -0,0 +1,97 @@
+use crate::structure::{GValue, GID};
+
+/// The result of the `tree()` step, a recursive map from an element to the subtree of the
+/// elements that were traversed after it.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Tree {
+    branches: Vec<(GValue, Tree)>,
+}
+
+impl Tree {
+    pub fn new(branches: Vec<(GValue, Tree)>) -> Self {
+        Tree { branches }
+    }
+
+    pub fn len(&self) -> usize {
+        self.branches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty()
+    }
+
+    /// Iterates over the roots of this tree and their subtrees.
+    pub fn iter(&self) -> impl Iterator<Item = (&GValue, &Tree)> {
+        self.branches.iter().map(|(key, tree)| (key, tree))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &GValue> {
+        self.branches.iter().map(|(key, _)| key)
+    }
+
+    /// Returns the subtree of a root of this tree.
+    pub fn get(&self, key: &GValue) -> Option<&Tree> {
+        self.branches
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, tree)| tree)
+    }
+
+    /// Searches the whole tree, depth first, for the vertex, edge or vertex property with the
+    /// given id and returns it together with its subtree.
+    pub fn get_by_id(&self, id: &GID) -> Option<(&GValue, &Tree)> {
+        self.walk()
+            .find(|(_, key, _)| element_id(key) == Some(id))
+            .map(|(_, key, tree)| (key, tree))
+    }
+
+    /// Walks the tree depth first, yielding every node with its depth, the roots being at depth 0.
+    pub fn walk(&self) -> Walk<'_> {
+        Walk {
+            stack: self.branches.iter().rev().map(|b| (0, b)).collect(),
+        }
+    }
+}
+
+fn element_id(value: &GValue) -> Option<&GID> {
+    match value {
+        GValue::Vertex(v) => Some(v.id()),
+        GValue::Edge(e) => Some(e.id()),
+        GValue::VertexProperty(vp) => Some(vp.id()),
+        _ => None,
+    }
+}
+
+/// Depth first iterator over a [Tree](Tree), see [Tree::walk](Tree::walk).
+pub struct Walk<'a> {
+    stack: Vec<(usize, &'a (GValue, Tree))>,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (usize, &'a GValue, &'a Tree);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, (key, tree)) = self.stack.pop()?;
+        self.stack
+            .extend(tree.branches.iter().rev().map(|b| (depth + 1, b)));
+        Some((depth, key, tree))
+    }
+}
+
+impl IntoIterator for Tree {
+    type Item = (GValue, Tree);
+    type IntoIter = std::vec::IntoIter<(GValue, Tree)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.branches.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Tree {
+    type Item = &'a (GValue, Tree);
+    type IntoIter = std::slice::Iter<'a, (GValue, Tree)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.branches.iter()
+    }
+}
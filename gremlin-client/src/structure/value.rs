@@ -2,8 +2,9 @@ use crate::conversion::{BorrowFromGValue, FromGValue};
 use crate::process::traversal::{Bytecode, Order, Scope, TraversalBuilder};
 use crate::structure::traverser::Traverser;
 use crate::structure::{
-    label::LabelType, Cardinality, Edge, GKey, IntermediateRepr, List, Map, Metric, Path, Property,
-    Set, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty,
+    label::LabelType, Binding, BulkSet, Cardinality, Edge, GKey, IntermediateRepr, Lambda, List,
+    Map, Metric, Path, Property, Set, Token, TraversalExplanation, TraversalMetrics, Tree, Vertex,
+    VertexProperty,
 };
 use crate::structure::{Pop, TextP, P, T};
 use crate::{GremlinError, GremlinResult, ToGValue, GID};
@@ -23,17 +24,33 @@ pub enum GValue {
     VertexProperty(VertexProperty),
     Property(Property),
     Uuid(uuid::Uuid),
+    Byte(i8),
+    Short(i16),
     Int32(i32),
     Int64(i64),
     Float(f32),
     Double(f64),
+    #[cfg(feature = "big_numbers")]
+    BigInteger(num_bigint::BigInt),
+    #[cfg(feature = "big_numbers")]
+    BigDecimal(bigdecimal::BigDecimal),
     Date(Date),
+    Duration(chrono::Duration),
+    Instant(chrono::DateTime<chrono::Utc>),
+    LocalDate(chrono::NaiveDate),
+    LocalDateTime(chrono::NaiveDateTime),
+    OffsetDateTime(chrono::DateTime<chrono::FixedOffset>),
+    /// The region id of the zone is not kept, only its offset.
+    ZonedDateTime(chrono::DateTime<chrono::FixedOffset>),
+    Year(i32),
     List(List),
     Set(Set),
+    BulkSet(BulkSet),
     Map(Map),
     Token(Token),
     String(String),
     Path(Path),
+    Tree(Tree),
     TraversalMetrics(TraversalMetrics),
     Metric(Metric),
     TraversalExplanation(TraversalExplanation),
@@ -41,10 +58,14 @@ pub enum GValue {
     P(P),
     T(T),
     Bytecode(Bytecode),
+    Lambda(Lambda),
+    Binding(Binding),
     Traverser(Traverser),
     Scope(Scope),
     Order(Order),
     Bool(bool),
+    Char(char),
+    Bytes(Vec<u8>),
     TextP(TextP),
     Pop(Pop),
     Cardinality(Cardinality),
@@ -67,6 +88,26 @@ impl GValue {
     {
         T::from_gvalue(self)
     }
+
+    /// Deserializes this value into any type implementing `serde::Deserialize`.
+    ///
+    /// Maps and structs are read from [Map](Map)s, sequences from lists and sets, and graph
+    /// elements from a map of their id, label and property values.
+    pub fn take_serde<T>(self) -> GremlinResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        T::deserialize(self)
+    }
+
+    /// Converts any type implementing `serde::Serialize` into a value, e.g. to pass it as a
+    /// property or as a script binding.
+    pub fn from_serde<T>(value: &T) -> GremlinResult<GValue>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        value.serialize(super::ser::Serializer)
+    }
 }
 
 impl From<Date> for GValue {
@@ -75,6 +116,30 @@ impl From<Date> for GValue {
     }
 }
 
+impl From<chrono::Duration> for GValue {
+    fn from(val: chrono::Duration) -> Self {
+        GValue::Duration(val)
+    }
+}
+
+impl From<chrono::NaiveDate> for GValue {
+    fn from(val: chrono::NaiveDate) -> Self {
+        GValue::LocalDate(val)
+    }
+}
+
+impl From<chrono::NaiveDateTime> for GValue {
+    fn from(val: chrono::NaiveDateTime) -> Self {
+        GValue::LocalDateTime(val)
+    }
+}
+
+impl From<chrono::DateTime<chrono::FixedOffset>> for GValue {
+    fn from(val: chrono::DateTime<chrono::FixedOffset>) -> Self {
+        GValue::OffsetDateTime(val)
+    }
+}
+
 impl From<String> for GValue {
     fn from(val: String) -> Self {
         GValue::String(val)
@@ -87,6 +152,18 @@ impl From<&String> for GValue {
     }
 }
 
+impl From<i8> for GValue {
+    fn from(val: i8) -> Self {
+        GValue::Byte(val)
+    }
+}
+
+impl From<i16> for GValue {
+    fn from(val: i16) -> Self {
+        GValue::Short(val)
+    }
+}
+
 impl From<i32> for GValue {
     fn from(val: i32) -> Self {
         GValue::Int32(val)
@@ -117,12 +194,39 @@ impl From<f64> for GValue {
     }
 }
 
+#[cfg(feature = "big_numbers")]
+impl From<num_bigint::BigInt> for GValue {
+    fn from(val: num_bigint::BigInt) -> Self {
+        GValue::BigInteger(val)
+    }
+}
+
+#[cfg(feature = "big_numbers")]
+impl From<bigdecimal::BigDecimal> for GValue {
+    fn from(val: bigdecimal::BigDecimal) -> Self {
+        GValue::BigDecimal(val)
+    }
+}
+
 impl<'a> From<&'a str> for GValue {
     fn from(val: &'a str) -> Self {
         GValue::String(String::from(val))
     }
 }
 
+impl From<char> for GValue {
+    fn from(val: char) -> Self {
+        GValue::Char(val)
+    }
+}
+
+// Only borrowed bytes convert, a From<Vec<u8>> would make `vec![x.into()]` ambiguous
+impl From<&[u8]> for GValue {
+    fn from(val: &[u8]) -> Self {
+        GValue::Bytes(val.to_vec())
+    }
+}
+
 impl From<Vertex> for GValue {
     fn from(val: Vertex) -> Self {
         GValue::Vertex(val)
@@ -140,6 +244,30 @@ impl From<Path> for GValue {
         GValue::Path(val)
     }
 }
+impl From<BulkSet> for GValue {
+    fn from(val: BulkSet) -> Self {
+        GValue::BulkSet(val)
+    }
+}
+
+impl From<Binding> for GValue {
+    fn from(val: Binding) -> Self {
+        GValue::Binding(val)
+    }
+}
+
+impl From<Lambda> for GValue {
+    fn from(val: Lambda) -> Self {
+        GValue::Lambda(val)
+    }
+}
+
+impl From<Tree> for GValue {
+    fn from(val: Tree) -> Self {
+        GValue::Tree(val)
+    }
+}
+
 impl From<Edge> for GValue {
     fn from(val: Edge) -> Self {
         GValue::Edge(val)
@@ -368,6 +496,11 @@ impl std::convert::TryFrom<GValue> for i64 {
     fn try_from(value: GValue) -> GremlinResult<Self> {
         match value {
             GValue::Int64(s) => Ok(s),
+            GValue::Int32(s) => Ok(s.into()),
+            GValue::Short(s) => Ok(s.into()),
+            GValue::Byte(s) => Ok(s.into()),
+            #[cfg(feature = "big_numbers")]
+            GValue::BigInteger(_) => <i64 as FromGValue>::from_gvalue(value),
             GValue::List(s) => from_list(s),
             GValue::VertexProperty(vp) => vp.take(),
             GValue::Property(p) => p.take(),
@@ -402,6 +535,7 @@ impl std::convert::TryFrom<GValue> for Date {
     fn try_from(value: GValue) -> GremlinResult<Self> {
         match value {
             GValue::Date(date) => Ok(date),
+            GValue::Instant(date) => Ok(date),
             GValue::List(s) => from_list(s),
             GValue::VertexProperty(vp) => vp.take(),
             GValue::Property(p) => p.take(),
@@ -453,6 +587,10 @@ impl std::convert::TryFrom<GValue> for f64 {
     fn try_from(value: GValue) -> GremlinResult<Self> {
         match value {
             GValue::Double(x) => Ok(x),
+            GValue::Float(x) => Ok(x.into()),
+            GValue::Int32(x) => Ok(x.into()),
+            GValue::Short(x) => Ok(x.into()),
+            GValue::Byte(x) => Ok(x.into()),
             GValue::List(s) => from_list(s),
             GValue::VertexProperty(vp) => vp.take(),
             GValue::Property(p) => p.take(),
@@ -650,6 +788,7 @@ macro_rules! impl_try_from_list {
             fn try_from(value: GValue) -> GremlinResult<Self> {
                 match value {
                     GValue::List(s) => for_list(&s),
+                    GValue::BulkSet(s) => s.into_expanded().map(|x| x.try_into()).collect(),
                     GValue::Null => Ok(Vec::new()),
                     _ => Err(GremlinError::Cast(format!(
                         "Cannot cast {:?} to Vec",
@@ -665,6 +804,7 @@ macro_rules! impl_try_from_list {
             fn try_from(value: &GValue) -> GremlinResult<Self> {
                 match value {
                     GValue::List(s) => for_list(s),
+                    GValue::BulkSet(s) => s.iter_expanded().map(|x| x.clone().try_into()).collect(),
                     GValue::Null => Ok(Vec::new()),
                     _ => Err(GremlinError::Cast(format!(
                         "Cannot cast {:?} to Vec",
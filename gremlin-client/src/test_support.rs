@@ -0,0 +1,102 @@
+//! An in-process Gremlin Server for the unit tests.
+
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Read};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use tungstenite::{Message, WebSocket};
+
+/// Start a server answering every GraphSON request with the messages returned by `respond`.
+///
+/// Any number of connections is accepted, each one on its own thread. Returns the port the
+/// server listens on.
+pub(crate) fn serve<F>(respond: F) -> u16
+where
+    F: Fn(&Value) -> Vec<Message> + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let respond = Arc::new(respond);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut websocket = match stream.map(tungstenite::accept) {
+                Ok(Ok(websocket)) => websocket,
+                _ => continue,
+            };
+            let respond = respond.clone();
+            thread::spawn(move || {
+                while let Ok(message) = websocket.read() {
+                    let request = match read_request(&message.into_data()) {
+                        Some(request) => request,
+                        None => continue,
+                    };
+                    for message in respond(&request) {
+                        if websocket.send(message).is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+    });
+    port
+}
+
+/// Start a server accepting a single connection and running `exchange` on its side of it.
+///
+/// Returns the port the server listens on and the handle of the server thread.
+pub(crate) fn serve_once<F>(exchange: F) -> (u16, JoinHandle<()>)
+where
+    F: FnOnce(&mut WebSocket<TcpStream>) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut websocket = tungstenite::accept(stream).unwrap();
+        exchange(&mut websocket);
+    });
+    (port, server)
+}
+
+// Requests are prefixed by their mime type
+fn read_request(data: &[u8]) -> Option<Value> {
+    let start = data.iter().position(|b| *b == b'{')?;
+    serde_json::from_slice(&data[start..]).ok()
+}
+
+/// A GraphSON response message carrying `data` as a list of strings.
+pub(crate) fn response(
+    request_id: &str,
+    code: i16,
+    data: Value,
+    attributes: Value,
+    meta: Value,
+) -> Message {
+    let response = json!({
+        "requestId": request_id,
+        "status": {"message": "", "code": code, "attributes": attributes},
+        "result": {"data": {"@type": "g:List", "@value": data}, "meta": meta}
+    });
+    Message::Binary(response.to_string().into_bytes())
+}
+
+/// Read an HTTP request off `stream`, returning its head.
+pub(crate) fn read_http_request(stream: &mut BufReader<TcpStream>) -> String {
+    let mut head = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).unwrap();
+        if line == "\r\n" {
+            break;
+        }
+        if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+            content_length = len.trim().parse().unwrap();
+        }
+        head.push_str(&line);
+    }
+    stream.read_exact(&mut vec![0; content_length]).unwrap();
+    head
+}
@@ -11,7 +11,10 @@ pub fn assert_map_property(element_map: &Map, expected_key: &str, expected_value
 
 #[allow(dead_code)]
 pub mod io {
-    use gremlin_client::{ConnectionOptions, Edge, GraphSON, GremlinClient, GremlinResult, Vertex};
+    use gremlin_client::{
+        ConnectionOptions, Edge, GraphSON, GremlinClient, GremlinResult, IoProtocol, Protocol,
+        Vertex,
+    };
 
     pub fn connect() -> GremlinResult<GremlinClient> {
         GremlinClient::connect(("localhost", 8182))
@@ -21,19 +24,23 @@ pub mod io {
         GremlinClient::connect(("localhost", 8184))
     }
 
-    pub fn connect_serializer(serializer: GraphSON) -> GremlinResult<GremlinClient> {
+    pub fn connect_serializer<T: Into<IoProtocol>>(serializer: T) -> GremlinResult<GremlinClient> {
+        let serializer = serializer.into();
         let port = match serializer {
-            GraphSON::V2 => 8182,
-            GraphSON::V3 => 8182,
+            IoProtocol::GraphSON(GraphSON::V1) => 8182,
+            IoProtocol::GraphSON(GraphSON::V2) => 8182,
+            IoProtocol::GraphSON(GraphSON::V3) => 8182,
+            IoProtocol::GraphBinaryV1 => 8182,
+            IoProtocol::GraphBinaryV4 => 8185,
         };
-        GremlinClient::connect(
-            ConnectionOptions::builder()
-                .host("localhost")
-                .port(port)
+        let builder = ConnectionOptions::builder().host("localhost").port(port);
+        let builder = match serializer {
+            IoProtocol::GraphBinaryV4 => builder.protocol(Protocol::TinkerPop4),
+            serializer => builder
                 .serializer(serializer.clone())
-                .deserializer(serializer)
-                .build(),
-        )
+                .deserializer(serializer),
+        };
+        GremlinClient::connect(builder.build())
     }
 
     pub fn expect_client() -> GremlinClient {
@@ -44,7 +51,7 @@ pub mod io {
         connect_janusgraph_client().expect("It should connect")
     }
 
-    pub fn expect_client_serializer(serializer: GraphSON) -> GremlinClient {
+    pub fn expect_client_serializer<T: Into<IoProtocol>>(serializer: T) -> GremlinClient {
         connect_serializer(serializer).expect("It should connect")
     }
 
@@ -54,7 +61,7 @@ pub mod io {
         client
     }
 
-    pub fn graph_serializer(serializer: GraphSON) -> GremlinClient {
+    pub fn graph_serializer<T: Into<IoProtocol>>(serializer: T) -> GremlinClient {
         let client = expect_client_serializer(serializer);
 
         client
@@ -112,7 +119,9 @@ pub mod io {
 pub mod aio {
     use gremlin_client::aio::GremlinClient;
 
-    use gremlin_client::{ConnectionOptions, Edge, GraphSON, GremlinResult, Vertex};
+    use gremlin_client::{
+        ConnectionOptions, Edge, GraphSON, GremlinResult, IoProtocol, Protocol, Vertex,
+    };
 
     #[cfg(feature = "async-std-runtime")]
     use async_std::prelude::*;
@@ -126,21 +135,25 @@ pub mod aio {
             .expect("It should connect")
     }
 
-    pub async fn connect_serializer(serializer: GraphSON) -> GremlinClient {
+    pub async fn connect_serializer<T: Into<IoProtocol>>(serializer: T) -> GremlinClient {
+        let serializer = serializer.into();
         let port = match serializer {
-            GraphSON::V2 => 8182,
-            GraphSON::V3 => 8182,
+            IoProtocol::GraphSON(GraphSON::V1) => 8182,
+            IoProtocol::GraphSON(GraphSON::V2) => 8182,
+            IoProtocol::GraphSON(GraphSON::V3) => 8182,
+            IoProtocol::GraphBinaryV1 => 8182,
+            IoProtocol::GraphBinaryV4 => 8185,
         };
-        GremlinClient::connect(
-            ConnectionOptions::builder()
-                .host("localhost")
-                .port(port)
+        let builder = ConnectionOptions::builder().host("localhost").port(port);
+        let builder = match serializer {
+            IoProtocol::GraphBinaryV4 => builder.protocol(Protocol::TinkerPop4),
+            serializer => builder
                 .serializer(serializer.clone())
-                .deserializer(serializer)
-                .build(),
-        )
-        .await
-        .expect("It should connect")
+                .deserializer(serializer),
+        };
+        GremlinClient::connect(builder.build())
+            .await
+            .expect("It should connect")
     }
 
     pub async fn create_vertex(graph: &GremlinClient, name: &str) -> Vertex {
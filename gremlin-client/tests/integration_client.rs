@@ -2,11 +2,12 @@ mod common;
 
 use std::collections::HashSet;
 use std::iter::FromIterator;
+use std::time::Duration;
 
 use chrono::{offset::TimeZone, DateTime, Utc};
 use gremlin_client::{
-    ConnectionOptions, GremlinClient, GremlinError, List, TlsOptions, ToGValue,
-    TraversalExplanation, TraversalMetrics, VertexProperty,
+    ConnectionOptions, GremlinClient, GremlinError, List, RequestOptions, ResponseStatusCode,
+    TlsOptions, ToGValue, Transport, TraversalExplanation, TraversalMetrics, VertexProperty,
 };
 use gremlin_client::{Edge, GValue, Map, Vertex};
 
@@ -28,6 +29,44 @@ fn test_empty_query() {
     )
 }
 
+#[test]
+fn test_status_attributes() {
+    let mut results = graph()
+        .execute("g.inject(1, 2, 3)", &[])
+        .expect("It should execute a traversal");
+
+    assert_eq!(3, results.by_ref().count());
+    assert!(results.status_attributes().get("host").is_some());
+}
+
+#[test]
+fn test_http_transport() {
+    let graph = GremlinClient::connect(
+        ConnectionOptions::builder()
+            .host("localhost")
+            .port(8182)
+            .transport(Transport::Http)
+            .build(),
+    )
+    .expect("It should connect");
+
+    let vertices = graph
+        .execute(
+            "g.V().hasLabel('person').has('name',name)",
+            &[("name", &"marko")],
+        )
+        .expect("It should execute a query")
+        .filter_map(Result::ok)
+        .map(|f| f.take::<Vertex>())
+        .collect::<Result<Vec<Vertex>, _>>()
+        .expect("It should be ok");
+
+    assert_eq!("person", vertices[0].label());
+
+    let result = graph.execute("g.V().hasLabel('NotFound')", &[]);
+    assert_eq!(0, result.expect("It should execute a traversal").count());
+}
+
 #[test]
 fn test_session_empty_query() {
     let mut graph = graph();
@@ -54,9 +93,7 @@ fn test_ok_credentials() {
             .port(8183)
             .credentials("stephen", "password")
             .ssl(true)
-            .tls_options(TlsOptions {
-                accept_invalid_certs: true,
-            })
+            .tls_options(TlsOptions::builder().accept_invalid_certs(true).build())
             .build(),
     )
     .expect("Cannot connect");
@@ -73,9 +110,7 @@ fn test_ko_credentials() {
             .port(8183)
             .credentials("stephen", "pwd")
             .ssl(true)
-            .tls_options(TlsOptions {
-                accept_invalid_certs: true,
-            })
+            .tls_options(TlsOptions::builder().accept_invalid_certs(true).build())
             .build(),
     )
     .expect("Cannot connect");
@@ -91,9 +126,33 @@ fn test_wrong_query() {
         .expect_err("it should return an error");
 
     match error {
-        GremlinError::Request((code, message)) => {
-            assert_eq!(597, code);
-            assert_eq!("No such property: V for class: org.apache.tinkerpop.gremlin.process.traversal.dsl.graph.GraphTraversalSource",message)
+        GremlinError::Server {
+            code,
+            message,
+            exceptions,
+            ..
+        } => {
+            assert_eq!(ResponseStatusCode::ServerErrorEvaluation, code);
+            assert_eq!("No such property: V for class: org.apache.tinkerpop.gremlin.process.traversal.dsl.graph.GraphTraversalSource",message);
+            assert!(!exceptions.is_empty());
+        }
+        _ => panic!("wrong error type"),
+    }
+}
+
+#[test]
+fn test_evaluation_timeout() {
+    let options = RequestOptions::builder()
+        .evaluation_timeout(Duration::from_millis(100))
+        .build();
+    let error = graph()
+        .execute_with_options("Thread.sleep(2000); g.V()", &[], &options)
+        .expect_err("it should time out");
+
+    assert!(error.is_timeout());
+    match error {
+        GremlinError::Server { code, .. } => {
+            assert_eq!(ResponseStatusCode::ServerErrorTimeout, code)
         }
         _ => panic!("wrong error type"),
     }
@@ -107,8 +166,11 @@ fn test_wrong_alias() {
         .expect_err("it should return an error");
 
     match error {
-        GremlinError::Request((code, message)) => {
-            assert_eq!(499, code);
+        GremlinError::Server { code, message, .. } => {
+            assert_eq!(
+                ResponseStatusCode::RequestErrorInvalidRequestArguments,
+                code
+            );
             assert_eq!("Could not alias [g] to [foo] as [foo] not in the Graph or TraversalSource global bindings",message)
         }
         _ => panic!("wrong error type"),
@@ -4,7 +4,10 @@ mod common;
 #[allow(unused_imports)]
 mod aio {
 
-    use gremlin_client::{aio::GremlinClient, ConnectionOptions, GremlinError, TlsOptions};
+    use gremlin_client::{
+        aio::GremlinClient, ConnectionOptions, GremlinError, Protocol, ResponseStatusCode,
+        TlsOptions, Transport,
+    };
     use gremlin_client::{Edge, GValue, Map, Vertex};
 
     use super::common::aio::{connect, create_edge, create_vertex, drop_vertices};
@@ -20,6 +23,61 @@ mod aio {
         connect().await;
     }
 
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn test_http_transport() {
+        let client = GremlinClient::connect(
+            ConnectionOptions::builder()
+                .host("localhost")
+                .port(8182)
+                .transport(Transport::Http)
+                .build(),
+        )
+        .await
+        .expect("Cannot connect");
+
+        let results = client
+            .execute(
+                "g.V().hasLabel('person').has('name',name)",
+                &[("name", &"marko")],
+            )
+            .await
+            .expect("It should execute a query")
+            .filter_map(Result::ok)
+            .map(|f| f.take::<Vertex>())
+            .collect::<Result<Vec<Vertex>, _>>()
+            .await
+            .expect("It should be ok");
+
+        assert_eq!("person", results[0].label());
+    }
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn test_tinkerpop4_protocol() {
+        let client = GremlinClient::connect(
+            ConnectionOptions::builder()
+                .host("localhost")
+                .port(8185)
+                .protocol(Protocol::TinkerPop4)
+                .build(),
+        )
+        .await
+        .expect("Cannot connect");
+
+        let values: Vec<GValue> = (0..5000_i64).map(GValue::from).collect();
+
+        let results = client
+            .execute("g.inject(values).unfold()", &[("values", &values)])
+            .await
+            .expect("It should execute a query")
+            .collect::<Result<Vec<GValue>, _>>()
+            .await
+            .expect("It should be ok");
+
+        assert_eq!(values, results);
+    }
+
     #[cfg_attr(feature = "async-std-runtime", async_std::test)]
     #[cfg_attr(feature = "tokio-runtime", tokio::test)]
     async fn test_ok_credentials() {
@@ -29,9 +87,7 @@ mod aio {
                 .port(8183)
                 .credentials("stephen", "password")
                 .ssl(true)
-                .tls_options(TlsOptions {
-                    accept_invalid_certs: true,
-                })
+                .tls_options(TlsOptions::builder().accept_invalid_certs(true).build())
                 .build(),
         )
         .await
@@ -147,8 +203,8 @@ mod aio {
             .expect_err("it should return an error");
 
         match error {
-            GremlinError::Request((code, message)) => {
-                assert_eq!(597, code);
+            GremlinError::Server { code, message, .. } => {
+                assert_eq!(ResponseStatusCode::ServerErrorEvaluation, code);
                 assert_eq!("No such property: V for class: org.apache.tinkerpop.gremlin.process.traversal.dsl.graph.GraphTraversalSource",message)
             }
             _ => panic!("wrong error type"),
@@ -166,8 +222,11 @@ mod aio {
             .expect_err("it should return an error");
 
         match error {
-            GremlinError::Request((code, message)) => {
-                assert_eq!(499, code);
+            GremlinError::Server { code, message, .. } => {
+                assert_eq!(
+                    ResponseStatusCode::RequestErrorInvalidRequestArguments,
+                    code
+                );
                 assert_eq!("Could not alias [g] to [foo] as [foo] not in the Graph or TraversalSource global bindings",message)
             }
             _ => panic!("wrong error type"),
@@ -4,8 +4,8 @@ mod common;
 #[cfg(feature = "async_gremlin")]
 mod aio {
 
-    use gremlin_client::GremlinError;
     use gremlin_client::{Edge, GValue, GraphSON, Map, Vertex};
+    use gremlin_client::{GremlinError, ResponseStatusCode};
 
     use super::common::aio::{connect_serializer, create_edge, create_vertex};
     #[cfg(feature = "async-std-runtime")]
@@ -46,8 +46,8 @@ mod aio {
             .expect_err("it should return an error");
 
         match error {
-            GremlinError::Request((code, message)) => {
-                assert_eq!(597, code);
+            GremlinError::Server { code, message, .. } => {
+                assert_eq!(ResponseStatusCode::ServerErrorEvaluation, code);
                 assert_eq!("No such property: V for class: org.apache.tinkerpop.gremlin.process.traversal.dsl.graph.GraphTraversalSource",message)
             }
             _ => panic!("wrong error type"),
@@ -65,8 +65,11 @@ mod aio {
             .expect_err("it should return an error");
 
         match error {
-            GremlinError::Request((code, message)) => {
-                assert_eq!(499, code);
+            GremlinError::Server { code, message, .. } => {
+                assert_eq!(
+                    ResponseStatusCode::RequestErrorInvalidRequestArguments,
+                    code
+                );
                 assert_eq!("Could not alias [g] to [foo] as [foo] not in the Graph or TraversalSource global bindings",message)
             }
             _ => panic!("wrong error type"),
@@ -1,8 +1,8 @@
 mod common;
 
 use gremlin_client::{
-    ConnectionOptions, GraphSON, GremlinClient, GremlinError, List, TlsOptions, ToGValue,
-    TraversalExplanation, TraversalMetrics, VertexProperty,
+    ConnectionOptions, GraphSON, GremlinClient, GremlinError, List, ResponseStatusCode, TlsOptions,
+    ToGValue, TraversalExplanation, TraversalMetrics, VertexProperty,
 };
 use gremlin_client::{Edge, GKey, GValue, Map, Vertex, GID};
 
@@ -32,9 +32,7 @@ fn test_ok_credentials_v2() {
             .port(8183)
             .credentials("stephen", "password")
             .ssl(true)
-            .tls_options(TlsOptions {
-                accept_invalid_certs: true,
-            })
+            .tls_options(TlsOptions::builder().accept_invalid_certs(true).build())
             .serializer(GraphSON::V2)
             .build(),
     )
@@ -52,9 +50,7 @@ fn test_ko_credentials_v2() {
             .port(8183)
             .credentials("stephen", "pwd")
             .ssl(true)
-            .tls_options(TlsOptions {
-                accept_invalid_certs: true,
-            })
+            .tls_options(TlsOptions::builder().accept_invalid_certs(true).build())
             .serializer(GraphSON::V2)
             .build(),
     )
@@ -71,8 +67,8 @@ fn test_wrong_query_v2() {
         .expect_err("it should return an error");
 
     match error {
-        GremlinError::Request((code, message)) => {
-            assert_eq!(597, code);
+        GremlinError::Server { code, message, .. } => {
+            assert_eq!(ResponseStatusCode::ServerErrorEvaluation, code);
             assert_eq!("No such property: V for class: org.apache.tinkerpop.gremlin.process.traversal.dsl.graph.GraphTraversalSource",message)
         }
         _ => panic!("wrong error type"),
@@ -87,8 +83,11 @@ fn test_wrong_alias_v2() {
         .expect_err("it should return an error");
 
     match error {
-        GremlinError::Request((code, message)) => {
-            assert_eq!(499, code);
+        GremlinError::Server { code, message, .. } => {
+            assert_eq!(
+                ResponseStatusCode::RequestErrorInvalidRequestArguments,
+                code
+            );
             assert_eq!("Could not alias [g] to [foo] as [foo] not in the Graph or TraversalSource global bindings",message)
         }
         _ => panic!("wrong error type"),
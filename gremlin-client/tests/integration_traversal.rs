@@ -1,13 +1,15 @@
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::time::Duration;
 
 use common::assert_map_property;
 use gremlin_client::process::traversal::{traversal, Order, __};
 use gremlin_client::structure::{
-    Cardinality, Column, List, Map, Pop, TextP, Vertex, VertexProperty, P, T,
+    Bindings, BulkSet, Cardinality, Column, Lambda, List, Map, Pop, TextP, Vertex, VertexProperty,
+    P, T,
 };
 
-use gremlin_client::{utils, GKey, GValue};
+use gremlin_client::{utils, GKey, GValue, RequestOptions};
 
 mod common;
 
@@ -495,6 +497,190 @@ fn test_inject() {
     assert_eq!(expected_value, response);
 }
 
+#[test]
+fn test_with_options() {
+    let options = RequestOptions::builder()
+        .evaluation_timeout(Duration::from_secs(30))
+        .batch_size(1)
+        .build();
+    let g = traversal().with_remote(graph()).with_options(options);
+
+    let results = g.inject(vec![1.into(), 2.into(), 3.into()]).to_list();
+
+    assert_eq!(
+        3,
+        results.expect("It should stream partial responses").len()
+    );
+}
+
+#[test]
+fn test_bytes_and_char_properties() {
+    let client = graph();
+
+    drop_vertices(&client, "test_bytes_and_char_properties").unwrap();
+
+    let g = traversal().with_remote(client);
+
+    let signature: &[u8] = &[0xde, 0xad, 0xbe, 0xef];
+
+    let vertex = g
+        .add_v("test_bytes_and_char_properties")
+        .property("signature", signature)
+        .property("grade", 'A')
+        .next()
+        .expect("Should get response")
+        .expect("Should have returned a vertex");
+
+    let signature_value = g
+        .v(vertex.id())
+        .values("signature")
+        .next()
+        .expect("Should get response")
+        .expect("Should have returned a value");
+    assert_eq!(
+        signature.to_vec(),
+        signature_value.take::<Vec<u8>>().unwrap()
+    );
+
+    let grade = g
+        .v(vertex.id())
+        .values("grade")
+        .next()
+        .expect("Should get response")
+        .expect("Should have returned a value");
+    assert_eq!('A', grade.take::<char>().unwrap());
+}
+
+#[test]
+fn test_tree_step() {
+    let client = graph();
+
+    drop_vertices(&client, "test_tree_step").unwrap();
+
+    let root = create_vertex_with_label(&client, "test_tree_step", "root");
+    let child = create_vertex_with_label(&client, "test_tree_step", "child");
+    let grandchild = create_vertex_with_label(&client, "test_tree_step", "grandchild");
+
+    create_edge(&client, &root, &child, "manages");
+    create_edge(&client, &child, &grandchild, "manages");
+
+    let g = traversal().with_remote(client);
+
+    let results = g
+        .v(root.id())
+        .repeat(__.out("manages"))
+        .emit()
+        .tree()
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+
+    let tree = &results[0];
+
+    let roots: Vec<&Vertex> = tree.keys().map(|k| k.get::<Vertex>().unwrap()).collect();
+    assert_eq!(
+        vec![root.id()],
+        roots.iter().map(|v| v.id()).collect::<Vec<_>>()
+    );
+
+    let (found, grandchild_tree) = tree
+        .get_by_id(grandchild.id())
+        .expect("It should contain the grandchild");
+    assert_eq!(grandchild.id(), found.get::<Vertex>().unwrap().id());
+    assert!(grandchild_tree.is_empty());
+
+    assert_eq!(
+        vec![0, 1, 2],
+        tree.walk().map(|(depth, _, _)| depth).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_aggregate_cap_bulk_set() {
+    let client = graph();
+
+    drop_vertices(&client, "test_aggregate_cap_bulk_set").unwrap();
+
+    create_vertex_with_label(&client, "test_aggregate_cap_bulk_set", "a");
+    create_vertex_with_label(&client, "test_aggregate_cap_bulk_set", "a");
+    create_vertex_with_label(&client, "test_aggregate_cap_bulk_set", "b");
+
+    let g = traversal().with_remote(client);
+
+    let results = g
+        .v(())
+        .has_label("test_aggregate_cap_bulk_set")
+        .values("name")
+        .aggregate("names")
+        .cap("names")
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+
+    let names = results[0].get::<BulkSet>().unwrap();
+    assert_eq!(2, names.len());
+    assert_eq!(3, names.size());
+    assert_eq!(2, names.bulk(&GValue::from("a")));
+
+    let mut expanded = results[0].clone().take::<Vec<String>>().unwrap();
+    expanded.sort();
+    assert_eq!(vec!["a", "a", "b"], expanded);
+}
+
+#[test]
+fn test_lambda_steps() {
+    let client = graph();
+
+    drop_vertices(&client, "test_lambda_steps").unwrap();
+
+    create_vertex_with_label(&client, "test_lambda_steps", "jon");
+    create_vertex_with_label(&client, "test_lambda_steps", "alice");
+
+    let g = traversal().with_remote(client);
+
+    let results = g
+        .v(())
+        .has_label("test_lambda_steps")
+        .values("name")
+        .filter(Lambda::new("it.get().startsWith('a')"))
+        .map(Lambda::new("it.get().length()"))
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+    assert_eq!(Some(&5), results[0].get::<i32>().ok());
+}
+
+#[test]
+fn test_bindings() {
+    let client = graph();
+
+    drop_vertices(&client, "test_bindings").unwrap();
+
+    let vertex = create_vertex_with_label(&client, "test_bindings", "jon");
+    create_vertex_with_label(&client, "test_bindings", "alice");
+
+    let g = traversal().with_remote(client);
+
+    let results = g
+        .v(())
+        .has_label("test_bindings")
+        .has(("name", Bindings::of("name", "jon")))
+        .limit(Bindings::of("limit", 1_i64))
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+    assert_eq!(vertex.id(), results[0].id());
+
+    let results = g.v(Bindings::of("id", vertex.id())).to_list().unwrap();
+
+    assert_eq!(1, results.len());
+    assert_eq!(vertex.id(), results[0].id());
+}
+
 #[test]
 fn test_simple_vertex_traversal_with_id() {
     let client = graph();
@@ -2683,3 +2869,28 @@ fn test_traversal_vertex_mapping() {
         person.unwrap()
     );
 }
+
+#[test]
+fn test_http_traversal() {
+    let client = gremlin_client::GremlinClient::connect(
+        gremlin_client::ConnectionOptions::builder()
+            .host("localhost")
+            .port(8182)
+            .transport(gremlin_client::Transport::Http)
+            .build(),
+    )
+    .expect("It should connect");
+
+    let g = traversal().with_remote(client);
+
+    let names = g
+        .v(())
+        .has_label("person")
+        .has(("name", "marko"))
+        .values("name")
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, names.len());
+    assert_eq!("marko", names[0].get::<String>().unwrap());
+}
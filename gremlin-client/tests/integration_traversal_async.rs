@@ -12,7 +12,7 @@ mod aio {
     #[cfg(feature = "tokio-runtime")]
     use tokio_stream::StreamExt;
 
-    use gremlin_client::Vertex;
+    use gremlin_client::{RequestOptions, Vertex};
 
     #[cfg_attr(feature = "async-std-runtime", async_std::test)]
     #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -73,4 +73,21 @@ mod aio {
 
         assert_eq!(2, vertices.len());
     }
+
+    #[cfg_attr(feature = "async-std-runtime", async_std::test)]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    async fn test_with_options() {
+        let options = RequestOptions::builder().batch_size(1).build();
+        let g = traversal()
+            .with_remote_async(connect().await)
+            .with_options(options);
+
+        let results = g
+            .inject(vec![1.into(), 2.into(), 3.into()])
+            .to_list()
+            .await
+            .expect("It should stream partial responses");
+
+        assert_eq!(3, results.len());
+    }
 }
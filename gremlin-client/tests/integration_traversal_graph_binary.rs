@@ -0,0 +1,220 @@
+use gremlin_client::process::traversal::{traversal, Order};
+use gremlin_client::structure::{GValue, List, TextP, Vertex, P, T};
+use gremlin_client::IoProtocol;
+
+mod common;
+
+use common::io::{
+    create_edge, create_vertex, create_vertex_with_label, drop_vertices, graph_serializer,
+};
+
+#[test]
+fn test_client_execute_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    let results = client
+        .execute("g.inject(x, y)", &[("x", &1), ("y", &"two")])
+        .expect("It should execute a script")
+        .collect::<Result<Vec<GValue>, _>>()
+        .unwrap();
+
+    assert_eq!(vec![GValue::Int32(1), GValue::from("two")], results);
+}
+
+#[test]
+fn test_simple_vertex_traversal_with_id_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    let vertex = create_vertex(&client, "Traversal");
+
+    let g = traversal().with_remote(client);
+
+    let results = g.v(vertex.id()).to_list().unwrap();
+
+    assert_eq!(1, results.len());
+
+    assert_eq!(vertex.id(), results[0].id());
+}
+
+#[test]
+fn test_simple_edge_traversal_id_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    let v = create_vertex(&client, "Traversal");
+    let v1 = create_vertex(&client, "Traversal");
+
+    let e = create_edge(&client, &v, &v1, "TraversalEdge");
+
+    let g = traversal().with_remote(client);
+
+    let results = g.e(e.id()).to_list().unwrap();
+
+    assert_eq!(1, results.len());
+
+    assert_eq!(e.id(), results[0].id());
+    assert_eq!(v.id(), results[0].out_v().id());
+    assert_eq!(v1.id(), results[0].in_v().id());
+}
+
+#[test]
+fn test_value_map_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    drop_vertices(&client, "test_value_map_graph_binary").unwrap();
+
+    let g = traversal().with_remote(client);
+
+    let vertices = g
+        .add_v("test_value_map_graph_binary")
+        .property("name", "test")
+        .property("age", 30)
+        .to_list()
+        .unwrap();
+
+    let results = g.v(vertices[0].id()).value_map(true).to_list().unwrap();
+
+    assert_eq!(1, results.len());
+
+    let value = &results[0];
+
+    assert_eq!(true, value.get("id").is_some());
+    assert_eq!(
+        "test_value_map_graph_binary",
+        value["label"].get::<String>().unwrap()
+    );
+    assert_eq!(
+        &30,
+        value["age"].get::<List>().unwrap()[0].get::<i32>().unwrap()
+    );
+}
+
+#[test]
+fn test_group_count_step_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    drop_vertices(&client, "test_group_count_graph_binary").unwrap();
+
+    create_vertex_with_label(&client, "test_group_count_graph_binary", "Count");
+
+    let g = traversal().with_remote(client);
+
+    let results = g
+        .v(())
+        .has_label("test_group_count_graph_binary")
+        .group_count()
+        .by(T::Label)
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+
+    let value = &results[0];
+
+    assert_eq!(
+        &1,
+        value["test_group_count_graph_binary"].get::<i64>().unwrap()
+    );
+}
+
+#[test]
+fn test_path_step_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    drop_vertices(&client, "test_path_step_graph_binary").unwrap();
+
+    let v = create_vertex_with_label(&client, "test_path_step_graph_binary", "Count");
+
+    let g = traversal().with_remote(client);
+
+    let results = g
+        .v(())
+        .has_label("test_path_step_graph_binary")
+        .path()
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+
+    let value = &results[0];
+
+    assert_eq!(v.id(), value.objects()[0].get::<Vertex>().unwrap().id());
+}
+
+#[test]
+fn test_has_with_p_and_text_p_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    drop_vertices(&client, "test_has_with_p_graph_binary").unwrap();
+
+    let g = traversal().with_remote(client);
+
+    g.add_v("test_has_with_p_graph_binary")
+        .property("name", "Jon")
+        .property("age", 26)
+        .to_list()
+        .unwrap();
+    let vertices = g
+        .add_v("test_has_with_p_graph_binary")
+        .property("name", "Alice")
+        .property("age", 20)
+        .to_list()
+        .unwrap();
+
+    let results = g
+        .v(())
+        .has((
+            "test_has_with_p_graph_binary",
+            "age",
+            P::within(vec![19, 20]),
+        ))
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+    assert_eq!(vertices[0].id(), results[0].id());
+
+    let results = g
+        .v(())
+        .has((
+            "test_has_with_p_graph_binary",
+            "name",
+            TextP::starting_with("Al"),
+        ))
+        .to_list()
+        .unwrap();
+
+    assert_eq!(1, results.len());
+    assert_eq!(vertices[0].id(), results[0].id());
+}
+
+#[test]
+fn test_order_step_graph_binary() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV1);
+
+    drop_vertices(&client, "test_order_step_graph_binary").unwrap();
+
+    let g = traversal().with_remote(client);
+
+    g.add_v("test_order_step_graph_binary")
+        .property("name", "b")
+        .to_list()
+        .unwrap();
+
+    g.add_v("test_order_step_graph_binary")
+        .property("name", "a")
+        .to_list()
+        .unwrap();
+
+    let results = g
+        .v(())
+        .has_label("test_order_step_graph_binary")
+        .values("name")
+        .order(())
+        .by(Order::Desc)
+        .to_list()
+        .unwrap();
+
+    assert_eq!(2, results.len());
+
+    assert_eq!("b", results[0].get::<String>().unwrap());
+}
@@ -0,0 +1,83 @@
+use gremlin_client::process::traversal::{traversal, Order};
+use gremlin_client::structure::{GValue, P};
+use gremlin_client::IoProtocol;
+
+mod common;
+
+use common::io::{create_vertex, drop_vertices, graph_serializer};
+
+#[test]
+fn test_client_execute_tinkerpop4() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV4);
+
+    let results = client
+        .execute("g.inject(x, y)", &[("x", &1), ("y", &"two")])
+        .expect("It should execute a script")
+        .collect::<Result<Vec<GValue>, _>>()
+        .unwrap();
+
+    assert_eq!(vec![GValue::Int32(1), GValue::from("two")], results);
+}
+
+#[test]
+fn test_simple_vertex_traversal_with_id_tinkerpop4() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV4);
+
+    let vertex = create_vertex(&client, "Traversal");
+
+    let g = traversal().with_remote(client);
+
+    let results = g.v(vertex.id()).to_list().unwrap();
+
+    assert_eq!(1, results.len());
+
+    assert_eq!(vertex.id(), results[0].id());
+}
+
+#[test]
+fn test_streamed_results_tinkerpop4() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV4);
+
+    let values: Vec<GValue> = (0..5000_i64).map(GValue::from).collect();
+
+    let results = client
+        .execute("g.inject(values).unfold()", &[("values", &values)])
+        .expect("It should execute a script")
+        .collect::<Result<Vec<GValue>, _>>()
+        .unwrap();
+
+    assert_eq!(values, results);
+}
+
+#[test]
+fn test_order_step_tinkerpop4() {
+    let client = graph_serializer(IoProtocol::GraphBinaryV4);
+
+    drop_vertices(&client, "test_order_step_tinkerpop4").unwrap();
+
+    let g = traversal().with_remote(client);
+
+    g.add_v("test_order_step_tinkerpop4")
+        .property("name", "b")
+        .to_list()
+        .unwrap();
+
+    g.add_v("test_order_step_tinkerpop4")
+        .property("name", "a")
+        .to_list()
+        .unwrap();
+
+    let results = g
+        .v(())
+        .has_label("test_order_step_tinkerpop4")
+        .has(("name", P::within(("a", "b"))))
+        .values("name")
+        .order(())
+        .by(Order::Desc)
+        .to_list()
+        .unwrap();
+
+    assert_eq!(2, results.len());
+
+    assert_eq!("b", results[0].get::<String>().unwrap());
+}